@@ -23,6 +23,7 @@ interface IVoterSessionCreateDialogProps {
 interface ICreateVotingSessionParams {
 	name: string;
 	description: string;
+	metadataUri?: string;
 }
 
 export default ({
@@ -56,7 +57,12 @@ export default ({
 						setFormData(data);
 						setPending(true);
 						votingClient
-							.createVotingSession(anchorWallet, data.name, data.description)
+							.createVotingSession(
+								anchorWallet,
+								data.name,
+								data.description,
+								data.metadataUri || '',
+							)
 							.then(() => {
 								setPending(false);
 								setDialogVisible(false);
@@ -78,6 +84,12 @@ export default ({
 							required={true}
 						/>
 						<br />
+						<TextFieldElement
+							type={'url'}
+							name={'metadataUri'}
+							label={'Metadata URI'}
+						/>
+						<br />
 						<LoadingButton
 							loading={pending}
 							loadingPosition={'end'}
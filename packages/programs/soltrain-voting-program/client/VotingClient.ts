@@ -15,6 +15,7 @@ type InternalVotingSessionStatus =
 			proposalsRegistrationEnded?: never;
 			votingSessionStarted?: never;
 			votingSessionEnded?: never;
+			vetoed?: never;
 	  } & { votesTallied: Record<string, never> })
 	| ({
 			votesTallied?: never;
@@ -23,21 +24,73 @@ type InternalVotingSessionStatus =
 			proposalsRegistrationEnded?: never;
 			votingSessionStarted?: never;
 			votingSessionEnded?: never;
+			vetoed?: never;
 	  } & { none: Record<string, never> })
-	| ({ votesTallied?: never; none?: never; proposalsRegistrationStarted?: never; proposalsRegistrationEnded?: never; votingSessionStarted?: never; votingSessionEnded?: never } & {
+	| ({
+			votesTallied?: never;
+			vetoed?: never;
+			none?: never;
+			proposalsRegistrationStarted?: never;
+			proposalsRegistrationEnded?: never;
+			votingSessionStarted?: never;
+			votingSessionEnded?: never;
+	  } & {
 			registeringVoters: Record<string, never>;
 	  })
-	| ({ votesTallied?: never; none?: never; registeringVoters?: never; proposalsRegistrationEnded?: never; votingSessionStarted?: never; votingSessionEnded?: never } & {
+	| ({
+			votesTallied?: never;
+			vetoed?: never;
+			none?: never;
+			registeringVoters?: never;
+			proposalsRegistrationEnded?: never;
+			votingSessionStarted?: never;
+			votingSessionEnded?: never;
+	  } & {
 			proposalsRegistrationStarted: Record<string, never>;
 	  })
-	| ({ votesTallied?: never; none?: never; registeringVoters?: never; proposalsRegistrationStarted?: never; votingSessionStarted?: never; votingSessionEnded?: never } & {
+	| ({
+			votesTallied?: never;
+			vetoed?: never;
+			none?: never;
+			registeringVoters?: never;
+			proposalsRegistrationStarted?: never;
+			votingSessionStarted?: never;
+			votingSessionEnded?: never;
+	  } & {
 			proposalsRegistrationEnded: Record<string, never>;
 	  })
-	| ({ votesTallied?: never; none?: never; registeringVoters?: never; proposalsRegistrationStarted?: never; proposalsRegistrationEnded?: never; votingSessionEnded?: never } & {
+	| ({
+			votesTallied?: never;
+			vetoed?: never;
+			none?: never;
+			registeringVoters?: never;
+			proposalsRegistrationStarted?: never;
+			proposalsRegistrationEnded?: never;
+			votingSessionEnded?: never;
+	  } & {
 			votingSessionStarted: Record<string, never>;
 	  })
-	| ({ votesTallied?: never; none?: never; registeringVoters?: never; proposalsRegistrationStarted?: never; proposalsRegistrationEnded?: never; votingSessionStarted?: never } & {
+	| ({
+			votesTallied?: never;
+			vetoed?: never;
+			none?: never;
+			registeringVoters?: never;
+			proposalsRegistrationStarted?: never;
+			proposalsRegistrationEnded?: never;
+			votingSessionStarted?: never;
+	  } & {
 			votingSessionEnded: Record<string, never>;
+	  })
+	| ({
+			votesTallied?: never;
+			none?: never;
+			registeringVoters?: never;
+			proposalsRegistrationStarted?: never;
+			proposalsRegistrationEnded?: never;
+			votingSessionStarted?: never;
+			votingSessionEnded?: never;
+	  } & {
+			vetoed: Record<string, never>;
 	  });
 
 export type InternalSessionResult = {
@@ -45,6 +98,7 @@ export type InternalSessionResult = {
 	blankVotes: number;
 	abstention: number;
 	winningProposals: Buffer;
+	tiedProposals: Buffer;
 };
 
 type InternalVotingSession = {
@@ -56,6 +110,8 @@ type InternalVotingSession = {
 	votersCount: number;
 	proposalsCount: number;
 	result: InternalSessionResult;
+	metadataUri: string;
+	metadataHash: number[];
 };
 
 export enum VotingSessionStatus {
@@ -66,17 +122,38 @@ export enum VotingSessionStatus {
 	VotingSessionStarted,
 	VotingSessionEnded,
 	VotesTallied,
+	Vetoed,
 }
 
+export type GlobalConfig = {
+	sessionFee: BN;
+	maxNameLen: number;
+	maxDescriptionLen: number;
+	maxVoters: number;
+};
+
+export const DEFAULT_GLOBAL_CONFIG: GlobalConfig = {
+	sessionFee: new BN(0),
+	maxNameLen: 20,
+	maxDescriptionLen: 80,
+	maxVoters: 1000,
+};
+
 export type Global = {
 	sessionCount: BN;
+	authority: PublicKey;
+	config: GlobalConfig;
+	paused: boolean;
 };
 
 export type SessionResult = {
 	totalVotes: number;
 	blankVotes: number;
 	abstention: number;
+	// elected proposals, best first
 	winningProposals: number[];
+	// proposals tied at the last seat, not elected
+	tiedProposals: number[];
 };
 
 export type VotingSession = {
@@ -88,6 +165,8 @@ export type VotingSession = {
 	votersCount: number;
 	proposalsCount: number;
 	result: SessionResult;
+	metadataUri: string;
+	metadataHash: number[];
 };
 
 export type Voter = {
@@ -107,6 +186,12 @@ export type Proposal = {
 	voteCount: number;
 };
 
+const BPF_LOADER_UPGRADEABLE_PROGRAM_ID = new PublicKey('BPFLoaderUpgradeab1e11111111111111111111111');
+const TOKEN_PROGRAM_ID = new PublicKey('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA');
+const ASSOCIATED_TOKEN_PROGRAM_ID = new PublicKey('ATokenGPvbdGVxr1b2hf5ThkR4cU8CyBuGTVq1oB4KAr');
+const SESSION_INDEX_PAGE_SIZE = 32;
+const EMPTY_METADATA_HASH = new Array(32).fill(0);
+
 export class VotingClient extends AbstractSolanaClient<Voting> {
 	public readonly globalAccountPubkey: PublicKey;
 
@@ -115,13 +200,44 @@ export class VotingClient extends AbstractSolanaClient<Voting> {
 		this.globalAccountPubkey = PublicKey.findProgramAddressSync([Buffer.from('global')], program.programId)[0];
 	}
 
-	public async initGlobal(payer: Wallet) {
+	public async initGlobal(payer: Wallet, config: GlobalConfig = DEFAULT_GLOBAL_CONFIG) {
 		return this.wrapFn(async () => {
+			const [programDataPubkey] = PublicKey.findProgramAddressSync([this.program.programId.toBuffer()], BPF_LOADER_UPGRADEABLE_PROGRAM_ID);
+
 			const tx = await this.program.methods
-				.initGlobal()
+				.initGlobal(config)
 				.accountsPartial({
 					owner: payer.publicKey,
 					globalAccount: this.globalAccountPubkey,
+					programData: programDataPubkey,
+				})
+				.transaction();
+
+			return this.signAndSendTransaction(payer, tx);
+		});
+	}
+
+	public async updateGlobalConfig(payer: Wallet, config: GlobalConfig): Promise<ITransactionResult> {
+		return this.wrapFn(async () => {
+			const tx = await this.program.methods
+				.updateGlobalConfig(config)
+				.accountsPartial({
+					authority: payer.publicKey,
+					globalAccount: this.globalAccountPubkey,
+				})
+				.transaction();
+
+			return this.signAndSendTransaction(payer, tx);
+		});
+	}
+
+	public async setPaused(payer: Wallet, paused: boolean): Promise<ITransactionResult> {
+		return this.wrapFn(async () => {
+			const tx = await this.program.methods
+				.setPaused(paused)
+				.accountsPartial({
+					authority: payer.publicKey,
+					globalAccount: this.globalAccountPubkey,
 				})
 				.transaction();
 
@@ -129,17 +245,28 @@ export class VotingClient extends AbstractSolanaClient<Voting> {
 		});
 	}
 
-	public async createVotingSession(payer: Wallet, name: string, description: string): Promise<ITransactionResult> {
+	public async createVotingSession(
+		payer: Wallet,
+		name: string,
+		description: string,
+		metadataUri: string = '',
+		metadataHash: number[] = EMPTY_METADATA_HASH,
+	): Promise<ITransactionResult> {
 		return this.wrapFn(async () => {
-			const sessionId = (await this.getNextSessionId()) || new BN(0);
+			const global = await this.getGlobal();
+			const sessionId = global.sessionCount || new BN(0);
 			const sessionAccountPubkey = this.findSessionAccountAddress(sessionId);
+			const adminSessionIndexPagePubkey = await this.findSessionIndexPageAddress('admin_sessions', payer.publicKey);
 
 			const tx = await this.program.methods
-				.createVotingSession(name, description)
+				.createVotingSession(name, description, metadataUri, metadataHash)
 				.accountsPartial({
 					owner: payer.publicKey,
 					sessionAccount: sessionAccountPubkey,
 					globalAccount: this.globalAccountPubkey,
+					authority: global.authority,
+					organizationAccount: null,
+					adminSessionIndexPage: adminSessionIndexPagePubkey,
 				})
 				.transaction();
 
@@ -149,17 +276,19 @@ export class VotingClient extends AbstractSolanaClient<Voting> {
 		});
 	}
 
-	public async registerVoter(payer: Wallet, sessionId: BN, voter: PublicKey): Promise<ITransactionResult> {
+	public async registerVoter(payer: Wallet, sessionId: BN, voter: PublicKey, weight: number | null = null, credits: number | null = null): Promise<ITransactionResult> {
 		return this.wrapFn(async () => {
 			const sessionAccountPubkey = this.findSessionAccountAddress(sessionId);
 			const voterAccountPubkey = this.findVoterAccountAddress(sessionId, voter);
+			const voterSessionIndexPagePubkey = await this.findSessionIndexPageAddress('voter_sessions', voter);
 
 			const tx = await this.program.methods
-				.registerVoter(voter)
+				.registerVoter(voter, weight, credits)
 				.accountsPartial({
 					admin: payer.publicKey,
 					sessionAccount: sessionAccountPubkey,
 					voterAccount: voterAccountPubkey,
+					voterSessionIndexPage: voterSessionIndexPagePubkey,
 				})
 				.transaction();
 
@@ -245,21 +374,40 @@ export class VotingClient extends AbstractSolanaClient<Voting> {
 		});
 	}
 
-	public async registerProposal(payer: Wallet, sessionId: BN, description: string): Promise<ITransactionResult> {
+	public async registerProposal(
+		payer: Wallet,
+		sessionId: BN,
+		description: string,
+		metadataUri: string = '',
+		metadataHash: number[] = EMPTY_METADATA_HASH,
+		questionId: number = 0,
+	): Promise<ITransactionResult> {
 		return this.wrapFn(async () => {
 			const sessionAccountPubkey = this.findSessionAccountAddress(sessionId);
 			const voterAccountPubkey = this.findVoterAccountAddress(sessionId, payer.publicKey);
 
-			const nextProposalId = (await this.getSession(sessionAccountPubkey)).proposalsCount;
-			const proposalAccountPubkey = this.findProposalAccountAddress(sessionId, nextProposalId);
+			const session = await this.program.account.sessionAccount.fetch(sessionAccountPubkey);
+			const proposalAccountPubkey = this.findProposalAccountAddress(sessionId, session.proposalsCount);
+
+			// deposit accounts are only given when the session requires a proposal deposit
+			const { depositAmount, depositMint } = session.proposalsConfig;
+			const vaultAccountPubkey = depositAmount.isZero() ? null : this.findVaultAccountAddress(sessionId);
+			const tokenDeposit = vaultAccountPubkey && depositMint;
 
 			const tx = await this.program.methods
-				.registerProposal(description)
+				.registerProposal(description, metadataUri, metadataHash)
 				.accountsPartial({
 					proposer: payer.publicKey,
 					sessionAccount: sessionAccountPubkey,
 					voterAccount: voterAccountPubkey,
 					proposalAccount: proposalAccountPubkey,
+					vaultAccount: vaultAccountPubkey,
+					depositMint: tokenDeposit ? depositMint : null,
+					proposerTokenAccount: tokenDeposit ? this.findAssociatedTokenAddress(payer.publicKey, depositMint) : null,
+					vaultTokenAccount: tokenDeposit ? this.findAssociatedTokenAddress(vaultAccountPubkey, depositMint) : null,
+					tokenProgram: tokenDeposit ? TOKEN_PROGRAM_ID : null,
+					// question 0 is the session itself
+					questionAccount: questionId ? this.findQuestionAccountAddress(sessionId, questionId) : null,
 				})
 				.transaction();
 
@@ -271,6 +419,32 @@ export class VotingClient extends AbstractSolanaClient<Voting> {
 		});
 	}
 
+	public async addQuestion(payer: Wallet, sessionId: BN, title: string): Promise<ITransactionResult> {
+		return this.wrapFn(async () => {
+			const sessionAccountPubkey = this.findSessionAccountAddress(sessionId);
+
+			const session = await this.program.account.sessionAccount.fetch(sessionAccountPubkey);
+			const questionAccountPubkey = this.findQuestionAccountAddress(sessionId, session.questionsCount);
+			const blankProposalAccountPubkey = this.findProposalAccountAddress(sessionId, session.proposalsCount);
+
+			const tx = await this.program.methods
+				.addQuestion(title)
+				.accountsPartial({
+					admin: payer.publicKey,
+					sessionAccount: sessionAccountPubkey,
+					questionAccount: questionAccountPubkey,
+					blankProposalAccount: blankProposalAccountPubkey,
+				})
+				.transaction();
+
+			return this.signAndSendTransaction(payer, tx, {
+				sessionAccountPubkey,
+				questionAccountPubkey,
+				blankProposalAccountPubkey,
+			});
+		});
+	}
+
 	public async vote(payer: Wallet, sessionId: BN, proposalId: number): Promise<ITransactionResult> {
 		return this.wrapFn(async () => {
 			const sessionAccountPubkey = this.findSessionAccountAddress(sessionId);
@@ -284,6 +458,10 @@ export class VotingClient extends AbstractSolanaClient<Voting> {
 					sessionAccount: sessionAccountPubkey,
 					voterAccount: voterAccountPubkey,
 					proposalAccount: proposalAccountPubkey,
+					badgeMint: null,
+					voterBadgeAccount: null,
+					tokenProgram: null,
+					associatedTokenProgram: null,
 				})
 				.transaction();
 
@@ -299,25 +477,121 @@ export class VotingClient extends AbstractSolanaClient<Voting> {
 		return this.wrapFn(async () => {
 			const sessionAccountPubkey = this.findSessionAccountAddress(sessionId);
 
-			const session = await this.getSession(sessionAccountPubkey);
+			const session = await this.program.account.sessionAccount.fetch(sessionAccountPubkey);
 
 			let proposalsAccounts: PublicKey[] = [];
 			for (let i = 1; i < session.proposalsCount; i++) {
 				const proposalAccount = this.findProposalAccountAddress(sessionId, i);
 				proposalsAccounts.push(proposalAccount);
 			}
+			// added questions follow the proposals, question 0 is the session itself
+			let questionsAccounts: PublicKey[] = [];
+			for (let i = 1; i < session.questionsCount; i++) {
+				questionsAccounts.push(this.findQuestionAccountAddress(sessionId, i));
+			}
+			// the pairwise matrix only exists once a ranked ballot is cast
+			const pairwiseAccountPubkey = 'condorcet' in session.votingMode && session.ballotsCount > 0 ? this.findPairwiseAccountAddress(sessionId) : null;
 
 			const tx = await this.program.methods
 				.tallyVotes()
 				.accountsPartial({
 					admin: payer.publicKey,
 					sessionAccount: sessionAccountPubkey,
+					pairwiseAccount: pairwiseAccountPubkey,
+				})
+				// added questions results are written to their question account
+				.remainingAccounts([
+					...proposalsAccounts.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false })),
+					...questionsAccounts.map((pubkey) => ({ pubkey, isWritable: true, isSigner: false })),
+				])
+				.transaction();
+
+			return this.signAndSendTransaction(payer, tx, {
+				sessionAccountPubkey,
+			});
+		});
+	}
+
+	public async fundRewardPool(payer: Wallet, sessionId: BN, amount: BN, rewardPerVoter: BN, claimDuration: BN, mint: PublicKey | null = null): Promise<ITransactionResult> {
+		return this.wrapFn(async () => {
+			const sessionAccountPubkey = this.findSessionAccountAddress(sessionId);
+			const rewardPoolAccountPubkey = this.findRewardPoolAccountAddress(sessionId);
+
+			// SOL rewards when no mint is given
+			const tx = await this.program.methods
+				.fundRewardPool(amount, rewardPerVoter, claimDuration)
+				.accountsPartial({
+					admin: payer.publicKey,
+					sessionAccount: sessionAccountPubkey,
+					rewardPoolAccount: rewardPoolAccountPubkey,
+					mint,
+					adminTokenAccount: mint ? this.findAssociatedTokenAddress(payer.publicKey, mint) : null,
+					poolTokenAccount: mint ? this.findAssociatedTokenAddress(rewardPoolAccountPubkey, mint) : null,
+					tokenProgram: mint ? TOKEN_PROGRAM_ID : null,
+					associatedTokenProgram: mint ? ASSOCIATED_TOKEN_PROGRAM_ID : null,
+				})
+				.transaction();
+
+			return this.signAndSendTransaction(payer, tx, {
+				sessionAccountPubkey,
+				rewardPoolAccountPubkey,
+			});
+		});
+	}
+
+	public async claimReward(payer: Wallet, sessionId: BN): Promise<ITransactionResult> {
+		return this.wrapFn(async () => {
+			const sessionAccountPubkey = this.findSessionAccountAddress(sessionId);
+			const voterAccountPubkey = this.findVoterAccountAddress(sessionId, payer.publicKey);
+			const rewardPoolAccountPubkey = this.findRewardPoolAccountAddress(sessionId);
+
+			const { mint } = await this.program.account.rewardPoolAccount.fetch(rewardPoolAccountPubkey);
+
+			const tx = await this.program.methods
+				.claimReward()
+				.accountsPartial({
+					voter: payer.publicKey,
+					sessionAccount: sessionAccountPubkey,
+					voterAccount: voterAccountPubkey,
+					rewardPoolAccount: rewardPoolAccountPubkey,
+					mint,
+					poolTokenAccount: mint ? this.findAssociatedTokenAddress(rewardPoolAccountPubkey, mint) : null,
+					voterTokenAccount: mint ? this.findAssociatedTokenAddress(payer.publicKey, mint) : null,
+					tokenProgram: mint ? TOKEN_PROGRAM_ID : null,
+				})
+				.transaction();
+
+			return this.signAndSendTransaction(payer, tx, {
+				sessionAccountPubkey,
+				voterAccountPubkey,
+				rewardPoolAccountPubkey,
+			});
+		});
+	}
+
+	public async reclaimRewardPool(payer: Wallet, sessionId: BN): Promise<ITransactionResult> {
+		return this.wrapFn(async () => {
+			const sessionAccountPubkey = this.findSessionAccountAddress(sessionId);
+			const rewardPoolAccountPubkey = this.findRewardPoolAccountAddress(sessionId);
+
+			const { mint } = await this.program.account.rewardPoolAccount.fetch(rewardPoolAccountPubkey);
+
+			const tx = await this.program.methods
+				.reclaimRewardPool()
+				.accountsPartial({
+					funder: payer.publicKey,
+					sessionAccount: sessionAccountPubkey,
+					rewardPoolAccount: rewardPoolAccountPubkey,
+					mint,
+					poolTokenAccount: mint ? this.findAssociatedTokenAddress(rewardPoolAccountPubkey, mint) : null,
+					funderTokenAccount: mint ? this.findAssociatedTokenAddress(payer.publicKey, mint) : null,
+					tokenProgram: mint ? TOKEN_PROGRAM_ID : null,
 				})
-				.remainingAccounts([...proposalsAccounts.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false }))])
 				.transaction();
 
 			return this.signAndSendTransaction(payer, tx, {
 				sessionAccountPubkey,
+				rewardPoolAccountPubkey,
 			});
 		});
 	}
@@ -337,7 +611,8 @@ export class VotingClient extends AbstractSolanaClient<Voting> {
 				.sort((a, b) => a.voterId - b.voterId)
 				.map((account) => account.pubkey);
 
-			return this.getPage(this.program.account.voterAccount, addresses, paginationOptions?.page, paginationOptions?.perPage);
+			const voters = await this.getPage<Voter>(this.program.account.voterAccount, addresses, paginationOptions?.page, paginationOptions?.perPage);
+			return voters.map(this.mapVoter);
 		});
 	}
 
@@ -375,7 +650,14 @@ export class VotingClient extends AbstractSolanaClient<Voting> {
 				.sort((a, b) => a.proposalId - b.proposalId)
 				.map((account) => account.pubkey);
 
-			return this.getPage(this.program.account.proposalAccount, addresses, paginationOptions?.page, paginationOptions?.perPage);
+			const proposals = await this.getPage<Proposal>(this.program.account.proposalAccount, addresses, paginationOptions?.page, paginationOptions?.perPage);
+			return proposals.map(this.mapProposal);
+		});
+	}
+
+	public async getGlobal(): Promise<Global> {
+		return this.wrapFn(async () => {
+			return this.program.account.globalAccount.fetch(this.globalAccountPubkey);
 		});
 	}
 
@@ -392,15 +674,29 @@ export class VotingClient extends AbstractSolanaClient<Voting> {
 		});
 	}
 
+	public async getSessionIds(prefix: 'admin_sessions' | 'voter_sessions', owner: PublicKey): Promise<BN[]> {
+		return this.wrapFn(async () => {
+			const sessionIndex = await this.program.account.sessionIndexAccount.fetchNullable(this.findSessionIndexAddress(prefix, owner));
+			const pagesCount = Math.ceil((sessionIndex?.sessionsCount || 0) / SESSION_INDEX_PAGE_SIZE);
+
+			const sessionIds: BN[] = [];
+			for (let page = 0; page < pagesCount; page++) {
+				const sessionIndexPage = await this.program.account.sessionIndexPageAccount.fetch(this.findSessionIndexPageAddressAt(prefix, owner, page));
+				sessionIds.push(...sessionIndexPage.sessionIds);
+			}
+			return sessionIds;
+		});
+	}
+
 	public async getVoter(voterAccountPubkey: PublicKey): Promise<Voter> {
 		return this.wrapFn(async () => {
-			return this.program.account.voterAccount.fetch(voterAccountPubkey);
+			return this.mapVoter(await this.program.account.voterAccount.fetch(voterAccountPubkey));
 		});
 	}
 
 	public async getProposal(proposalAccountPubkey: PublicKey): Promise<Proposal> {
 		return this.wrapFn(async () => {
-			return this.program.account.proposalAccount.fetch(proposalAccountPubkey);
+			return this.mapProposal(await this.program.account.proposalAccount.fetch(proposalAccountPubkey));
 		});
 	}
 
@@ -424,6 +720,50 @@ export class VotingClient extends AbstractSolanaClient<Voting> {
 		return sessionAccountPubkey;
 	}
 
+	public findQuestionAccountAddress(sessionId: BN, questionId: number): PublicKey {
+		const [questionAccountPubkey] = PublicKey.findProgramAddressSync([Buffer.from('question'), sessionId.toBuffer('le', 8), Buffer.from([questionId])], this.program.programId);
+		return questionAccountPubkey;
+	}
+
+	public findVaultAccountAddress(sessionId: BN): PublicKey {
+		const [vaultAccountPubkey] = PublicKey.findProgramAddressSync([Buffer.from('vault'), sessionId.toBuffer('le', 8)], this.program.programId);
+		return vaultAccountPubkey;
+	}
+
+	public findRewardPoolAccountAddress(sessionId: BN): PublicKey {
+		const [rewardPoolAccountPubkey] = PublicKey.findProgramAddressSync([Buffer.from('reward_pool'), sessionId.toBuffer('le', 8)], this.program.programId);
+		return rewardPoolAccountPubkey;
+	}
+
+	public findPairwiseAccountAddress(sessionId: BN): PublicKey {
+		const [pairwiseAccountPubkey] = PublicKey.findProgramAddressSync([Buffer.from('pairwise'), sessionId.toBuffer('le', 8)], this.program.programId);
+		return pairwiseAccountPubkey;
+	}
+
+	public findAssociatedTokenAddress(owner: PublicKey, mint: PublicKey): PublicKey {
+		const [tokenAccountPubkey] = PublicKey.findProgramAddressSync([owner.toBuffer(), TOKEN_PROGRAM_ID.toBuffer(), mint.toBuffer()], ASSOCIATED_TOKEN_PROGRAM_ID);
+		return tokenAccountPubkey;
+	}
+
+	public findSessionIndexAddress(prefix: 'admin_sessions' | 'voter_sessions', owner: PublicKey): PublicKey {
+		const [sessionIndexPubkey] = PublicKey.findProgramAddressSync([Buffer.from(prefix), owner.toBuffer()], this.program.programId);
+		return sessionIndexPubkey;
+	}
+
+	public async findSessionIndexPageAddress(prefix: 'admin_sessions' | 'voter_sessions', owner: PublicKey): Promise<PublicKey> {
+		const sessionIndex = await this.program.account.sessionIndexAccount.fetchNullable(this.findSessionIndexAddress(prefix, owner));
+		const page = Math.floor((sessionIndex?.sessionsCount || 0) / SESSION_INDEX_PAGE_SIZE);
+		return this.findSessionIndexPageAddressAt(prefix, owner, page);
+	}
+
+	public findSessionIndexPageAddressAt(prefix: 'admin_sessions' | 'voter_sessions', owner: PublicKey, page: number): PublicKey {
+		const pageSeed = Buffer.alloc(4);
+		pageSeed.writeUInt32LE(page);
+
+		const [sessionIndexPagePubkey] = PublicKey.findProgramAddressSync([Buffer.from(prefix), owner.toBuffer(), pageSeed], this.program.programId);
+		return sessionIndexPagePubkey;
+	}
+
 	public mapSessionStatus(internalStatus: InternalVotingSessionStatus): VotingSessionStatus {
 		if (internalStatus.none) return VotingSessionStatus.None;
 		if (internalStatus.registeringVoters) return VotingSessionStatus.RegisteringVoters;
@@ -432,6 +772,7 @@ export class VotingClient extends AbstractSolanaClient<Voting> {
 		if (internalStatus.votingSessionStarted) return VotingSessionStatus.VotingSessionStarted;
 		if (internalStatus.votingSessionEnded) return VotingSessionStatus.VotingSessionEnded;
 		if (internalStatus.votesTallied) return VotingSessionStatus.VotesTallied;
+		if (internalStatus.vetoed) return VotingSessionStatus.Vetoed;
 		throw new Error('Bad session status');
 	}
 
@@ -442,7 +783,16 @@ export class VotingClient extends AbstractSolanaClient<Voting> {
 			result: {
 				...internalSession.result,
 				winningProposals: internalSession.result.winningProposals ? ([...internalSession.result.winningProposals] as number[]) : [],
+				tiedProposals: internalSession.result.tiedProposals ? ([...internalSession.result.tiedProposals] as number[]) : [],
 			},
 		};
 	};
+
+	private mapVoter = ({ sessionId, voter, voterId, hasVoted, votedProposalId, nbProposals }: Voter): Voter => {
+		return { sessionId, voter, voterId, hasVoted, votedProposalId, nbProposals };
+	};
+
+	private mapProposal = ({ sessionId, proposalId, description, proposer, voteCount }: Proposal): Proposal => {
+		return { sessionId, proposalId, description, proposer, voteCount };
+	};
 }
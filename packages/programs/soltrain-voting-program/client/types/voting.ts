@@ -14,61 +14,53 @@ export type Voting = {
 	};
 	instructions: [
 		{
-			name: 'createVotingSession';
+			name: 'addOrganizationAdmin';
 			docs: [
-				"* Anyone can create new voting session. Session's creator becomes session administrator.\n     *\n     * @dev An event SessionCreated is emitted\n     *\n     * @param name The session name\n     * @param description The session description",
+				'* Organization administrators can add another administrator.\n     *\n     * @dev An event OrganizationAdminAdded is emitted\n     *\n     * @param admin The address to add into organization administrators',
 			];
-			discriminator: [241, 56, 27, 243, 109, 166, 75, 93];
+			discriminator: [235, 96, 13, 53, 159, 99, 197, 91];
 			accounts: [
 				{
-					name: 'owner';
-					writable: true;
+					name: 'admin';
 					signer: true;
 				},
 				{
 					name: 'globalAccount';
-					writable: true;
-				},
-				{
-					name: 'sessionAccount';
-					writable: true;
 					pda: {
 						seeds: [
 							{
 								kind: 'const';
-								value: [115, 101, 115, 115, 105, 111, 110];
-							},
-							{
-								kind: 'account';
-								path: 'global_account.session_count';
-								account: 'globalAccount';
+								value: [103, 108, 111, 98, 97, 108];
 							},
 						];
 					};
 				},
 				{
-					name: 'systemProgram';
-					address: '11111111111111111111111111111111';
+					name: 'organizationAccount';
+					writable: true;
 				},
 			];
 			args: [
 				{
-					name: 'name';
-					type: 'string';
-				},
-				{
-					name: 'description';
-					type: 'string';
+					name: 'admin';
+					type: 'pubkey';
 				},
 			];
 		},
 		{
-			name: 'initGlobal';
-			discriminator: [44, 238, 77, 253, 76, 182, 192, 162];
+			name: 'addOrganizationMember';
+			docs: [
+				'* Organization administrators can register a standing member.\n     *\n     * @dev An event OrganizationMemberAdded is emitted\n     *\n     * @param member The address to add into organization members registry',
+			];
+			discriminator: [83, 161, 233, 161, 125, 223, 191, 151];
 			accounts: [
 				{
-					name: 'globalAccount';
+					name: 'admin';
 					writable: true;
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
 					pda: {
 						seeds: [
 							{
@@ -79,39 +71,75 @@ export type Voting = {
 					};
 				},
 				{
-					name: 'owner';
+					name: 'organizationAccount';
 					writable: true;
-					signer: true;
+				},
+				{
+					name: 'memberAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [109, 101, 109, 98, 101, 114];
+							},
+							{
+								kind: 'account';
+								path: 'organization_account.organization_id';
+								account: 'organizationAccount';
+							},
+							{
+								kind: 'arg';
+								path: 'member';
+							},
+						];
+					};
 				},
 				{
 					name: 'systemProgram';
 					address: '11111111111111111111111111111111';
 				},
 			];
-			args: [];
+			args: [
+				{
+					name: 'member';
+					type: 'pubkey';
+				},
+			];
 		},
 		{
-			name: 'registerProposal';
+			name: 'addQuestion';
 			docs: [
-				'* A voter can register a new proposal.\n     *\n     * @dev Each voter can register many proposals.\n     * As the vote is considered to be done in small organization context, and to prevent dos gas limit, the maximum number of proposals is limited to 256.\n     * A vote can be added only by registered voter when status is set to VotingSessionStarted\n     *\n     * @param description The proposal description',
+				'* Administrator can add a question to the session ballot.\n     *\n     * @dev Can be called only when status is set to ProposalsRegistrationStarted.\n     * The session itself is the main question (id 0), added questions get their own `Blank` proposal and result.\n     * An event QuestionAdded is emitted\n     *\n     * @param title The question title',
 			];
-			discriminator: [255, 112, 186, 111, 67, 158, 20, 87];
+			discriminator: [164, 157, 121, 228, 98, 242, 205, 193];
 			accounts: [
 				{
-					name: 'proposer';
+					name: 'admin';
 					writable: true;
 					signer: true;
 				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
 				{
 					name: 'sessionAccount';
 					writable: true;
 				},
 				{
-					name: 'voterAccount';
+					name: 'questionAccount';
 					writable: true;
 				},
 				{
-					name: 'proposalAccount';
+					name: 'blankProposalAccount';
 					writable: true;
 				},
 				{
@@ -121,23 +149,34 @@ export type Voting = {
 			];
 			args: [
 				{
-					name: 'description';
+					name: 'title';
 					type: 'string';
 				},
 			];
 		},
 		{
-			name: 'registerVoter';
+			name: 'allocateVotes';
 			docs: [
-				'* Session administrator can register voters.\n     *\n     * @dev voters can be added only by session administrator when status is set to RegisteringVoters\n     * An event VoterRegistered is emitted\n     *\n     * @param voter The address to add into voters registry',
+				'* A voter can spread votes across the session proposals.\n     *\n     * @dev Can be called only in quadratic or cumulative mode when status is set to VotingSessionStarted.\n     * Voters spend down their budget across allocations, each proposal being allocated votes once.\n     * In quadratic mode, n votes on a proposal cost n² credits out of the voter credits, or the session credits.\n     * In cumulative mode, each voter holds one point per seat, or the configured points, to stack on one proposal or split.\n     * The allocations are recorded in the voter ballot account and can be audited against the proposals votes count.\n     * Voted proposals are given as remaining accounts in increasing id order, their votes count is increased by the allocated votes.\n     * The vote receipt ballot is the (proposal id, value) pairs of the entries.\n     * Events BallotCast and VoteReceiptAdded are emitted\n     *\n     * @param allocations The votes given to each proposal',
 			];
-			discriminator: [229, 124, 185, 99, 118, 51, 226, 6];
+			discriminator: [251, 165, 222, 178, 17, 93, 169, 204];
 			accounts: [
 				{
-					name: 'admin';
+					name: 'voter';
 					writable: true;
 					signer: true;
 				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
 				{
 					name: 'sessionAccount';
 					writable: true;
@@ -145,11 +184,15 @@ export type Voting = {
 				{
 					name: 'voterAccount';
 					writable: true;
+				},
+				{
+					name: 'ballotAccount';
+					writable: true;
 					pda: {
 						seeds: [
 							{
 								kind: 'const';
-								value: [118, 111, 116, 101, 114];
+								value: [98, 97, 108, 108, 111, 116];
 							},
 							{
 								kind: 'account';
@@ -157,12 +200,29 @@ export type Voting = {
 								account: 'sessionAccount';
 							},
 							{
-								kind: 'arg';
+								kind: 'account';
 								path: 'voter';
 							},
 						];
 					};
 				},
+				{
+					name: 'receiptsAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [114, 101, 99, 101, 105, 112, 116, 115];
+							},
+							{
+								kind: 'account';
+								path: 'session_account.session_id';
+								account: 'sessionAccount';
+							},
+						];
+					};
+				},
 				{
 					name: 'systemProgram';
 					address: '11111111111111111111111111111111';
@@ -170,98 +230,104 @@ export type Voting = {
 			];
 			args: [
 				{
-					name: 'voter';
-					type: 'pubkey';
+					name: 'allocations';
+					type: {
+						vec: {
+							defined: {
+								name: 'ballotEntry';
+							};
+						};
+					};
 				},
 			];
 		},
 		{
-			name: 'startProposalsRegistration';
+			name: 'applyVotingPower';
 			docs: [
-				'* Administrator can close voters registration and open proposals registration.\n     *\n     * @dev Can be called only when status is set to RegisteringVoters.\n     * Two default proposals are registered at the beginning of this step: `Abstention` and `Blank`.\n     * That means a registered voter that forget to vote will be counted as `abstention` thanks to voter registration account and initialized state\n     * An event WorkflowStatusChanged is emitted',
+				'* A voter can use the voting power of their locked tokens as weight.\n     *\n     * @dev Can be called only in sessions configured with a lock mint, when status is set to VotingSessionStarted and before voting.\n     * The power is read from the voter snapshot, taken before voting opened, and evaluated at voting opening time.\n     * An event VotingPowerApplied is emitted',
 			];
-			discriminator: [186, 177, 117, 107, 84, 210, 40, 48];
+			discriminator: [53, 79, 79, 159, 206, 46, 224, 75];
 			accounts: [
 				{
-					name: 'admin';
-					writable: true;
+					name: 'voter';
 					signer: true;
 				},
 				{
-					name: 'sessionAccount';
-					writable: true;
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
 				},
 				{
-					name: 'blankProposalAccount';
+					name: 'sessionAccount';
 					writable: true;
 				},
 				{
-					name: 'systemProgram';
-					address: '11111111111111111111111111111111';
-				},
-			];
-			args: [];
-		},
-		{
-			name: 'startVotingSession';
-			docs: [
-				'* Administrator can open voting session.\n     *\n     * @dev Can be called only when status is set to ProposalsRegistrationEnded.\n     * An event WorkflowStatusChange is emitted',
-			];
-			discriminator: [164, 58, 7, 145, 162, 194, 204, 56];
-			accounts: [
-				{
-					name: 'admin';
+					name: 'voterAccount';
 					writable: true;
-					signer: true;
 				},
 				{
-					name: 'sessionAccount';
-					writable: true;
+					name: 'mint';
 				},
 				{
-					name: 'systemProgram';
-					address: '11111111111111111111111111111111';
+					name: 'snapshotAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [115, 110, 97, 112, 115, 104, 111, 116];
+							},
+							{
+								kind: 'account';
+								path: 'session_account.session_id';
+								account: 'sessionAccount';
+							},
+							{
+								kind: 'account';
+								path: 'voter';
+							},
+						];
+					};
 				},
 			];
 			args: [];
 		},
 		{
-			name: 'stopProposalsRegistration';
+			name: 'attachProposalTransaction';
 			docs: [
-				'* Administrator can close proposals registration.\n     *\n     * @dev Can be called only when status is set to ProposalsRegistrationStarted.\n     * An event WorkflowStatusChange is emitted',
+				'* A proposer can attach on-chain instructions to a registered proposal.\n     *\n     * @dev Can be called only when status is set to ProposalsRegistrationStarted, so voters know what they vote for.\n     * Instructions are signed by the session executor PDA when the proposal wins.\n     * An event ProposalTransactionAttached is emitted\n     *\n     * @param instructions The instructions to invoke when the proposal wins',
 			];
-			discriminator: [190, 223, 19, 127, 225, 61, 4, 11];
+			discriminator: [123, 117, 227, 150, 17, 18, 182, 48];
 			accounts: [
 				{
-					name: 'admin';
+					name: 'proposer';
 					writable: true;
 					signer: true;
 				},
 				{
-					name: 'sessionAccount';
-					writable: true;
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
 				},
 				{
-					name: 'systemProgram';
-					address: '11111111111111111111111111111111';
+					name: 'sessionAccount';
 				},
-			];
-			args: [];
-		},
-		{
-			name: 'stopVotingSession';
-			docs: [
-				'* Administrator can close voting session.\n     *\n     * @dev Can be called only when status is set to VotingSessionStarted.\n     * An event WorkflowStatusChange is emitted',
-			];
-			discriminator: [244, 180, 9, 118, 55, 224, 199, 73];
-			accounts: [
 				{
-					name: 'admin';
-					writable: true;
-					signer: true;
+					name: 'proposalAccount';
 				},
 				{
-					name: 'sessionAccount';
+					name: 'proposalTransactionAccount';
 					writable: true;
 				},
 				{
@@ -269,48 +335,44 @@ export type Voting = {
 					address: '11111111111111111111111111111111';
 				},
 			];
-			args: [];
-		},
-		{
-			name: 'tallyVotes';
-			docs: [
-				'* Administrator can trigger votes talling.\n     *\n     * @dev After votes talling, it is possible that we got many winning proposals.\n     * Votes talling can be triggered only by voting session administrator when voting session status is set to VotingSessionEnded\n     * Events WorkflowStatusChange and VotesTallied are emitted\n     *',
-			];
-			discriminator: [144, 82, 0, 72, 160, 132, 35, 121];
-			accounts: [
-				{
-					name: 'admin';
-					writable: true;
-					signer: true;
-				},
-				{
-					name: 'sessionAccount';
-					writable: true;
-				},
+			args: [
 				{
-					name: 'systemProgram';
-					address: '11111111111111111111111111111111';
+					name: 'instructions';
+					type: {
+						vec: {
+							defined: {
+								name: 'proposalInstruction';
+							};
+						};
+					};
 				},
 			];
-			args: [];
 		},
 		{
-			name: 'vote';
+			name: 'castAnonymousVote';
 			docs: [
-				'* A voter can register his vote for a proposal.\n     *\n     * @dev Each voter can vote only once for one proposal.\n     * Votes can be added only by registered voter when status is set to VotingSessionStarted',
+				'* Anyone can relay an anonymous vote, proven by a zero-knowledge proof of membership in the session identity tree.\n     *\n     * @dev Can be called only when status is set to VotingSessionStarted, in an anonymous session.\n     * The session id is the external nullifier and the proposal id the signal, so each identity votes once and the choice can not be changed.\n     * The nullifier hash account creation fails if it was already used.\n     * The verifying key must match the one snapshotted when anonymous voting was configured.\n     * The vote receipt voter is the nullifier hash and its ballot the proposal id.\n     * Events AnonymousVoted and VoteReceiptAdded are emitted\n     *\n     * @param proposal_id The proposal id\n     * @param nullifier_hash The identity nullifier hash for the session\n     * @param proof The Groth16 membership proof',
 			];
-			discriminator: [227, 110, 155, 23, 136, 126, 172, 25];
+			discriminator: [18, 22, 170, 69, 158, 84, 226, 62];
 			accounts: [
 				{
-					name: 'voter';
+					name: 'relayer';
 					writable: true;
 					signer: true;
 				},
 				{
-					name: 'sessionAccount';
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
 				},
 				{
-					name: 'voterAccount';
+					name: 'sessionAccount';
 					writable: true;
 				},
 				{
@@ -318,134 +380,6329 @@ export type Voting = {
 					writable: true;
 				},
 				{
-					name: 'systemProgram';
-					address: '11111111111111111111111111111111';
-				},
-			];
+					name: 'identityTreeAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [105, 100, 101, 110, 116, 105, 116, 121, 95, 116, 114, 101, 101];
+							},
+							{
+								kind: 'account';
+								path: 'session_account.session_id';
+								account: 'sessionAccount';
+							},
+						];
+					};
+				},
+				{
+					name: 'verifyingKeyAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [118, 101, 114, 105, 102, 121, 105, 110, 103, 95, 107, 101, 121];
+							},
+						];
+					};
+				},
+				{
+					name: 'nullifierAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [110, 117, 108, 108, 105, 102, 105, 101, 114];
+							},
+							{
+								kind: 'account';
+								path: 'session_account.session_id';
+								account: 'sessionAccount';
+							},
+							{
+								kind: 'arg';
+								path: 'nullifierHash';
+							},
+						];
+					};
+				},
+				{
+					name: 'receiptsAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [114, 101, 99, 101, 105, 112, 116, 115];
+							},
+							{
+								kind: 'account';
+								path: 'session_account.session_id';
+								account: 'sessionAccount';
+							},
+						];
+					};
+				},
+				{
+					name: 'systemProgram';
+					address: '11111111111111111111111111111111';
+				},
+			];
+			args: [
+				{
+					name: 'proposalId';
+					type: 'u8';
+				},
+				{
+					name: 'nullifierHash';
+					type: {
+						array: ['u8', 32];
+					};
+				},
+				{
+					name: 'proof';
+					type: {
+						defined: {
+							name: 'groth16Proof';
+						};
+					};
+				},
+			];
+		},
+		{
+			name: 'castEncryptedVote';
+			docs: [
+				'* Voter adds the encryption of its choice for a proposal to its encrypted ballot.\n     *\n     * @dev Can be called only when status is set to VotingSessionStarted, in an encrypted session.\n     * Ciphertexts must be cast for every proposal, blank proposal included, in proposal order.\n     * The proof shows the ciphertext encrypts 0 or 1 without revealing which.\n     *\n     * @param proposal_id The proposal id\n     * @param ciphertext The ElGamal encryption of 1 for the chosen proposal, 0 otherwise\n     * @param proof The disjunctive Chaum-Pedersen proof of the ciphertext',
+			];
+			discriminator: [141, 245, 116, 4, 108, 150, 192, 127];
+			accounts: [
+				{
+					name: 'voter';
+					writable: true;
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'sessionAccount';
+				},
+				{
+					name: 'voterAccount';
+				},
+				{
+					name: 'encryptedBallotAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [101, 110, 99, 114, 121, 112, 116, 101, 100, 95, 98, 97, 108, 108, 111, 116];
+							},
+							{
+								kind: 'account';
+								path: 'session_account.session_id';
+								account: 'sessionAccount';
+							},
+							{
+								kind: 'account';
+								path: 'voter';
+							},
+						];
+					};
+				},
+				{
+					name: 'systemProgram';
+					address: '11111111111111111111111111111111';
+				},
+			];
+			args: [
+				{
+					name: 'proposalId';
+					type: 'u8';
+				},
+				{
+					name: 'ciphertext';
+					type: {
+						defined: {
+							name: 'ciphertext';
+						};
+					};
+				},
+				{
+					name: 'proof';
+					type: {
+						defined: {
+							name: 'bitProof';
+						};
+					};
+				},
+			];
+		},
+		{
+			name: 'claimReward';
+			docs: [
+				'* A voter who voted can claim its participation reward.\n     *\n     * @dev Can be called only when status is set to VotesTallied, once per voter, until the claim duration elapsed since the tally.\n     * An event RewardClaimed is emitted',
+			];
+			discriminator: [149, 95, 181, 242, 94, 90, 158, 162];
+			accounts: [
+				{
+					name: 'voter';
+					writable: true;
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'sessionAccount';
+				},
+				{
+					name: 'voterAccount';
+					writable: true;
+				},
+				{
+					name: 'rewardPoolAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [114, 101, 119, 97, 114, 100, 95, 112, 111, 111, 108];
+							},
+							{
+								kind: 'account';
+								path: 'session_account.session_id';
+								account: 'sessionAccount';
+							},
+						];
+					};
+				},
+				{
+					name: 'mint';
+					optional: true;
+				},
+				{
+					name: 'poolTokenAccount';
+					writable: true;
+					optional: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'account';
+								path: 'rewardPoolAccount';
+							},
+							{
+								kind: 'const';
+								value: [6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133, 126, 255, 0, 169];
+							},
+							{
+								kind: 'account';
+								path: 'mint';
+							},
+						];
+						program: {
+							kind: 'const';
+							value: [140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142, 13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216, 219, 233, 248, 89];
+						};
+					};
+				},
+				{
+					name: 'voterTokenAccount';
+					writable: true;
+					optional: true;
+				},
+				{
+					name: 'tokenProgram';
+					optional: true;
+					address: 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA';
+				},
+			];
 			args: [];
 		},
-	];
-	accounts: [
 		{
-			name: 'globalAccount';
-			discriminator: [129, 105, 124, 171, 189, 42, 108, 69];
+			name: 'configureAnonymousVoting';
+			docs: [
+				'* Session administrator can make votes anonymous, voters proving their membership in a session identity tree.\n     *\n     * @dev Can be called only when status is set to RegisteringVoters, for a plurality session with a single seat and no added question.\n     * Creates the session identity tree, a Poseidon Merkle tree of the voters identity commitments.\n     * The verifying key hash is snapshotted in the identity tree, votes being verified with this key only.\n     * An event AnonymousVotingConfigured is emitted',
+			];
+			discriminator: [202, 245, 30, 116, 121, 40, 119, 23];
+			accounts: [
+				{
+					name: 'admin';
+					writable: true;
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'sessionAccount';
+					writable: true;
+				},
+				{
+					name: 'identityTreeAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [105, 100, 101, 110, 116, 105, 116, 121, 95, 116, 114, 101, 101];
+							},
+							{
+								kind: 'account';
+								path: 'session_account.session_id';
+								account: 'sessionAccount';
+							},
+						];
+					};
+				},
+				{
+					name: 'verifyingKeyAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [118, 101, 114, 105, 102, 121, 105, 110, 103, 95, 107, 101, 121];
+							},
+						];
+					};
+				},
+				{
+					name: 'systemProgram';
+					address: '11111111111111111111111111111111';
+				},
+			];
+			args: [];
+		},
+		{
+			name: 'configureEncryption';
+			docs: [
+				'* Session administrator can make ballots secret, encrypted under the public key of a t-of-n trustees group.\n     *\n     * @dev Can be called only when status is set to RegisteringVoters, for a plurality session with a single seat and no added question.\n     * Ballots are exponential ElGamal ciphertexts on ristretto255, only the aggregated votes of each proposal are ever decrypted.\n     * Verification keys must lie on the same degree threshold - 1 polynomial as the election public key, so that any threshold trustees can decrypt.\n     * An event EncryptionConfigured is emitted\n     *\n     * @param election_public_key The compressed ristretto election public key, shared among the trustees\n     * @param threshold The number of trustees needed to decrypt\n     * @param trustees The trustees authorities and the verification keys of their secret shares, share index being the trustee position + 1',
+			];
+			discriminator: [27, 130, 116, 89, 248, 45, 212, 181];
+			accounts: [
+				{
+					name: 'admin';
+					writable: true;
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'sessionAccount';
+					writable: true;
+				},
+			];
+			args: [
+				{
+					name: 'electionPublicKey';
+					type: {
+						array: ['u8', 32];
+					};
+				},
+				{
+					name: 'threshold';
+					type: 'u8';
+				},
+				{
+					name: 'trustees';
+					type: {
+						vec: {
+							defined: {
+								name: 'trustee';
+							};
+						};
+					};
+				},
+			];
+		},
+		{
+			name: 'configureExecution';
+			docs: [
+				'* Session administrator can configure the timelock and veto window applied to the result.\n     *\n     * @dev Can be called only when status is set to RegisteringVoters.\n     * An event ExecutionConfigured is emitted\n     *\n     * @param execution_delay The timelock in seconds between votes talling and result release, at most one year\n     * @param veto_council The address allowed to veto the result during the timelock',
+			];
+			discriminator: [7, 203, 227, 203, 226, 9, 147, 60];
+			accounts: [
+				{
+					name: 'admin';
+					writable: true;
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'sessionAccount';
+					writable: true;
+				},
+			];
+			args: [
+				{
+					name: 'executionDelay';
+					type: 'i64';
+				},
+				{
+					name: 'vetoCouncil';
+					type: {
+						option: 'pubkey';
+					};
+				},
+			];
+		},
+		{
+			name: 'configureProposals';
+			docs: [
+				'* Session administrator can configure proposals registration rules.\n     *\n     * @dev Can be called only when status is set to RegisteringVoters.\n     * The optional deposit (SOL or SPL when a mint is set) is escrowed in the session vault at each proposal registration.\n     * The number of proposals a voter can register is capped by `max_proposals_per_voter`.\n     * An event ProposalsConfigured is emitted\n     *\n     * @param config The proposals configuration',
+			];
+			discriminator: [232, 77, 49, 0, 181, 67, 154, 10];
+			accounts: [
+				{
+					name: 'admin';
+					writable: true;
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'sessionAccount';
+					writable: true;
+				},
+				{
+					name: 'vaultAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [118, 97, 117, 108, 116];
+							},
+							{
+								kind: 'account';
+								path: 'session_account.session_id';
+								account: 'sessionAccount';
+							},
+						];
+					};
+				},
+				{
+					name: 'depositMint';
+					optional: true;
+				},
+				{
+					name: 'vaultTokenAccount';
+					writable: true;
+					optional: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'account';
+								path: 'vaultAccount';
+							},
+							{
+								kind: 'const';
+								value: [6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133, 126, 255, 0, 169];
+							},
+							{
+								kind: 'account';
+								path: 'depositMint';
+							},
+						];
+						program: {
+							kind: 'const';
+							value: [140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142, 13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216, 219, 233, 248, 89];
+						};
+					};
+				},
+				{
+					name: 'tokenProgram';
+					optional: true;
+					address: 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA';
+				},
+				{
+					name: 'associatedTokenProgram';
+					optional: true;
+					address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL';
+				},
+				{
+					name: 'systemProgram';
+					address: '11111111111111111111111111111111';
+				},
+			];
+			args: [
+				{
+					name: 'config';
+					type: {
+						defined: {
+							name: 'proposalsConfig';
+						};
+					};
+				},
+			];
+		},
+		{
+			name: 'configureVotingMode';
+			docs: [
+				'* Session administrator can configure how ballots are cast and tallied.\n     *\n     * @dev Can be called only when status is set to RegisteringVoters.\n     * Sessions use plurality voting by default, other modes rank the session proposals as a whole and do not support added questions.\n     * With many seats, the best ranked proposals are elected, plurality voters get one vote per seat (block voting).\n     * An event VotingModeConfigured is emitted\n     *\n     * @param voting_mode The session voting mode\n     * @param seats The number of proposals to elect',
+			];
+			discriminator: [97, 129, 77, 140, 96, 7, 99, 177];
+			accounts: [
+				{
+					name: 'admin';
+					writable: true;
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'sessionAccount';
+					writable: true;
+				},
+			];
+			args: [
+				{
+					name: 'votingMode';
+					type: {
+						defined: {
+							name: 'votingMode';
+						};
+					};
+				},
+				{
+					name: 'seats';
+					type: 'u8';
+				},
+			];
+		},
+		{
+			name: 'configureVotingPower';
+			docs: [
+				'* Session administrator can make voters weight the voting power of their locked tokens.\n     *\n     * @dev Can be called only when status is set to RegisteringVoters.\n     * Voters of such sessions can only vote once their voting power is applied.\n     * An event VotingPowerConfigured is emitted\n     *\n     * @param lock_mint The mint of the locked tokens, or none to keep registered weights',
+			];
+			discriminator: [7, 192, 125, 211, 28, 181, 180, 119];
+			accounts: [
+				{
+					name: 'admin';
+					writable: true;
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'sessionAccount';
+					writable: true;
+				},
+			];
+			args: [
+				{
+					name: 'lockMint';
+					type: {
+						option: 'pubkey';
+					};
+				},
+			];
+		},
+		{
+			name: 'createOrganization';
+			docs: [
+				"* Anyone can create a new organization. Organization's creator becomes its first administrator.\n     *\n     * @dev An event OrganizationCreated is emitted\n     *\n     * @param name The organization name",
+			];
+			discriminator: [60, 173, 177, 39, 122, 23, 68, 185];
+			accounts: [
+				{
+					name: 'owner';
+					writable: true;
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'organizationAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [111, 114, 103, 97, 110, 105, 122, 97, 116, 105, 111, 110];
+							},
+							{
+								kind: 'account';
+								path: 'global_account.organization_count';
+								account: 'globalAccount';
+							},
+						];
+					};
+				},
+				{
+					name: 'systemProgram';
+					address: '11111111111111111111111111111111';
+				},
+			];
+			args: [
+				{
+					name: 'name';
+					type: 'string';
+				},
+			];
+		},
+		{
+			name: 'createVotingSession';
+			docs: [
+				"* Anyone can create new voting session. Session's creator becomes session administrator.\n     *\n     * @dev The global session fee is paid to the protocol authority.\n     * When an organization account is given, the creator must be one of its administrators\n     * and the organization members at creation time are eligible to join the session as voters.\n     * The session id is appended to the creator sessions index (`admin_sessions` PDAs).\n     * An event SessionCreated is emitted\n     *\n     * @param name The session name\n     * @param description The session description\n     * @param metadata_uri The off-chain session document URI, empty if none\n     * @param metadata_hash The SHA-256 of the off-chain session document",
+			];
+			discriminator: [241, 56, 27, 243, 109, 166, 75, 93];
+			accounts: [
+				{
+					name: 'owner';
+					writable: true;
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'authority';
+					writable: true;
+				},
+				{
+					name: 'sessionAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [115, 101, 115, 115, 105, 111, 110];
+							},
+							{
+								kind: 'account';
+								path: 'global_account.session_count';
+								account: 'globalAccount';
+							},
+						];
+					};
+				},
+				{
+					name: 'organizationAccount';
+					optional: true;
+				},
+				{
+					name: 'adminSessionIndex';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [97, 100, 109, 105, 110, 95, 115, 101, 115, 115, 105, 111, 110, 115];
+							},
+							{
+								kind: 'account';
+								path: 'owner';
+							},
+						];
+					};
+				},
+				{
+					name: 'adminSessionIndexPage';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [97, 100, 109, 105, 110, 95, 115, 101, 115, 115, 105, 111, 110, 115];
+							},
+							{
+								kind: 'account';
+								path: 'owner';
+							},
+							{
+								kind: 'account';
+								path: 'adminSessionIndex';
+							},
+						];
+					};
+				},
+				{
+					name: 'systemProgram';
+					address: '11111111111111111111111111111111';
+				},
+			];
+			args: [
+				{
+					name: 'name';
+					type: 'string';
+				},
+				{
+					name: 'description';
+					type: 'string';
+				},
+				{
+					name: 'metadataUri';
+					type: 'string';
+				},
+				{
+					name: 'metadataHash';
+					type: {
+						array: ['u8', 32];
+					};
+				},
+			];
+		},
+		{
+			name: 'depositToTreasury';
+			docs: [
+				'* Anyone can deposit SOL or SPL tokens into a session treasury.\n     *\n     * @dev When a mint account is given, tokens are transferred to the treasury associated token account.\n     * An event TreasuryDeposited is emitted\n     *\n     * @param amount The amount to deposit',
+			];
+			discriminator: [10, 195, 112, 242, 107, 206, 240, 198];
+			accounts: [
+				{
+					name: 'depositor';
+					writable: true;
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'sessionAccount';
+				},
+				{
+					name: 'treasuryAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [116, 114, 101, 97, 115, 117, 114, 121];
+							},
+							{
+								kind: 'account';
+								path: 'session_account.session_id';
+								account: 'sessionAccount';
+							},
+						];
+					};
+				},
+				{
+					name: 'mint';
+					optional: true;
+				},
+				{
+					name: 'depositorTokenAccount';
+					writable: true;
+					optional: true;
+				},
+				{
+					name: 'treasuryTokenAccount';
+					writable: true;
+					optional: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'account';
+								path: 'treasuryAccount';
+							},
+							{
+								kind: 'const';
+								value: [6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133, 126, 255, 0, 169];
+							},
+							{
+								kind: 'account';
+								path: 'mint';
+							},
+						];
+						program: {
+							kind: 'const';
+							value: [140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142, 13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216, 219, 233, 248, 89];
+						};
+					};
+				},
+				{
+					name: 'tokenProgram';
+					optional: true;
+					address: 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA';
+				},
+				{
+					name: 'associatedTokenProgram';
+					optional: true;
+					address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL';
+				},
+				{
+					name: 'systemProgram';
+					address: '11111111111111111111111111111111';
+				},
+			];
+			args: [
+				{
+					name: 'amount';
+					type: 'u64';
+				},
+			];
+		},
+		{
+			name: 'enableBadges';
+			docs: [
+				'* Session administrator can enable participation badges, non-transferable Token-2022 tokens minted to voters who voted.\n     *\n     * @dev Creates the session badge mint, with the non-transferable and metadata pointer extensions.\n     * Badge metadata, stored in the mint, holds the session name and metadata uri, and a session_id field.\n     * An event BadgesEnabled is emitted',
+			];
+			discriminator: [95, 151, 117, 223, 4, 114, 91, 119];
+			accounts: [
+				{
+					name: 'admin';
+					writable: true;
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'sessionAccount';
+					writable: true;
+				},
+				{
+					name: 'badgeMint';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [98, 97, 100, 103, 101, 95, 109, 105, 110, 116];
+							},
+							{
+								kind: 'account';
+								path: 'session_account.session_id';
+								account: 'sessionAccount';
+							},
+						];
+					};
+				},
+				{
+					name: 'tokenProgram';
+					address: 'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb';
+				},
+				{
+					name: 'systemProgram';
+					address: '11111111111111111111111111111111';
+				},
+			];
+			args: [];
+		},
+		{
+			name: 'executeWinningProposal';
+			docs: [
+				'* Anyone can execute the instructions attached to the winning proposal.\n     *\n     * @dev Can be called only when status is set to VotesTallied, once the result is released,\n     * and only if the proposal is the single winning proposal.\n     * Every account used by the instructions, including invoked programs, must be passed as remaining accounts.\n     * An event ProposalExecuted is emitted',
+			];
+			discriminator: [2, 35, 167, 74, 211, 163, 58, 247];
+			accounts: [
+				{
+					name: 'payer';
+					writable: true;
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'sessionAccount';
+				},
+				{
+					name: 'proposalTransactionAccount';
+					writable: true;
+				},
+				{
+					name: 'executor';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [101, 120, 101, 99, 117, 116, 111, 114];
+							},
+							{
+								kind: 'account';
+								path: 'session_account.session_id';
+								account: 'sessionAccount';
+							},
+						];
+					};
+				},
+			];
+			args: [];
+		},
+		{
+			name: 'fundRewardPool';
+			docs: [
+				'* Session administrator can fund a participation reward pool, in SOL or SPL tokens.\n     *\n     * @dev Can be called once, before votes tallying. SOL rewards are used when no mint is given.\n     * The claim window opens when votes are tallied and lasts at most a year.\n     * An event RewardPoolFunded is emitted\n     *\n     * @param amount The amount deposited in the pool\n     * @param reward_per_voter The amount each voter who voted can claim\n     * @param claim_duration The number of seconds rewards can be claimed for, from the votes tallying',
+			];
+			discriminator: [85, 49, 108, 245, 204, 70, 243, 3];
+			accounts: [
+				{
+					name: 'admin';
+					writable: true;
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'sessionAccount';
+				},
+				{
+					name: 'rewardPoolAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [114, 101, 119, 97, 114, 100, 95, 112, 111, 111, 108];
+							},
+							{
+								kind: 'account';
+								path: 'session_account.session_id';
+								account: 'sessionAccount';
+							},
+						];
+					};
+				},
+				{
+					name: 'mint';
+					optional: true;
+				},
+				{
+					name: 'adminTokenAccount';
+					writable: true;
+					optional: true;
+				},
+				{
+					name: 'poolTokenAccount';
+					writable: true;
+					optional: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'account';
+								path: 'rewardPoolAccount';
+							},
+							{
+								kind: 'const';
+								value: [6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133, 126, 255, 0, 169];
+							},
+							{
+								kind: 'account';
+								path: 'mint';
+							},
+						];
+						program: {
+							kind: 'const';
+							value: [140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142, 13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216, 219, 233, 248, 89];
+						};
+					};
+				},
+				{
+					name: 'tokenProgram';
+					optional: true;
+					address: 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA';
+				},
+				{
+					name: 'associatedTokenProgram';
+					optional: true;
+					address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL';
+				},
+				{
+					name: 'systemProgram';
+					address: '11111111111111111111111111111111';
+				},
+			];
+			args: [
+				{
+					name: 'amount';
+					type: 'u64';
+				},
+				{
+					name: 'rewardPerVoter';
+					type: 'u64';
+				},
+				{
+					name: 'claimDuration';
+					type: 'i64';
+				},
+			];
+		},
+		{
+			name: 'getProposalOutcome';
+			docs: [
+				'* Anyone, including other programs through CPI, can read the outcome of a proposal of a tallied session.\n     *\n     * @dev Read only, the outcome is returned with set_return_data, and is read with `Return::get` by CPI callers.\n     * Can be called only when status is set to VotesTallied, vetoed sessions have no outcome.\n     * Callers relying on a timelocked result should also check the outcome released_at.\n     * Proposals of added questions are read among their question result, the question account is then required.',
+			];
+			discriminator: [76, 244, 160, 53, 19, 155, 200, 235];
+			accounts: [
+				{
+					name: 'sessionAccount';
+				},
+				{
+					name: 'proposalAccount';
+				},
+				{
+					name: 'questionAccount';
+					optional: true;
+				},
+			];
+			args: [];
+			returns: {
+				defined: {
+					name: 'proposalOutcome';
+				};
+			};
+		},
+		{
+			name: 'initGlobal';
+			docs: [
+				'* Program upgrade authority initializes the global account and becomes protocol authority.\n     *\n     * @dev An event GlobalConfigUpdated is emitted\n     *\n     * @param config The protocol configuration (session fee, name and description max lengths, max voters)',
+			];
+			discriminator: [44, 238, 77, 253, 76, 182, 192, 162];
+			accounts: [
+				{
+					name: 'globalAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'owner';
+					writable: true;
+					signer: true;
+				},
+				{
+					name: 'program';
+					address: '8H7dfzoBA4kJjokGqDgQ4VsDeBqg7SuMWFUWowdvRQda';
+				},
+				{
+					name: 'programData';
+				},
+				{
+					name: 'systemProgram';
+					address: '11111111111111111111111111111111';
+				},
+			];
+			args: [
+				{
+					name: 'config';
+					type: {
+						defined: {
+							name: 'globalConfig';
+						};
+					};
+				},
+			];
+		},
+		{
+			name: 'joinSession';
+			docs: [
+				'* An organization member can join an organization session as voter.\n     *\n     * @dev Replaces register_voter for organization sessions. Can be called until voting session starts.\n     * Only members registered when the session was created are eligible, joined members are the session voters.\n     * The session id is appended to the voter sessions index (`voter_sessions` PDAs).\n     * An event VoterRegistered is emitted',
+			];
+			discriminator: [23, 92, 4, 160, 155, 56, 164, 253];
+			accounts: [
+				{
+					name: 'member';
+					writable: true;
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'sessionAccount';
+					writable: true;
+				},
+				{
+					name: 'memberAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [109, 101, 109, 98, 101, 114];
+							},
+							{
+								kind: 'account';
+								path: 'member_account.organization_id';
+								account: 'organizationMemberAccount';
+							},
+							{
+								kind: 'account';
+								path: 'member';
+							},
+						];
+					};
+				},
+				{
+					name: 'voterAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [118, 111, 116, 101, 114];
+							},
+							{
+								kind: 'account';
+								path: 'session_account.session_id';
+								account: 'sessionAccount';
+							},
+							{
+								kind: 'account';
+								path: 'member';
+							},
+						];
+					};
+				},
+				{
+					name: 'voterSessionIndex';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [118, 111, 116, 101, 114, 95, 115, 101, 115, 115, 105, 111, 110, 115];
+							},
+							{
+								kind: 'account';
+								path: 'member';
+							},
+						];
+					};
+				},
+				{
+					name: 'voterSessionIndexPage';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [118, 111, 116, 101, 114, 95, 115, 101, 115, 115, 105, 111, 110, 115];
+							},
+							{
+								kind: 'account';
+								path: 'member';
+							},
+							{
+								kind: 'account';
+								path: 'voterSessionIndex';
+							},
+						];
+					};
+				},
+				{
+					name: 'systemProgram';
+					address: '11111111111111111111111111111111';
+				},
+			];
+			args: [];
+		},
+		{
+			name: 'lockTokens';
+			docs: [
+				'* Anyone can lock SPL tokens in a program owned vault to get voting power.\n     *\n     * @dev The voting power is the locked amount scaled by the remaining lock time over the maximum lock duration, decaying linearly.\n     * A lock can be topped up or extended, but never shortened.\n     * An event TokensLocked is emitted\n     *\n     * @param amount The amount of tokens to add to the lock\n     * @param duration The lock duration in seconds from now, up to 4 years',
+			];
+			discriminator: [136, 11, 32, 232, 161, 117, 54, 211];
+			accounts: [
+				{
+					name: 'owner';
+					writable: true;
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'mint';
+				},
+				{
+					name: 'lockAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [108, 111, 99, 107];
+							},
+							{
+								kind: 'account';
+								path: 'mint';
+							},
+							{
+								kind: 'account';
+								path: 'owner';
+							},
+						];
+					};
+				},
+				{
+					name: 'ownerTokenAccount';
+					writable: true;
+				},
+				{
+					name: 'lockTokenAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'account';
+								path: 'lockAccount';
+							},
+							{
+								kind: 'const';
+								value: [6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133, 126, 255, 0, 169];
+							},
+							{
+								kind: 'account';
+								path: 'mint';
+							},
+						];
+						program: {
+							kind: 'const';
+							value: [140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142, 13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216, 219, 233, 248, 89];
+						};
+					};
+				},
+				{
+					name: 'tokenProgram';
+					address: 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA';
+				},
+				{
+					name: 'associatedTokenProgram';
+					address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL';
+				},
+				{
+					name: 'systemProgram';
+					address: '11111111111111111111111111111111';
+				},
+			];
+			args: [
+				{
+					name: 'amount';
+					type: 'u64';
+				},
+				{
+					name: 'duration';
+					type: 'i64';
+				},
+			];
+		},
+		{
+			name: 'mintBadge';
+			docs: [
+				'* A voter who voted can mint its session participation badge, once.\n     *\n     * @dev The badge can also be minted along the vote, by giving the optional badge accounts to `vote`, which skips voters already holding it.\n     * An event BadgeMinted is emitted',
+			];
+			discriminator: [242, 234, 237, 183, 232, 245, 146, 1];
+			accounts: [
+				{
+					name: 'voter';
+					writable: true;
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'sessionAccount';
+				},
+				{
+					name: 'voterAccount';
+					writable: true;
+				},
+				{
+					name: 'badgeMint';
+					writable: true;
+				},
+				{
+					name: 'voterBadgeAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'account';
+								path: 'voter';
+							},
+							{
+								kind: 'account';
+								path: 'tokenProgram';
+							},
+							{
+								kind: 'account';
+								path: 'badgeMint';
+							},
+						];
+						program: {
+							kind: 'const';
+							value: [140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142, 13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216, 219, 233, 248, 89];
+						};
+					};
+				},
+				{
+					name: 'tokenProgram';
+					address: 'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb';
+				},
+				{
+					name: 'associatedTokenProgram';
+					address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL';
+				},
+				{
+					name: 'systemProgram';
+					address: '11111111111111111111111111111111';
+				},
+			];
+			args: [];
+		},
+		{
+			name: 'postDecryptionShare';
+			docs: [
+				'* Trustee posts its decryption share of a proposal encrypted votes.\n     *\n     * @dev Can be called only when status is set to VotingSessionEnded.\n     * The proof shows the share uses the same secret as the trustee verification key.\n     * An event DecryptionSharePosted is emitted\n     *\n     * @param share The trustee secret share applied to the first ciphertext component\n     * @param proof The Chaum-Pedersen proof of the share',
+			];
+			discriminator: [232, 255, 3, 164, 196, 150, 114, 28];
+			accounts: [
+				{
+					name: 'trustee';
+					writable: true;
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'sessionAccount';
+				},
+				{
+					name: 'proposalAccount';
+					writable: true;
+				},
+			];
+			args: [
+				{
+					name: 'share';
+					type: {
+						array: ['u8', 32];
+					};
+				},
+				{
+					name: 'proof';
+					type: {
+						defined: {
+							name: 'equalityProof';
+						};
+					};
+				},
+			];
+		},
+		{
+			name: 'reclaimRewardPool';
+			docs: [
+				'* Reward pool funder can take back unclaimed rewards once the claim deadline passed.\n     *\n     * @dev Can be called only when status is set to VotesTallied once the claim duration elapsed since the tally, or Vetoed.\n     * The reward pool account and its token account are closed.\n     * An event RewardPoolReclaimed is emitted',
+			];
+			discriminator: [95, 103, 195, 216, 175, 179, 205, 134];
+			accounts: [
+				{
+					name: 'funder';
+					writable: true;
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'sessionAccount';
+				},
+				{
+					name: 'rewardPoolAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [114, 101, 119, 97, 114, 100, 95, 112, 111, 111, 108];
+							},
+							{
+								kind: 'account';
+								path: 'session_account.session_id';
+								account: 'sessionAccount';
+							},
+						];
+					};
+				},
+				{
+					name: 'mint';
+					optional: true;
+				},
+				{
+					name: 'poolTokenAccount';
+					writable: true;
+					optional: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'account';
+								path: 'rewardPoolAccount';
+							},
+							{
+								kind: 'const';
+								value: [6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133, 126, 255, 0, 169];
+							},
+							{
+								kind: 'account';
+								path: 'mint';
+							},
+						];
+						program: {
+							kind: 'const';
+							value: [140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142, 13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216, 219, 233, 248, 89];
+						};
+					};
+				},
+				{
+					name: 'funderTokenAccount';
+					writable: true;
+					optional: true;
+				},
+				{
+					name: 'tokenProgram';
+					optional: true;
+					address: 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA';
+				},
+			];
+			args: [];
+		},
+		{
+			name: 'registerIdentityCommitment';
+			docs: [
+				'* Registered voter adds its Semaphore identity commitment to the session identity tree.\n     *\n     * @dev Can be called until the voting session starts, once per voter, voter weight must be 1.\n     * An event IdentityCommitmentAdded is emitted\n     *\n     * @param identity_commitment The voter identity commitment',
+			];
+			discriminator: [254, 49, 207, 87, 231, 218, 12, 158];
+			accounts: [
+				{
+					name: 'voter';
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'sessionAccount';
+				},
+				{
+					name: 'voterAccount';
+					writable: true;
+				},
+				{
+					name: 'identityTreeAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [105, 100, 101, 110, 116, 105, 116, 121, 95, 116, 114, 101, 101];
+							},
+							{
+								kind: 'account';
+								path: 'session_account.session_id';
+								account: 'sessionAccount';
+							},
+						];
+					};
+				},
+			];
+			args: [
+				{
+					name: 'identityCommitment';
+					type: {
+						array: ['u8', 32];
+					};
+				},
+			];
+		},
+		{
+			name: 'registerProposal';
+			docs: [
+				'* A voter can register a new proposal.\n     *\n     * @dev Each voter can register many proposals.\n     * As the vote is considered to be done in small organization context, and to prevent dos gas limit, the maximum number of proposals is limited to 256.\n     * A vote can be added only by registered voter when status is set to VotingSessionStarted\n     * When a proposal deposit is configured, it is escrowed in the session vault.\n     * The proposal answers the session main question unless a question account is given.\n     *\n     * @param description The proposal description\n     * @param metadata_uri The off-chain proposal document URI, empty if none\n     * @param metadata_hash The SHA-256 of the off-chain proposal document',
+			];
+			discriminator: [255, 112, 186, 111, 67, 158, 20, 87];
+			accounts: [
+				{
+					name: 'proposer';
+					writable: true;
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'sessionAccount';
+					writable: true;
+				},
+				{
+					name: 'voterAccount';
+					writable: true;
+				},
+				{
+					name: 'proposalAccount';
+					writable: true;
+				},
+				{
+					name: 'vaultAccount';
+					writable: true;
+					optional: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [118, 97, 117, 108, 116];
+							},
+							{
+								kind: 'account';
+								path: 'session_account.session_id';
+								account: 'sessionAccount';
+							},
+						];
+					};
+				},
+				{
+					name: 'depositMint';
+					optional: true;
+				},
+				{
+					name: 'proposerTokenAccount';
+					writable: true;
+					optional: true;
+				},
+				{
+					name: 'vaultTokenAccount';
+					writable: true;
+					optional: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'account';
+								path: 'vaultAccount';
+							},
+							{
+								kind: 'const';
+								value: [6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133, 126, 255, 0, 169];
+							},
+							{
+								kind: 'account';
+								path: 'depositMint';
+							},
+						];
+						program: {
+							kind: 'const';
+							value: [140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142, 13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216, 219, 233, 248, 89];
+						};
+					};
+				},
+				{
+					name: 'tokenProgram';
+					optional: true;
+					address: 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA';
+				},
+				{
+					name: 'questionAccount';
+					optional: true;
+				},
+				{
+					name: 'systemProgram';
+					address: '11111111111111111111111111111111';
+				},
+			];
+			args: [
+				{
+					name: 'description';
+					type: 'string';
+				},
+				{
+					name: 'metadataUri';
+					type: 'string';
+				},
+				{
+					name: 'metadataHash';
+					type: {
+						array: ['u8', 32];
+					};
+				},
+			];
+		},
+		{
+			name: 'registerVoter';
+			docs: [
+				'* Session administrator can register voters.\n     *\n     * @dev voters can be added only by session administrator when status is set to RegisteringVoters\n     * The session id is appended to the voter sessions index (`voter_sessions` PDAs).\n     * An event VoterRegistered is emitted\n     *\n     * @param voter The address to add into voters registry\n     * @param weight The votes count of each voter vote, 1 if none\n     * @param credits The voter quadratic credits budget, the session credits if none',
+			];
+			discriminator: [229, 124, 185, 99, 118, 51, 226, 6];
+			accounts: [
+				{
+					name: 'admin';
+					writable: true;
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'sessionAccount';
+					writable: true;
+				},
+				{
+					name: 'voterAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [118, 111, 116, 101, 114];
+							},
+							{
+								kind: 'account';
+								path: 'session_account.session_id';
+								account: 'sessionAccount';
+							},
+							{
+								kind: 'arg';
+								path: 'voter';
+							},
+						];
+					};
+				},
+				{
+					name: 'voterSessionIndex';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [118, 111, 116, 101, 114, 95, 115, 101, 115, 115, 105, 111, 110, 115];
+							},
+							{
+								kind: 'arg';
+								path: 'voter';
+							},
+						];
+					};
+				},
+				{
+					name: 'voterSessionIndexPage';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [118, 111, 116, 101, 114, 95, 115, 101, 115, 115, 105, 111, 110, 115];
+							},
+							{
+								kind: 'arg';
+								path: 'voter';
+							},
+							{
+								kind: 'account';
+								path: 'voterSessionIndex';
+							},
+						];
+					};
+				},
+				{
+					name: 'systemProgram';
+					address: '11111111111111111111111111111111';
+				},
+			];
+			args: [
+				{
+					name: 'voter';
+					type: 'pubkey';
+				},
+				{
+					name: 'weight';
+					type: {
+						option: 'u32';
+					};
+				},
+				{
+					name: 'credits';
+					type: {
+						option: 'u32';
+					};
+				},
+			];
+		},
+		{
+			name: 'relayVote';
+			docs: [
+				'* A relayer can submit a vote signed off-chain by a voter, paying the transaction fees.\n     *\n     * @dev Same rules as `vote`, the vote being attributed to the signing voter.\n     * The ballot ("soltrain-voting:ballot" domain tag, program id, session id, proposal id, nonce) signature must be verified by an ed25519 program instruction placed right before this one.\n     * The nonce must match the voter nonce, incremented on each signed ballot.\n     * An event Voted is emitted\n     *\n     * @param nonce The voter nonce included in the signed ballot',
+			];
+			discriminator: [95, 144, 14, 235, 21, 209, 78, 166];
+			accounts: [
+				{
+					name: 'relayer';
+					writable: true;
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'sessionAccount';
+					writable: true;
+				},
+				{
+					name: 'voterAccount';
+					writable: true;
+				},
+				{
+					name: 'proposalAccount';
+					writable: true;
+				},
+				{
+					name: 'receiptsAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [114, 101, 99, 101, 105, 112, 116, 115];
+							},
+							{
+								kind: 'account';
+								path: 'session_account.session_id';
+								account: 'sessionAccount';
+							},
+						];
+					};
+				},
+				{
+					name: 'instructionsSysvar';
+					address: 'Sysvar1nstructions1111111111111111111111111';
+				},
+			];
+			args: [
+				{
+					name: 'nonce';
+					type: 'u64';
+				},
+			];
+		},
+		{
+			name: 'relayVotes';
+			docs: [
+				'* A relayer can submit many votes signed off-chain by voters in a single transaction.\n     *\n     * @dev Same rules as `relay_vote`, all ballots signatures being verified by the ed25519 program instruction placed right before this one.\n     * Remaining accounts are the voter account and the voted proposal account of each ballot, in the nonces order.\n     * A voter can have many ballots in the batch, one per seat in block voting, with consecutive nonces.\n     * Ballots of voters who already voted are skipped instead of failing the batch.\n     * Events Voted and SignedBallotsRelayed, reporting skipped voters, are emitted\n     *\n     * @param nonces The voter nonce included in each signed ballot',
+			];
+			discriminator: [187, 110, 55, 236, 205, 31, 180, 185];
+			accounts: [
+				{
+					name: 'relayer';
+					writable: true;
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'sessionAccount';
+					writable: true;
+				},
+				{
+					name: 'receiptsAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [114, 101, 99, 101, 105, 112, 116, 115];
+							},
+							{
+								kind: 'account';
+								path: 'session_account.session_id';
+								account: 'sessionAccount';
+							},
+						];
+					};
+				},
+				{
+					name: 'instructionsSysvar';
+					address: 'Sysvar1nstructions1111111111111111111111111';
+				},
+			];
+			args: [
+				{
+					name: 'nonces';
+					type: {
+						vec: 'u64';
+					};
+				},
+			];
+		},
+		{
+			name: 'releaseResult';
+			docs: [
+				'* Anyone can release the result once the execution timelock elapsed.\n     *\n     * @dev Can be called only when status is set to VotesTallied, and only for timelocked sessions since results without timelock are released at tally.\n     * Follow-up actions (proposal execution, treasury withdrawal, deposits settlement) require a released result.\n     * An event ResultReleased is emitted',
+			];
+			discriminator: [106, 191, 87, 232, 48, 244, 46, 99];
+			accounts: [
+				{
+					name: 'payer';
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'sessionAccount';
+					writable: true;
+				},
+			];
+			args: [];
+		},
+		{
+			name: 'removeOrganizationAdmin';
+			docs: [
+				'* Organization administrators can remove an administrator.\n     *\n     * @dev The last administrator can not be removed.\n     * An event OrganizationAdminRemoved is emitted\n     *\n     * @param admin The address to remove from organization administrators',
+			];
+			discriminator: [132, 81, 239, 172, 43, 232, 187, 117];
+			accounts: [
+				{
+					name: 'admin';
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'organizationAccount';
+					writable: true;
+				},
+			];
+			args: [
+				{
+					name: 'admin';
+					type: 'pubkey';
+				},
+			];
+		},
+		{
+			name: 'removeOrganizationMember';
+			docs: ['* Organization administrators can remove a standing member.\n     *\n     * @dev An event OrganizationMemberRemoved is emitted'];
+			discriminator: [166, 165, 117, 138, 177, 184, 150, 159];
+			accounts: [
+				{
+					name: 'admin';
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'organizationAccount';
+					writable: true;
+				},
+				{
+					name: 'memberAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [109, 101, 109, 98, 101, 114];
+							},
+							{
+								kind: 'account';
+								path: 'organization_account.organization_id';
+								account: 'organizationAccount';
+							},
+							{
+								kind: 'account';
+								path: 'member_account.member';
+								account: 'organizationMemberAccount';
+							},
+						];
+					};
+				},
+			];
+			args: [];
+		},
+		{
+			name: 'requestTreasuryTransfer';
+			docs: [
+				'* A proposer can request a transfer from the session treasury for a registered proposal.\n     *\n     * @dev Can be called only when status is set to ProposalsRegistrationStarted.\n     * The transfer can only be withdrawn if the proposal wins.\n     * An event TreasuryTransferRequested is emitted\n     *\n     * @param recipient The transfer recipient\n     * @param mint The SPL mint to transfer, or none for SOL\n     * @param amount The amount to transfer',
+			];
+			discriminator: [30, 221, 118, 32, 241, 45, 224, 215];
+			accounts: [
+				{
+					name: 'proposer';
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'sessionAccount';
+				},
+				{
+					name: 'proposalAccount';
+					writable: true;
+				},
+			];
+			args: [
+				{
+					name: 'recipient';
+					type: 'pubkey';
+				},
+				{
+					name: 'mint';
+					type: {
+						option: 'pubkey';
+					};
+				},
+				{
+					name: 'amount';
+					type: 'u64';
+				},
+			];
+		},
+		{
+			name: 'revealProposalVotes';
+			docs: [
+				'* Anyone can reveal a proposal votes count once enough trustees posted their decryption shares.\n     *\n     * @dev Can be called only when status is set to VotingSessionEnded, votes of every proposal must be revealed before the tally.\n     * The first `threshold` shares are combined, the count being checked against the combined decryption.\n     * An event ProposalVotesRevealed is emitted\n     *\n     * @param vote_count The decrypted votes count\n     * @param coefficients The Lagrange coefficients at 0 of the combined shares',
+			];
+			discriminator: [139, 229, 254, 19, 156, 56, 41, 160];
+			accounts: [
+				{
+					name: 'signer';
+					writable: true;
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'sessionAccount';
+				},
+				{
+					name: 'proposalAccount';
+					writable: true;
+				},
+			];
+			args: [
+				{
+					name: 'voteCount';
+					type: 'u32';
+				},
+				{
+					name: 'coefficients';
+					type: {
+						vec: {
+							array: ['u8', 32];
+						};
+					};
+				},
+			];
+		},
+		{
+			name: 'sealEncryptedBallot';
+			docs: [
+				'* Voter seals its complete encrypted ballot, which is added to the proposals encrypted votes.\n     *\n     * @dev Can be called only when status is set to VotingSessionStarted.\n     * The proof shows the ciphertexts sum encrypts 1, so a single proposal is chosen.\n     * Remaining accounts are all the session proposals, in proposal order.\n     * The vote receipt ballot is the hash of the ciphertexts.\n     * Events EncryptedBallotSealed and VoteReceiptAdded are emitted\n     *\n     * @param sum_proof The Chaum-Pedersen proof that the ciphertexts sum encrypts 1',
+			];
+			discriminator: [161, 1, 156, 131, 8, 67, 199, 109];
+			accounts: [
+				{
+					name: 'voter';
+					writable: true;
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'sessionAccount';
+					writable: true;
+				},
+				{
+					name: 'voterAccount';
+					writable: true;
+				},
+				{
+					name: 'encryptedBallotAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [101, 110, 99, 114, 121, 112, 116, 101, 100, 95, 98, 97, 108, 108, 111, 116];
+							},
+							{
+								kind: 'account';
+								path: 'session_account.session_id';
+								account: 'sessionAccount';
+							},
+							{
+								kind: 'account';
+								path: 'voter';
+							},
+						];
+					};
+				},
+				{
+					name: 'receiptsAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [114, 101, 99, 101, 105, 112, 116, 115];
+							},
+							{
+								kind: 'account';
+								path: 'session_account.session_id';
+								account: 'sessionAccount';
+							},
+						];
+					};
+				},
+			];
+			args: [
+				{
+					name: 'sumProof';
+					type: {
+						defined: {
+							name: 'equalityProof';
+						};
+					};
+				},
+			];
+		},
+		{
+			name: 'setPaused';
+			docs: [
+				'* Protocol authority can pause or unpause every session instruction.\n     *\n     * @dev An event PausedChanged is emitted\n     *\n     * @param paused The new pause flag',
+			];
+			discriminator: [91, 60, 125, 192, 176, 225, 166, 218];
+			accounts: [
+				{
+					name: 'authority';
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+			];
+			args: [
+				{
+					name: 'paused';
+					type: 'bool';
+				},
+			];
+		},
+		{
+			name: 'setVerifyingKey';
+			docs: [
+				'* Protocol authority sets the Groth16 verifying key of the anonymous voting membership circuit.\n     *\n     * @dev The key is the Semaphore circuit one, for the identity tree depth, points being big endian as expected by the alt_bn128 syscalls.\n     * Sessions configured with the previous key can not verify anonymous votes anymore.\n     * An event VerifyingKeyUpdated is emitted\n     *\n     * @param verifying_key The circuit verifying key',
+			];
+			discriminator: [79, 162, 161, 210, 103, 106, 246, 78];
+			accounts: [
+				{
+					name: 'authority';
+					writable: true;
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'verifyingKeyAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [118, 101, 114, 105, 102, 121, 105, 110, 103, 95, 107, 101, 121];
+							},
+						];
+					};
+				},
+				{
+					name: 'systemProgram';
+					address: '11111111111111111111111111111111';
+				},
+			];
+			args: [
+				{
+					name: 'verifyingKey';
+					type: {
+						defined: {
+							name: 'verifyingKey';
+						};
+					};
+				},
+			];
+		},
+		{
+			name: 'settleProposalDeposit';
+			docs: [
+				'* Anyone can settle a proposal deposit once the result is released or vetoed.\n     *\n     * @dev The deposit is refunded to the proposer when the proposal support share within its question\n     * reached `refund_threshold_bps`, otherwise it is forfeited to the session treasury.\n     * Added questions proposals are settled on their question account result.\n     * Main question support depends on the voting mode: votes, score sums over the best score, pairwise\n     * contests won (with the pairwise account) or ballots allocating votes to the proposal.\n     * When nobody voted, deposits are only refunded if the threshold is 0.\n     * An event ProposalDepositSettled is emitted',
+			];
+			discriminator: [234, 65, 163, 18, 118, 83, 128, 133];
+			accounts: [
+				{
+					name: 'payer';
+					writable: true;
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'sessionAccount';
+				},
+				{
+					name: 'proposalAccount';
+					writable: true;
+				},
+				{
+					name: 'pairwiseAccount';
+					optional: true;
+				},
+				{
+					name: 'questionAccount';
+					optional: true;
+				},
+				{
+					name: 'proposer';
+					writable: true;
+				},
+				{
+					name: 'vaultAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [118, 97, 117, 108, 116];
+							},
+							{
+								kind: 'account';
+								path: 'session_account.session_id';
+								account: 'sessionAccount';
+							},
+						];
+					};
+				},
+				{
+					name: 'treasuryAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [116, 114, 101, 97, 115, 117, 114, 121];
+							},
+							{
+								kind: 'account';
+								path: 'session_account.session_id';
+								account: 'sessionAccount';
+							},
+						];
+					};
+				},
+				{
+					name: 'depositMint';
+					optional: true;
+				},
+				{
+					name: 'vaultTokenAccount';
+					writable: true;
+					optional: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'account';
+								path: 'vaultAccount';
+							},
+							{
+								kind: 'const';
+								value: [6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133, 126, 255, 0, 169];
+							},
+							{
+								kind: 'account';
+								path: 'depositMint';
+							},
+						];
+						program: {
+							kind: 'const';
+							value: [140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142, 13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216, 219, 233, 248, 89];
+						};
+					};
+				},
+				{
+					name: 'proposerTokenAccount';
+					writable: true;
+					optional: true;
+				},
+				{
+					name: 'treasuryTokenAccount';
+					writable: true;
+					optional: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'account';
+								path: 'treasuryAccount';
+							},
+							{
+								kind: 'const';
+								value: [6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133, 126, 255, 0, 169];
+							},
+							{
+								kind: 'account';
+								path: 'depositMint';
+							},
+						];
+						program: {
+							kind: 'const';
+							value: [140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142, 13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216, 219, 233, 248, 89];
+						};
+					};
+				},
+				{
+					name: 'tokenProgram';
+					optional: true;
+					address: 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA';
+				},
+				{
+					name: 'associatedTokenProgram';
+					optional: true;
+					address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL';
+				},
+				{
+					name: 'systemProgram';
+					address: '11111111111111111111111111111111';
+				},
+			];
+			args: [];
+		},
+		{
+			name: 'snapshotVotingPower';
+			docs: [
+				'* A voter can snapshot their locked tokens for a session weighted by voting power.\n     *\n     * @dev Can be called until voting opens, the latest snapshot being kept.\n     * Voting opening records the snapshot slot, only snapshots taken up to it can be applied.\n     * An event VoterSnapshotTaken is emitted',
+			];
+			discriminator: [207, 224, 117, 221, 42, 159, 15, 181];
+			accounts: [
+				{
+					name: 'voter';
+					writable: true;
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'sessionAccount';
+				},
+				{
+					name: 'voterAccount';
+				},
+				{
+					name: 'lockAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [108, 111, 99, 107];
+							},
+							{
+								kind: 'account';
+								path: 'lock_account.mint';
+								account: 'lockAccount';
+							},
+							{
+								kind: 'account';
+								path: 'voter';
+							},
+						];
+					};
+				},
+				{
+					name: 'snapshotAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [115, 110, 97, 112, 115, 104, 111, 116];
+							},
+							{
+								kind: 'account';
+								path: 'session_account.session_id';
+								account: 'sessionAccount';
+							},
+							{
+								kind: 'account';
+								path: 'voter';
+							},
+						];
+					};
+				},
+				{
+					name: 'systemProgram';
+					address: '11111111111111111111111111111111';
+				},
+			];
+			args: [];
+		},
+		{
+			name: 'startProposalsRegistration';
+			docs: [
+				'* Administrator can close voters registration and open proposals registration.\n     *\n     * @dev Can be called only when status is set to RegisteringVoters.\n     * Two default proposals are registered at the beginning of this step: `Abstention` and `Blank`.\n     * That means a registered voter that forget to vote will be counted as `abstention` thanks to voter registration account and initialized state\n     * An event WorkflowStatusChanged is emitted',
+			];
+			discriminator: [186, 177, 117, 107, 84, 210, 40, 48];
+			accounts: [
+				{
+					name: 'admin';
+					writable: true;
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'sessionAccount';
+					writable: true;
+				},
+				{
+					name: 'blankProposalAccount';
+					writable: true;
+				},
+				{
+					name: 'systemProgram';
+					address: '11111111111111111111111111111111';
+				},
+			];
+			args: [];
+		},
+		{
+			name: 'startVotingSession';
+			docs: [
+				'* Administrator can open voting session.\n     *\n     * @dev Can be called only when status is set to ProposalsRegistrationEnded.\n     * Creates the session vote receipts accumulator.\n     * An event WorkflowStatusChange is emitted',
+			];
+			discriminator: [164, 58, 7, 145, 162, 194, 204, 56];
+			accounts: [
+				{
+					name: 'admin';
+					writable: true;
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'sessionAccount';
+					writable: true;
+				},
+				{
+					name: 'receiptsAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [114, 101, 99, 101, 105, 112, 116, 115];
+							},
+							{
+								kind: 'account';
+								path: 'session_account.session_id';
+								account: 'sessionAccount';
+							},
+						];
+					};
+				},
+				{
+					name: 'systemProgram';
+					address: '11111111111111111111111111111111';
+				},
+			];
+			args: [];
+		},
+		{
+			name: 'stopProposalsRegistration';
+			docs: [
+				'* Administrator can close proposals registration.\n     *\n     * @dev Can be called only when status is set to ProposalsRegistrationStarted.\n     * An event WorkflowStatusChange is emitted',
+			];
+			discriminator: [190, 223, 19, 127, 225, 61, 4, 11];
+			accounts: [
+				{
+					name: 'admin';
+					writable: true;
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'sessionAccount';
+					writable: true;
+				},
+				{
+					name: 'systemProgram';
+					address: '11111111111111111111111111111111';
+				},
+			];
+			args: [];
+		},
+		{
+			name: 'stopVotingSession';
+			docs: [
+				'* Administrator can close voting session.\n     *\n     * @dev Can be called only when status is set to VotingSessionStarted.\n     * An event WorkflowStatusChange is emitted',
+			];
+			discriminator: [244, 180, 9, 118, 55, 224, 199, 73];
+			accounts: [
+				{
+					name: 'admin';
+					writable: true;
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'sessionAccount';
+					writable: true;
+				},
+				{
+					name: 'systemProgram';
+					address: '11111111111111111111111111111111';
+				},
+			];
+			args: [];
+		},
+		{
+			name: 'tallyVotes';
+			docs: [
+				'* Administrator can trigger votes talling.\n     *\n     * @dev After votes talling, it is possible that we got many winning proposals.\n     * Votes talling can be triggered only by voting session administrator when voting session status is set to VotingSessionEnded\n     * Remaining accounts are the session proposals followed by the added questions, writable as each added question\n     * result is stored in its question account.\n     * In condorcet mode, the pairwise preferences account is required once a ballot has been cast.\n     * The session seats are filled by the best ranked proposals, proposals tied at the last seat are recorded apart\n     * and not elected, so a single seat session tied at the top has no winner.\n     * Votes and abstention are weighted by voters weight, raw headcounts are reported along.\n     * The vote receipts accumulator root is recorded in the result, for voters to verify their receipts inclusion.\n     * The result is queued until the execution timelock elapsed, without timelock it is released right away.\n     * Events WorkflowStatusChange, VotesTallied and ResultQueued are emitted, and ResultReleased without timelock\n     *',
+			];
+			discriminator: [144, 82, 0, 72, 160, 132, 35, 121];
+			accounts: [
+				{
+					name: 'admin';
+					writable: true;
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'sessionAccount';
+					writable: true;
+				},
+				{
+					name: 'pairwiseAccount';
+					optional: true;
+				},
+				{
+					name: 'receiptsAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [114, 101, 99, 101, 105, 112, 116, 115];
+							},
+							{
+								kind: 'account';
+								path: 'session_account.session_id';
+								account: 'sessionAccount';
+							},
+						];
+					};
+				},
+				{
+					name: 'systemProgram';
+					address: '11111111111111111111111111111111';
+				},
+			];
+			args: [];
+		},
+		{
+			name: 'unlockTokens';
+			docs: ['* Lock owner can withdraw the locked tokens once the lock expired.\n     *\n     * @dev An event TokensUnlocked is emitted'];
+			discriminator: [233, 35, 95, 159, 37, 185, 47, 88];
+			accounts: [
+				{
+					name: 'owner';
+					writable: true;
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'mint';
+				},
+				{
+					name: 'lockAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [108, 111, 99, 107];
+							},
+							{
+								kind: 'account';
+								path: 'mint';
+							},
+							{
+								kind: 'account';
+								path: 'owner';
+							},
+						];
+					};
+				},
+				{
+					name: 'ownerTokenAccount';
+					writable: true;
+				},
+				{
+					name: 'lockTokenAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'account';
+								path: 'lockAccount';
+							},
+							{
+								kind: 'const';
+								value: [6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133, 126, 255, 0, 169];
+							},
+							{
+								kind: 'account';
+								path: 'mint';
+							},
+						];
+						program: {
+							kind: 'const';
+							value: [140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142, 13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216, 219, 233, 248, 89];
+						};
+					};
+				},
+				{
+					name: 'tokenProgram';
+					address: 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA';
+				},
+			];
+			args: [];
+		},
+		{
+			name: 'updateGlobalConfig';
+			docs: [
+				'* Protocol authority can update the protocol configuration.\n     *\n     * @dev An event GlobalConfigUpdated is emitted\n     *\n     * @param config The new protocol configuration',
+			];
+			discriminator: [164, 84, 130, 189, 111, 58, 250, 200];
+			accounts: [
+				{
+					name: 'authority';
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+			];
+			args: [
+				{
+					name: 'config';
+					type: {
+						defined: {
+							name: 'globalConfig';
+						};
+					};
+				},
+			];
+		},
+		{
+			name: 'vetoResult';
+			docs: [
+				'* Veto council can cancel the result while the execution timelock is running.\n     *\n     * @dev Can be called only when status is set to VotesTallied and before result release.\n     * Events ResultVetoed and WorkflowStatusChange are emitted',
+			];
+			discriminator: [233, 192, 85, 197, 28, 4, 237, 123];
+			accounts: [
+				{
+					name: 'vetoCouncil';
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'sessionAccount';
+					writable: true;
+				},
+			];
+			args: [];
+		},
+		{
+			name: 'vote';
+			docs: [
+				'* A voter can register his vote for a proposal.\n     *\n     * @dev Each voter can vote only once per question, the question being the one of the proposal.\n     * When many seats are configured, voters can vote once per seat for distinct proposals of the main question.\n     * Each vote adds the voter weight to the proposal votes count.\n     * Each vote receipt, hash of (session id, voter, ballot, slot), is appended to the session receipts accumulator, the ballot being the proposal id.\n     * When the optional badge accounts are given, the session participation badge is minted to the voter along the vote, unless already minted.\n     * Votes can be added only by registered voter when status is set to VotingSessionStarted',
+			];
+			discriminator: [227, 110, 155, 23, 136, 126, 172, 25];
+			accounts: [
+				{
+					name: 'voter';
+					writable: true;
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'sessionAccount';
+					writable: true;
+				},
+				{
+					name: 'voterAccount';
+					writable: true;
+				},
+				{
+					name: 'proposalAccount';
+					writable: true;
+				},
+				{
+					name: 'receiptsAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [114, 101, 99, 101, 105, 112, 116, 115];
+							},
+							{
+								kind: 'account';
+								path: 'session_account.session_id';
+								account: 'sessionAccount';
+							},
+						];
+					};
+				},
+				{
+					name: 'badgeMint';
+					writable: true;
+					optional: true;
+				},
+				{
+					name: 'voterBadgeAccount';
+					writable: true;
+					optional: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'account';
+								path: 'voter';
+							},
+							{
+								kind: 'account';
+								path: 'tokenProgram';
+							},
+							{
+								kind: 'account';
+								path: 'badgeMint';
+							},
+						];
+						program: {
+							kind: 'const';
+							value: [140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142, 13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216, 219, 233, 248, 89];
+						};
+					};
+				},
+				{
+					name: 'tokenProgram';
+					optional: true;
+					address: 'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb';
+				},
+				{
+					name: 'associatedTokenProgram';
+					optional: true;
+					address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL';
+				},
+				{
+					name: 'systemProgram';
+					address: '11111111111111111111111111111111';
+				},
+			];
+			args: [];
+		},
+		{
+			name: 'voteRanked';
+			docs: [
+				'* A voter can rank the session proposals.\n     *\n     * @dev Can be called only in condorcet mode when status is set to VotingSessionStarted.\n     * Each voter casts a single ballot, lower ranks are preferred, equal ranks express no preference and unranked proposals come last.\n     * Ballots are accumulated in the session pairwise preferences matrix, tallied with the Schulze method.\n     * The vote receipt ballot is the (proposal id, value) pairs of the entries.\n     * Events BallotCast and VoteReceiptAdded are emitted\n     *\n     * @param rankings The rank given to each proposal, in increasing proposal id order',
+			];
+			discriminator: [153, 132, 225, 108, 5, 236, 172, 200];
+			accounts: [
+				{
+					name: 'voter';
+					writable: true;
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'sessionAccount';
+					writable: true;
+				},
+				{
+					name: 'voterAccount';
+					writable: true;
+				},
+				{
+					name: 'ballotAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [98, 97, 108, 108, 111, 116];
+							},
+							{
+								kind: 'account';
+								path: 'session_account.session_id';
+								account: 'sessionAccount';
+							},
+							{
+								kind: 'account';
+								path: 'voter';
+							},
+						];
+					};
+				},
+				{
+					name: 'receiptsAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [114, 101, 99, 101, 105, 112, 116, 115];
+							},
+							{
+								kind: 'account';
+								path: 'session_account.session_id';
+								account: 'sessionAccount';
+							},
+						];
+					};
+				},
+				{
+					name: 'pairwiseAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [112, 97, 105, 114, 119, 105, 115, 101];
+							},
+							{
+								kind: 'account';
+								path: 'session_account.session_id';
+								account: 'sessionAccount';
+							},
+						];
+					};
+				},
+				{
+					name: 'systemProgram';
+					address: '11111111111111111111111111111111';
+				},
+			];
+			args: [
+				{
+					name: 'rankings';
+					type: {
+						vec: {
+							defined: {
+								name: 'ballotEntry';
+							};
+						};
+					};
+				},
+			];
+		},
+		{
+			name: 'voteScores';
+			docs: [
+				'* A voter can score the session proposals.\n     *\n     * @dev Can be called only in score mode when status is set to VotingSessionStarted.\n     * Each voter casts a single ballot, scored proposals are given as remaining accounts in increasing id order.\n     * Proposals are ranked by mean score over all ballots when votes are tallied, unscored proposals of a ballot counting as scored 0.\n     * The vote receipt ballot is the (proposal id, value) pairs of the entries.\n     * Events BallotCast and VoteReceiptAdded are emitted\n     *\n     * @param scores The score given to each proposal, from 0 to the session maximum score',
+			];
+			discriminator: [107, 79, 251, 38, 146, 27, 152, 68];
+			accounts: [
+				{
+					name: 'voter';
+					writable: true;
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'sessionAccount';
+					writable: true;
+				},
+				{
+					name: 'voterAccount';
+					writable: true;
+				},
+				{
+					name: 'ballotAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [98, 97, 108, 108, 111, 116];
+							},
+							{
+								kind: 'account';
+								path: 'session_account.session_id';
+								account: 'sessionAccount';
+							},
+							{
+								kind: 'account';
+								path: 'voter';
+							},
+						];
+					};
+				},
+				{
+					name: 'receiptsAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [114, 101, 99, 101, 105, 112, 116, 115];
+							},
+							{
+								kind: 'account';
+								path: 'session_account.session_id';
+								account: 'sessionAccount';
+							},
+						];
+					};
+				},
+				{
+					name: 'systemProgram';
+					address: '11111111111111111111111111111111';
+				},
+			];
+			args: [
+				{
+					name: 'scores';
+					type: {
+						vec: {
+							defined: {
+								name: 'ballotEntry';
+							};
+						};
+					};
+				},
+			];
+		},
+		{
+			name: 'withdrawFromTreasury';
+			docs: [
+				'* Anyone can trigger the treasury transfer requested by the winning proposal.\n     *\n     * @dev Can be called only when status is set to VotesTallied, once the result is released,\n     * and only if the proposal is the single winning proposal.\n     * An event TreasuryWithdrawn is emitted',
+			];
+			discriminator: [0, 164, 86, 76, 56, 72, 12, 170];
+			accounts: [
+				{
+					name: 'payer';
+					signer: true;
+				},
+				{
+					name: 'globalAccount';
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [103, 108, 111, 98, 97, 108];
+							},
+						];
+					};
+				},
+				{
+					name: 'sessionAccount';
+				},
+				{
+					name: 'proposalAccount';
+					writable: true;
+				},
+				{
+					name: 'treasuryAccount';
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'const';
+								value: [116, 114, 101, 97, 115, 117, 114, 121];
+							},
+							{
+								kind: 'account';
+								path: 'session_account.session_id';
+								account: 'sessionAccount';
+							},
+						];
+					};
+				},
+				{
+					name: 'recipient';
+					writable: true;
+				},
+				{
+					name: 'mint';
+					optional: true;
+				},
+				{
+					name: 'treasuryTokenAccount';
+					writable: true;
+					optional: true;
+					pda: {
+						seeds: [
+							{
+								kind: 'account';
+								path: 'treasuryAccount';
+							},
+							{
+								kind: 'const';
+								value: [6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133, 126, 255, 0, 169];
+							},
+							{
+								kind: 'account';
+								path: 'mint';
+							},
+						];
+						program: {
+							kind: 'const';
+							value: [140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142, 13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216, 219, 233, 248, 89];
+						};
+					};
+				},
+				{
+					name: 'recipientTokenAccount';
+					writable: true;
+					optional: true;
+				},
+				{
+					name: 'tokenProgram';
+					optional: true;
+					address: 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA';
+				},
+			];
+			args: [];
+		},
+	];
+	accounts: [
+		{
+			name: 'ballotAccount';
+			discriminator: [207, 160, 225, 23, 25, 97, 86, 116];
+		},
+		{
+			name: 'encryptedBallotAccount';
+			discriminator: [183, 148, 213, 159, 189, 181, 1, 58];
+		},
+		{
+			name: 'globalAccount';
+			discriminator: [129, 105, 124, 171, 189, 42, 108, 69];
+		},
+		{
+			name: 'identityTreeAccount';
+			discriminator: [57, 137, 86, 142, 139, 105, 248, 100];
+		},
+		{
+			name: 'lockAccount';
+			discriminator: [223, 64, 71, 124, 255, 86, 118, 192];
+		},
+		{
+			name: 'nullifierAccount';
+			discriminator: [250, 31, 238, 177, 213, 98, 48, 172];
+		},
+		{
+			name: 'organizationAccount';
+			discriminator: [188, 133, 114, 111, 191, 0, 19, 74];
+		},
+		{
+			name: 'organizationMemberAccount';
+			discriminator: [228, 255, 208, 65, 205, 85, 94, 149];
+		},
+		{
+			name: 'pairwiseMatrixAccount';
+			discriminator: [212, 57, 241, 15, 144, 36, 127, 26];
+		},
+		{
+			name: 'proposalAccount';
+			discriminator: [164, 190, 4, 248, 203, 124, 243, 64];
+		},
+		{
+			name: 'proposalTransactionAccount';
+			discriminator: [76, 113, 184, 113, 102, 73, 175, 172];
+		},
+		{
+			name: 'questionAccount';
+			discriminator: [74, 254, 61, 10, 201, 52, 133, 220];
+		},
+		{
+			name: 'receiptAccumulatorAccount';
+			discriminator: [244, 97, 143, 200, 19, 107, 248, 191];
+		},
+		{
+			name: 'rewardPoolAccount';
+			discriminator: [231, 113, 30, 218, 177, 213, 27, 254];
+		},
+		{
+			name: 'sessionAccount';
+			discriminator: [74, 34, 65, 133, 96, 163, 80, 69];
+		},
+		{
+			name: 'sessionIndexAccount';
+			discriminator: [64, 177, 161, 166, 28, 158, 120, 255];
+		},
+		{
+			name: 'sessionIndexPageAccount';
+			discriminator: [196, 252, 206, 63, 15, 165, 74, 66];
+		},
+		{
+			name: 'treasuryAccount';
+			discriminator: [204, 140, 18, 173, 90, 152, 134, 123];
+		},
+		{
+			name: 'vaultAccount';
+			discriminator: [230, 251, 241, 83, 139, 202, 93, 28];
+		},
+		{
+			name: 'verifyingKeyAccount';
+			discriminator: [5, 54, 231, 84, 81, 70, 17, 168];
+		},
+		{
+			name: 'voterAccount';
+			discriminator: [24, 202, 161, 124, 196, 184, 105, 236];
+		},
+		{
+			name: 'voterSnapshotAccount';
+			discriminator: [140, 210, 163, 200, 98, 255, 30, 206];
+		},
+	];
+	events: [
+		{
+			name: 'anonymousVoted';
+			discriminator: [124, 150, 28, 217, 151, 118, 114, 205];
+		},
+		{
+			name: 'anonymousVotingConfigured';
+			discriminator: [91, 36, 86, 126, 110, 180, 230, 185];
+		},
+		{
+			name: 'badgeMinted';
+			discriminator: [53, 227, 68, 72, 115, 78, 25, 14];
+		},
+		{
+			name: 'badgesEnabled';
+			discriminator: [164, 90, 155, 212, 9, 1, 141, 211];
+		},
+		{
+			name: 'ballotCast';
+			discriminator: [194, 0, 41, 178, 6, 3, 169, 32];
+		},
+		{
+			name: 'decryptionSharePosted';
+			discriminator: [78, 124, 156, 231, 28, 43, 98, 155];
+		},
+		{
+			name: 'encryptedBallotSealed';
+			discriminator: [140, 150, 36, 44, 101, 99, 126, 40];
+		},
+		{
+			name: 'encryptionConfigured';
+			discriminator: [167, 245, 134, 21, 128, 48, 108, 55];
+		},
+		{
+			name: 'executionConfigured';
+			discriminator: [41, 158, 62, 146, 217, 82, 155, 158];
+		},
+		{
+			name: 'globalConfigUpdated';
+			discriminator: [232, 238, 158, 123, 210, 172, 159, 46];
+		},
+		{
+			name: 'identityCommitmentAdded';
+			discriminator: [170, 215, 84, 84, 40, 205, 235, 142];
+		},
+		{
+			name: 'organizationAdminAdded';
+			discriminator: [105, 23, 165, 215, 82, 119, 120, 135];
+		},
+		{
+			name: 'organizationAdminRemoved';
+			discriminator: [132, 235, 245, 210, 136, 70, 0, 107];
+		},
+		{
+			name: 'organizationCreated';
+			discriminator: [50, 45, 35, 29, 215, 59, 22, 185];
+		},
+		{
+			name: 'organizationMemberAdded';
+			discriminator: [147, 179, 198, 23, 64, 44, 54, 96];
+		},
+		{
+			name: 'organizationMemberRemoved';
+			discriminator: [142, 155, 245, 124, 181, 50, 196, 93];
+		},
+		{
+			name: 'pausedChanged';
+			discriminator: [12, 10, 153, 247, 60, 115, 137, 69];
+		},
+		{
+			name: 'proposalDepositEscrowed';
+			discriminator: [3, 126, 35, 99, 0, 187, 241, 138];
+		},
+		{
+			name: 'proposalDepositSettled';
+			discriminator: [137, 194, 12, 52, 141, 131, 232, 249];
+		},
+		{
+			name: 'proposalExecuted';
+			discriminator: [92, 213, 189, 201, 101, 83, 111, 83];
+		},
+		{
+			name: 'proposalRegistered';
+			discriminator: [194, 224, 148, 158, 74, 91, 11, 247];
+		},
+		{
+			name: 'proposalTransactionAttached';
+			discriminator: [231, 218, 189, 52, 7, 88, 224, 28];
+		},
+		{
+			name: 'proposalVotesRevealed';
+			discriminator: [182, 137, 230, 230, 8, 106, 58, 170];
+		},
+		{
+			name: 'proposalsConfigured';
+			discriminator: [136, 153, 1, 38, 242, 79, 58, 131];
+		},
+		{
+			name: 'questionAdded';
+			discriminator: [73, 232, 186, 48, 42, 63, 192, 157];
+		},
+		{
+			name: 'resultQueued';
+			discriminator: [168, 147, 136, 45, 64, 56, 134, 83];
+		},
+		{
+			name: 'resultReleased';
+			discriminator: [241, 152, 172, 73, 65, 177, 57, 223];
+		},
+		{
+			name: 'resultVetoed';
+			discriminator: [89, 150, 82, 150, 240, 163, 26, 232];
+		},
+		{
+			name: 'rewardClaimed';
+			discriminator: [49, 28, 87, 84, 158, 48, 229, 175];
+		},
+		{
+			name: 'rewardPoolFunded';
+			discriminator: [86, 253, 182, 175, 22, 199, 5, 198];
+		},
+		{
+			name: 'rewardPoolReclaimed';
+			discriminator: [168, 244, 116, 139, 90, 118, 161, 50];
+		},
+		{
+			name: 'sessionCreated';
+			discriminator: [107, 111, 254, 25, 21, 122, 220, 225];
+		},
+		{
+			name: 'sessionWorkflowStatusChanged';
+			discriminator: [37, 80, 31, 154, 111, 190, 223, 237];
+		},
+		{
+			name: 'signedBallotsRelayed';
+			discriminator: [10, 95, 64, 53, 60, 193, 27, 30];
+		},
+		{
+			name: 'tokensLocked';
+			discriminator: [63, 184, 201, 20, 203, 194, 249, 138];
+		},
+		{
+			name: 'tokensUnlocked';
+			discriminator: [32, 143, 250, 162, 63, 131, 83, 163];
+		},
+		{
+			name: 'treasuryDeposited';
+			discriminator: [1, 193, 184, 0, 137, 134, 85, 50];
+		},
+		{
+			name: 'treasuryTransferRequested';
+			discriminator: [207, 35, 133, 81, 176, 89, 45, 172];
+		},
+		{
+			name: 'treasuryWithdrawn';
+			discriminator: [143, 181, 157, 169, 87, 155, 170, 46];
+		},
+		{
+			name: 'verifyingKeyUpdated';
+			discriminator: [64, 66, 76, 1, 76, 30, 129, 10];
+		},
+		{
+			name: 'voteReceiptAdded';
+			discriminator: [49, 2, 66, 52, 172, 220, 69, 248];
+		},
+		{
+			name: 'voted';
+			discriminator: [189, 74, 101, 127, 109, 214, 95, 130];
+		},
+		{
+			name: 'voterRegistered';
+			discriminator: [184, 179, 209, 46, 125, 60, 51, 197];
+		},
+		{
+			name: 'voterSnapshotTaken';
+			discriminator: [234, 2, 26, 161, 136, 239, 254, 153];
+		},
+		{
+			name: 'votesTallied';
+			discriminator: [209, 23, 244, 97, 94, 140, 180, 165];
+		},
+		{
+			name: 'votingModeConfigured';
+			discriminator: [36, 245, 214, 91, 32, 34, 46, 78];
+		},
+		{
+			name: 'votingPowerApplied';
+			discriminator: [72, 200, 135, 223, 152, 227, 67, 55];
+		},
+		{
+			name: 'votingPowerConfigured';
+			discriminator: [0, 188, 16, 66, 17, 70, 64, 164];
+		},
+	];
+	errors: [
+		{
+			code: 6000;
+			name: 'unexpectedSessionStatus';
+			msg: 'Unexpected session status';
+		},
+		{
+			code: 6001;
+			name: 'adminForbiddenAsVoter';
+			msg: 'Voting session administrator can not be registered as voter';
+		},
+		{
+			code: 6002;
+			name: 'forbiddenAsNonAdmin';
+			msg: 'Forbidden as non administrator';
+		},
+		{
+			code: 6003;
+			name: 'forbiddenAsAdmin';
+			msg: 'Forbidden as administrator';
+		},
+		{
+			code: 6004;
+			name: 'voterAlreadyRegistered';
+			msg: 'Voter already registered';
+		},
+		{
+			code: 6005;
+			name: 'proposerNotRegistered';
+			msg: 'Proposer must be registered as voter';
+		},
+		{
+			code: 6006;
+			name: 'voterAlreadyVoted';
+			msg: 'Voter already voted';
+		},
+		{
+			code: 6007;
+			name: 'unexpectedVoter';
+			msg: 'Unexpected voter';
+		},
+		{
+			code: 6008;
+			name: 'forbiddenAbstention';
+			msg: 'Abstention can not be voted';
+		},
+		{
+			code: 6009;
+			name: 'invalidAccountType';
+			msg: 'Invalid account';
+		},
+		{
+			code: 6010;
+			name: 'invalidProposalId';
+			msg: 'Invalid proposal';
+		},
+		{
+			code: 6011;
+			name: 'badProposalAccountsCount';
+			msg: 'Bad proposals accounts count';
+		},
+		{
+			code: 6012;
+			name: 'forbiddenAsNonAuthority';
+			msg: 'Forbidden as non protocol authority';
+		},
+		{
+			code: 6013;
+			name: 'unexpectedAuthority';
+			msg: 'Unexpected protocol authority account';
+		},
+		{
+			code: 6014;
+			name: 'invalidGlobalConfig';
+			msg: 'Invalid global configuration';
+		},
+		{
+			code: 6015;
+			name: 'programPaused';
+			msg: 'Program is paused';
+		},
+		{
+			code: 6016;
+			name: 'sessionNameTooLong';
+			msg: 'Session name is too long';
+		},
+		{
+			code: 6017;
+			name: 'sessionDescriptionTooLong';
+			msg: 'Session description is too long';
+		},
+		{
+			code: 6018;
+			name: 'emptySessionName';
+			msg: 'Session name can not be empty';
+		},
+		{
+			code: 6019;
+			name: 'proposalDescriptionTooLong';
+			msg: 'Proposal description is too long';
+		},
+		{
+			code: 6020;
+			name: 'emptyProposalDescription';
+			msg: 'Proposal description can not be empty';
+		},
+		{
+			code: 6021;
+			name: 'metadataUriTooLong';
+			msg: 'Metadata URI is too long';
+		},
+		{
+			code: 6022;
+			name: 'missingMetadataUri';
+			msg: 'Metadata hash requires a metadata URI';
+		},
+		{
+			code: 6023;
+			name: 'emptyQuestionTitle';
+			msg: 'Question title can not be empty';
+		},
+		{
+			code: 6024;
+			name: 'questionTitleTooLong';
+			msg: 'Question title is too long';
+		},
+		{
+			code: 6025;
+			name: 'maxQuestionsReached';
+			msg: 'Maximum number of questions reached';
+		},
+		{
+			code: 6026;
+			name: 'invalidQuestionId';
+			msg: 'Invalid question';
+		},
+		{
+			code: 6027;
+			name: 'invalidVotingMode';
+			msg: 'Invalid voting mode';
+		},
+		{
+			code: 6028;
+			name: 'unexpectedVotingMode';
+			msg: 'Unexpected voting mode';
+		},
+		{
+			code: 6029;
+			name: 'invalidScore';
+			msg: 'Score exceeds the session maximum score';
+		},
+		{
+			code: 6030;
+			name: 'invalidRank';
+			msg: 'Invalid rank';
+		},
+		{
+			code: 6031;
+			name: 'invalidPairwiseAccount';
+			msg: 'Invalid pairwise preferences account';
+		},
+		{
+			code: 6032;
+			name: 'proposalAlreadyVoted';
+			msg: 'Voter already voted for this proposal';
+		},
+		{
+			code: 6033;
+			name: 'insufficientCredits';
+			msg: 'Votes cost exceeds the voter credits or points';
+		},
+		{
+			code: 6034;
+			name: 'invalidVoterWeight';
+			msg: 'Voter weight must be positive';
+		},
+		{
+			code: 6035;
+			name: 'invalidLockDuration';
+			msg: 'Invalid lock duration';
+		},
+		{
+			code: 6036;
+			name: 'tokensStillLocked';
+			msg: 'Tokens are still locked';
+		},
+		{
+			code: 6037;
+			name: 'snapshotTooLate';
+			msg: 'Voter snapshot was taken after voting opened';
+		},
+		{
+			code: 6038;
+			name: 'invalidSignedBallot';
+			msg: 'Invalid signed ballot';
+		},
+		{
+			code: 6039;
+			name: 'invalidBallotNonce';
+			msg: 'Invalid signed ballot nonce';
+		},
+		{
+			code: 6040;
+			name: 'badBallotAccountsCount';
+			msg: 'Bad ballot accounts count';
+		},
+		{
+			code: 6041;
+			name: 'duplicateSignedBallot';
+			msg: 'Signed ballot is relayed twice in the batch';
+		},
+		{
+			code: 6042;
+			name: 'noVotingPower';
+			msg: 'No voting power';
+		},
+		{
+			code: 6043;
+			name: 'maxVotersReached';
+			msg: 'Maximum number of voters reached';
+		},
+		{
+			code: 6044;
+			name: 'maxProposalsReached';
+			msg: 'Maximum number of proposals reached';
+		},
+		{
+			code: 6045;
+			name: 'maxProposalsPerVoterReached';
+			msg: 'Maximum number of proposals per voter reached';
+		},
+		{
+			code: 6046;
+			name: 'invalidProposalsConfig';
+			msg: 'Invalid proposals configuration';
+		},
+		{
+			code: 6047;
+			name: 'invalidDepositMint';
+			msg: 'Invalid deposit mint';
+		},
+		{
+			code: 6048;
+			name: 'missingDepositAccounts';
+			msg: 'Missing deposit accounts';
+		},
+		{
+			code: 6049;
+			name: 'depositAlreadySettled';
+			msg: 'Deposit already settled';
+		},
+		{
+			code: 6050;
+			name: 'invalidExecutionConfig';
+			msg: 'Invalid execution configuration';
+		},
+		{
+			code: 6051;
+			name: 'emptyProposalTransaction';
+			msg: 'Proposal transaction is empty';
+		},
+		{
+			code: 6052;
+			name: 'forbiddenProposalInstruction';
+			msg: 'Proposal transaction can not invoke the voting program';
+		},
+		{
+			code: 6053;
+			name: 'proposalNotWinning';
+			msg: 'Proposal is not the single winning proposal';
+		},
+		{
+			code: 6054;
+			name: 'timelockNotElapsed';
+			msg: 'Execution timelock not elapsed';
+		},
+		{
+			code: 6055;
+			name: 'forbiddenAsNonVetoCouncil';
+			msg: 'Forbidden as non veto council';
+		},
+		{
+			code: 6056;
+			name: 'vetoWindowClosed';
+			msg: 'Veto window is closed';
+		},
+		{
+			code: 6057;
+			name: 'resultNotReleased';
+			msg: 'Result is not released';
+		},
+		{
+			code: 6058;
+			name: 'resultAlreadyReleased';
+			msg: 'Result already released';
+		},
+		{
+			code: 6059;
+			name: 'organizationNameTooLong';
+			msg: 'Organization name is too long';
+		},
+		{
+			code: 6060;
+			name: 'forbiddenAsNonOrganizationAdmin';
+			msg: 'Forbidden as non organization administrator';
+		},
+		{
+			code: 6061;
+			name: 'organizationAdminAlreadyRegistered';
+			msg: 'Organization administrator already registered';
+		},
+		{
+			code: 6062;
+			name: 'unexpectedOrganizationAdmin';
+			msg: 'Unexpected organization administrator';
+		},
+		{
+			code: 6063;
+			name: 'maxOrganizationAdminsReached';
+			msg: 'Maximum number of organization administrators reached';
+		},
+		{
+			code: 6064;
+			name: 'lastOrganizationAdmin';
+			msg: 'Organization must keep at least one administrator';
+		},
+		{
+			code: 6065;
+			name: 'memberAlreadyRegistered';
+			msg: 'Member already registered';
+		},
+		{
+			code: 6066;
+			name: 'memberNotRegistered';
+			msg: 'Member not registered';
+		},
+		{
+			code: 6067;
+			name: 'notOrganizationSession';
+			msg: 'Session does not belong to the member organization';
+		},
+		{
+			code: 6068;
+			name: 'organizationSessionVoter';
+			msg: 'Organization session voters are organization members';
+		},
+		{
+			code: 6069;
+			name: 'proposalAlreadyExecuted';
+			msg: 'Proposal already executed';
+		},
+		{
+			code: 6070;
+			name: 'missingInstructionAccount';
+			msg: 'Missing account for proposal instruction';
+		},
+		{
+			code: 6071;
+			name: 'invalidAmount';
+			msg: 'Invalid amount';
+		},
+		{
+			code: 6072;
+			name: 'missingTokenAccounts';
+			msg: 'Missing token accounts';
+		},
+		{
+			code: 6073;
+			name: 'invalidMint';
+			msg: 'Invalid mint';
+		},
+		{
+			code: 6074;
+			name: 'noTreasuryTransfer';
+			msg: 'Proposal has no treasury transfer';
+		},
+		{
+			code: 6075;
+			name: 'treasuryTransferAlreadyRequested';
+			msg: 'Treasury transfer already requested';
+		},
+		{
+			code: 6076;
+			name: 'insufficientTreasuryFunds';
+			msg: 'Insufficient treasury funds';
+		},
+		{
+			code: 6077;
+			name: 'invalidEncryptionConfig';
+			msg: 'Invalid encryption configuration';
+		},
+		{
+			code: 6078;
+			name: 'invalidCiphertext';
+			msg: 'Invalid ciphertext';
+		},
+		{
+			code: 6079;
+			name: 'invalidProof';
+			msg: 'Invalid proof';
+		},
+		{
+			code: 6080;
+			name: 'encryptedBallotIncomplete';
+			msg: 'Encrypted ballot does not hold a ciphertext per proposal';
+		},
+		{
+			code: 6081;
+			name: 'forbiddenAsNonTrustee';
+			msg: 'Only session trustees can post decryption shares';
+		},
+		{
+			code: 6082;
+			name: 'decryptionShareAlreadyPosted';
+			msg: 'Decryption share already posted';
+		},
+		{
+			code: 6083;
+			name: 'notEnoughDecryptionShares';
+			msg: 'Not enough decryption shares';
+		},
+		{
+			code: 6084;
+			name: 'invalidDecryption';
+			msg: 'Invalid decryption';
+		},
+		{
+			code: 6085;
+			name: 'votesAlreadyRevealed';
+			msg: 'Proposal votes already revealed';
+		},
+		{
+			code: 6086;
+			name: 'votesNotRevealed';
+			msg: 'Proposal votes not revealed';
+		},
+		{
+			code: 6087;
+			name: 'invalidIdentityCommitment';
+			msg: 'Invalid identity commitment';
+		},
+		{
+			code: 6088;
+			name: 'identityAlreadyRegistered';
+			msg: 'Identity commitment already registered';
+		},
+		{
+			code: 6089;
+			name: 'identityTreeFull';
+			msg: 'Identity tree is full';
+		},
+		{
+			code: 6090;
+			name: 'invalidMembershipProof';
+			msg: 'Invalid membership proof';
+		},
+		{
+			code: 6091;
+			name: 'receiptAccumulatorFull';
+			msg: 'Receipt accumulator is full';
+		},
+		{
+			code: 6092;
+			name: 'invalidClaimDuration';
+			msg: 'Invalid claim duration';
+		},
+		{
+			code: 6093;
+			name: 'voterDidNotVote';
+			msg: 'Voter did not vote';
+		},
+		{
+			code: 6094;
+			name: 'rewardAlreadyClaimed';
+			msg: 'Reward already claimed';
+		},
+		{
+			code: 6095;
+			name: 'claimPeriodEnded';
+			msg: 'Claim period ended';
+		},
+		{
+			code: 6096;
+			name: 'claimPeriodNotEnded';
+			msg: 'Claim period not ended';
+		},
+		{
+			code: 6097;
+			name: 'insufficientRewardFunds';
+			msg: 'Insufficient reward pool funds';
+		},
+		{
+			code: 6098;
+			name: 'forbiddenAsNonFunder';
+			msg: 'Only the reward pool funder can reclaim it';
+		},
+		{
+			code: 6099;
+			name: 'badgesAlreadyEnabled';
+			msg: 'Badges already enabled';
+		},
+		{
+			code: 6100;
+			name: 'badgeAlreadyMinted';
+			msg: 'Badge already minted';
+		},
+		{
+			code: 6101;
+			name: 'votersWeightOverflow';
+			msg: 'Voters weight overflow';
+		},
+		{
+			code: 6102;
+			name: 'verifyingKeyChanged';
+			msg: 'Verifying key changed since anonymous voting was configured';
+		},
+	];
+	types: [
+		{
+			name: 'anonymousVoted';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'proposalId';
+						type: 'u8';
+					},
+					{
+						name: 'nullifierHash';
+						type: {
+							array: ['u8', 32];
+						};
+					},
+				];
+			};
+		},
+		{
+			name: 'anonymousVotingConfigured';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'root';
+						type: {
+							array: ['u8', 32];
+						};
+					},
+				];
+			};
+		},
+		{
+			name: 'badgeMinted';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'voter';
+						type: 'pubkey';
+					},
+					{
+						name: 'mint';
+						type: 'pubkey';
+					},
+				];
+			};
+		},
+		{
+			name: 'badgesEnabled';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'mint';
+						type: 'pubkey';
+					},
+				];
+			};
+		},
+		{
+			name: 'ballotAccount';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'voter';
+						type: 'pubkey';
+					},
+					{
+						name: 'entries';
+						type: {
+							vec: {
+								defined: {
+									name: 'ballotEntry';
+								};
+							};
+						};
+					},
+				];
+			};
+		},
+		{
+			name: 'ballotCast';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'voter';
+						type: 'pubkey';
+					},
+					{
+						name: 'entries';
+						type: {
+							vec: {
+								defined: {
+									name: 'ballotEntry';
+								};
+							};
+						};
+					},
+				];
+			};
+		},
+		{
+			name: 'ballotEntry';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'proposalId';
+						type: 'u8';
+					},
+					{
+						name: 'value';
+						type: 'u8';
+					},
+				];
+			};
+		},
+		{
+			name: 'bitProof';
+			docs: ['Disjunctive Chaum-Pedersen proof that a ciphertext encrypts 0 or 1.'];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'commitments';
+						type: {
+							array: [
+								{
+									array: ['u8', 32];
+								},
+								4,
+							];
+						};
+					},
+					{
+						name: 'challenges';
+						type: {
+							array: [
+								{
+									array: ['u8', 32];
+								},
+								2,
+							];
+						};
+					},
+					{
+						name: 'responses';
+						type: {
+							array: [
+								{
+									array: ['u8', 32];
+								},
+								2,
+							];
+						};
+					},
+				];
+			};
+		},
+		{
+			name: 'ciphertext';
+			docs: ['Exponential ElGamal ciphertext (C1, C2) = (r·G, m·G + r·H), compressed ristretto points.'];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'c1';
+						type: {
+							array: ['u8', 32];
+						};
+					},
+					{
+						name: 'c2';
+						type: {
+							array: ['u8', 32];
+						};
+					},
+				];
+			};
+		},
+		{
+			name: 'decryptionShare';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'trusteeIndex';
+						type: 'u8';
+					},
+					{
+						name: 'share';
+						type: {
+							array: ['u8', 32];
+						};
+					},
+				];
+			};
+		},
+		{
+			name: 'decryptionSharePosted';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'proposalId';
+						type: 'u8';
+					},
+					{
+						name: 'trusteeIndex';
+						type: 'u8';
+					},
+				];
+			};
+		},
+		{
+			name: 'encryptedBallotAccount';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'voter';
+						type: 'pubkey';
+					},
+					{
+						name: 'sealed';
+						type: 'bool';
+					},
+					{
+						name: 'ciphertexts';
+						type: {
+							vec: {
+								defined: {
+									name: 'ciphertext';
+								};
+							};
+						};
+					},
+				];
+			};
+		},
+		{
+			name: 'encryptedBallotSealed';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'voter';
+						type: 'pubkey';
+					},
+				];
+			};
+		},
+		{
+			name: 'encryptionConfig';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'electionPublicKey';
+						type: {
+							array: ['u8', 32];
+						};
+					},
+					{
+						name: 'threshold';
+						type: 'u8';
+					},
+					{
+						name: 'trustees';
+						type: {
+							vec: {
+								defined: {
+									name: 'trustee';
+								};
+							};
+						};
+					},
+				];
+			};
+		},
+		{
+			name: 'encryptionConfigured';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'electionPublicKey';
+						type: {
+							array: ['u8', 32];
+						};
+					},
+					{
+						name: 'threshold';
+						type: 'u8';
+					},
+					{
+						name: 'trustees';
+						type: {
+							vec: 'pubkey';
+						};
+					},
+				];
+			};
+		},
+		{
+			name: 'equalityProof';
+			docs: ['Chaum-Pedersen proof that log_g1(y1) == log_g2(y2).'];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'commitments';
+						type: {
+							array: [
+								{
+									array: ['u8', 32];
+								},
+								2,
+							];
+						};
+					},
+					{
+						name: 'response';
+						type: {
+							array: ['u8', 32];
+						};
+					},
+				];
+			};
+		},
+		{
+			name: 'executionConfigured';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'executionDelay';
+						type: 'i64';
+					},
+					{
+						name: 'vetoCouncil';
+						type: {
+							option: 'pubkey';
+						};
+					},
+				];
+			};
+		},
+		{
+			name: 'globalAccount';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionCount';
+						type: 'u64';
+					},
+					{
+						name: 'authority';
+						type: 'pubkey';
+					},
+					{
+						name: 'config';
+						type: {
+							defined: {
+								name: 'globalConfig';
+							};
+						};
+					},
+					{
+						name: 'paused';
+						type: 'bool';
+					},
+					{
+						name: 'organizationCount';
+						type: 'u64';
+					},
+				];
+			};
+		},
+		{
+			name: 'globalConfig';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionFee';
+						type: 'u64';
+					},
+					{
+						name: 'maxNameLen';
+						type: 'u8';
+					},
+					{
+						name: 'maxDescriptionLen';
+						type: 'u8';
+					},
+					{
+						name: 'maxVoters';
+						type: 'u32';
+					},
+				];
+			};
+		},
+		{
+			name: 'globalConfigUpdated';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'authority';
+						type: 'pubkey';
+					},
+					{
+						name: 'sessionFee';
+						type: 'u64';
+					},
+					{
+						name: 'maxNameLen';
+						type: 'u8';
+					},
+					{
+						name: 'maxDescriptionLen';
+						type: 'u8';
+					},
+					{
+						name: 'maxVoters';
+						type: 'u32';
+					},
+				];
+			};
+		},
+		{
+			name: 'groth16Proof';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'a';
+						type: {
+							array: ['u8', 64];
+						};
+					},
+					{
+						name: 'b';
+						type: {
+							array: ['u8', 128];
+						};
+					},
+					{
+						name: 'c';
+						type: {
+							array: ['u8', 64];
+						};
+					},
+				];
+			};
+		},
+		{
+			name: 'identityCommitmentAdded';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'identityCommitment';
+						type: {
+							array: ['u8', 32];
+						};
+					},
+					{
+						name: 'leafIndex';
+						type: 'u32';
+					},
+					{
+						name: 'root';
+						type: {
+							array: ['u8', 32];
+						};
+					},
+				];
+			};
+		},
+		{
+			name: 'identityTreeAccount';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'leavesCount';
+						type: 'u32';
+					},
+					{
+						name: 'root';
+						type: {
+							array: ['u8', 32];
+						};
+					},
+					{
+						name: 'verifyingKeyHash';
+						type: {
+							array: ['u8', 32];
+						};
+					},
+					{
+						name: 'filledSubtrees';
+						type: {
+							array: [
+								{
+									array: ['u8', 32];
+								},
+								16,
+							];
+						};
+					},
+					{
+						name: 'zeros';
+						type: {
+							array: [
+								{
+									array: ['u8', 32];
+								},
+								16,
+							];
+						};
+					},
+				];
+			};
+		},
+		{
+			name: 'lockAccount';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'owner';
+						type: 'pubkey';
+					},
+					{
+						name: 'mint';
+						type: 'pubkey';
+					},
+					{
+						name: 'amount';
+						type: 'u64';
+					},
+					{
+						name: 'unlockAt';
+						type: 'i64';
+					},
+					{
+						name: 'updatedAt';
+						type: 'i64';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+				];
+			};
+		},
+		{
+			name: 'nullifierAccount';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'nullifierHash';
+						type: {
+							array: ['u8', 32];
+						};
+					},
+				];
+			};
+		},
+		{
+			name: 'organizationAccount';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'organizationId';
+						type: 'u64';
+					},
+					{
+						name: 'name';
+						type: 'string';
+					},
+					{
+						name: 'admins';
+						type: {
+							vec: 'pubkey';
+						};
+					},
+					{
+						name: 'membersCount';
+						type: 'u32';
+					},
+					{
+						name: 'membersSeq';
+						type: 'u32';
+					},
+				];
+			};
+		},
+		{
+			name: 'organizationAdminAdded';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'organizationId';
+						type: 'u64';
+					},
+					{
+						name: 'admin';
+						type: 'pubkey';
+					},
+				];
+			};
+		},
+		{
+			name: 'organizationAdminRemoved';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'organizationId';
+						type: 'u64';
+					},
+					{
+						name: 'admin';
+						type: 'pubkey';
+					},
+				];
+			};
+		},
+		{
+			name: 'organizationCreated';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'organizationId';
+						type: 'u64';
+					},
+					{
+						name: 'name';
+						type: 'string';
+					},
+					{
+						name: 'admin';
+						type: 'pubkey';
+					},
+				];
+			};
+		},
+		{
+			name: 'organizationMemberAccount';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'organizationId';
+						type: 'u64';
+					},
+					{
+						name: 'member';
+						type: 'pubkey';
+					},
+					{
+						name: 'memberId';
+						type: 'u32';
+					},
+					{
+						name: 'active';
+						type: 'bool';
+					},
+				];
+			};
+		},
+		{
+			name: 'organizationMemberAdded';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'organizationId';
+						type: 'u64';
+					},
+					{
+						name: 'member';
+						type: 'pubkey';
+					},
+					{
+						name: 'memberId';
+						type: 'u32';
+					},
+				];
+			};
+		},
+		{
+			name: 'organizationMemberRemoved';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'organizationId';
+						type: 'u64';
+					},
+					{
+						name: 'member';
+						type: 'pubkey';
+					},
+				];
+			};
+		},
+		{
+			name: 'pairwiseMatrixAccount';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'candidatesCount';
+						type: 'u8';
+					},
+					{
+						name: 'preferences';
+						type: {
+							array: [
+								{
+									array: ['u32', 16];
+								},
+								16,
+							];
+						};
+					},
+				];
+			};
+		},
+		{
+			name: 'pausedChanged';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'authority';
+						type: 'pubkey';
+					},
+					{
+						name: 'paused';
+						type: 'bool';
+					},
+				];
+			};
+		},
+		{
+			name: 'proposalAccount';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'proposalId';
+						type: 'u8';
+					},
+					{
+						name: 'description';
+						type: 'string';
+					},
+					{
+						name: 'proposer';
+						type: 'pubkey';
+					},
+					{
+						name: 'voteCount';
+						type: 'u32';
+					},
+					{
+						name: 'depositAmount';
+						type: 'u64';
+					},
+					{
+						name: 'depositSettled';
+						type: 'bool';
+					},
+					{
+						name: 'treasuryTransfer';
+						type: {
+							option: {
+								defined: {
+									name: 'treasuryTransfer';
+								};
+							};
+						};
+					},
+					{
+						name: 'metadataUri';
+						type: 'string';
+					},
+					{
+						name: 'metadataHash';
+						type: {
+							array: ['u8', 32];
+						};
+					},
+					{
+						name: 'questionId';
+						type: 'u8';
+					},
+					{
+						name: 'scoreSum';
+						type: 'u64';
+					},
+					{
+						name: 'scoreCount';
+						type: 'u32';
+					},
+					{
+						name: 'scoreDistribution';
+						type: {
+							array: ['u32', 11];
+						};
+					},
+					{
+						name: 'encryptedVotes';
+						type: {
+							defined: {
+								name: 'ciphertext';
+							};
+						};
+					},
+					{
+						name: 'decryptionShares';
+						type: {
+							vec: {
+								defined: {
+									name: 'decryptionShare';
+								};
+							};
+						};
+					},
+					{
+						name: 'votesRevealed';
+						type: 'bool';
+					},
+				];
+			};
+		},
+		{
+			name: 'proposalAccountMeta';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'pubkey';
+						type: 'pubkey';
+					},
+					{
+						name: 'isSigner';
+						type: 'bool';
+					},
+					{
+						name: 'isWritable';
+						type: 'bool';
+					},
+				];
+			};
+		},
+		{
+			name: 'proposalDepositEscrowed';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'proposalId';
+						type: 'u8';
+					},
+					{
+						name: 'proposer';
+						type: 'pubkey';
+					},
+					{
+						name: 'mint';
+						type: {
+							option: 'pubkey';
+						};
+					},
+					{
+						name: 'amount';
+						type: 'u64';
+					},
+				];
+			};
+		},
+		{
+			name: 'proposalDepositSettled';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'proposalId';
+						type: 'u8';
+					},
+					{
+						name: 'refunded';
+						type: 'bool';
+					},
+					{
+						name: 'amount';
+						type: 'u64';
+					},
+				];
+			};
+		},
+		{
+			name: 'proposalExecuted';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'proposalId';
+						type: 'u8';
+					},
+				];
+			};
+		},
+		{
+			name: 'proposalInstruction';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'programId';
+						type: 'pubkey';
+					},
+					{
+						name: 'accounts';
+						type: {
+							vec: {
+								defined: {
+									name: 'proposalAccountMeta';
+								};
+							};
+						};
+					},
+					{
+						name: 'data';
+						type: 'bytes';
+					},
+				];
+			};
+		},
+		{
+			name: 'proposalOutcome';
+			docs: ['Returned by `get_proposal_outcome` to calling programs, see `SessionAccount::proposal_outcome`.'];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'proposalId';
+						type: 'u8';
+					},
+					{
+						name: 'won';
+						type: 'bool';
+					},
+					{
+						name: 'rank';
+						type: 'u8';
+					},
+					{
+						name: 'tied';
+						type: 'bool';
+					},
+					{
+						name: 'totalVotes';
+						type: 'u32';
+					},
+					{
+						name: 'talliedAt';
+						type: 'i64';
+					},
+					{
+						name: 'releasedAt';
+						type: 'i64';
+					},
+				];
+			};
+		},
+		{
+			name: 'proposalRegistered';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'proposalId';
+						type: 'u8';
+					},
+					{
+						name: 'description';
+						type: 'string';
+					},
+					{
+						name: 'metadataUri';
+						type: 'string';
+					},
+					{
+						name: 'metadataHash';
+						type: {
+							array: ['u8', 32];
+						};
+					},
+				];
+			};
+		},
+		{
+			name: 'proposalTransactionAccount';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'proposalId';
+						type: 'u8';
+					},
+					{
+						name: 'executed';
+						type: 'bool';
+					},
+					{
+						name: 'instructions';
+						type: {
+							vec: {
+								defined: {
+									name: 'proposalInstruction';
+								};
+							};
+						};
+					},
+				];
+			};
+		},
+		{
+			name: 'proposalTransactionAttached';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'proposalId';
+						type: 'u8';
+					},
+					{
+						name: 'instructionsCount';
+						type: 'u8';
+					},
+				];
+			};
+		},
+		{
+			name: 'proposalVotesRevealed';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'proposalId';
+						type: 'u8';
+					},
+					{
+						name: 'voteCount';
+						type: 'u32';
+					},
+				];
+			};
+		},
+		{
+			name: 'proposalsConfig';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'depositAmount';
+						type: 'u64';
+					},
+					{
+						name: 'depositMint';
+						type: {
+							option: 'pubkey';
+						};
+					},
+					{
+						name: 'refundThresholdBps';
+						type: 'u16';
+					},
+					{
+						name: 'maxProposalsPerVoter';
+						type: 'u8';
+					},
+				];
+			};
+		},
+		{
+			name: 'proposalsConfigured';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'depositAmount';
+						type: 'u64';
+					},
+					{
+						name: 'depositMint';
+						type: {
+							option: 'pubkey';
+						};
+					},
+					{
+						name: 'refundThresholdBps';
+						type: 'u16';
+					},
+					{
+						name: 'maxProposalsPerVoter';
+						type: 'u8';
+					},
+				];
+			};
+		},
+		{
+			name: 'questionAccount';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'questionId';
+						type: 'u8';
+					},
+					{
+						name: 'title';
+						type: 'string';
+					},
+					{
+						name: 'blankProposalId';
+						type: 'u8';
+					},
+					{
+						name: 'result';
+						type: {
+							defined: {
+								name: 'questionResult';
+							};
+						};
+					},
+				];
+			};
+		},
+		{
+			name: 'questionAdded';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'questionId';
+						type: 'u8';
+					},
+					{
+						name: 'title';
+						type: 'string';
+					},
+					{
+						name: 'blankProposalId';
+						type: 'u8';
+					},
+				];
+			};
+		},
+		{
+			name: 'questionResult';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'questionId';
+						type: 'u8';
+					},
+					{
+						name: 'totalVotes';
+						type: 'u32';
+					},
+					{
+						name: 'blankVotes';
+						type: 'u32';
+					},
+					{
+						name: 'abstention';
+						type: 'u32';
+					},
+					{
+						name: 'winningProposals';
+						type: 'bytes';
+					},
+					{
+						name: 'tiedProposals';
+						type: 'bytes';
+					},
+				];
+			};
+		},
+		{
+			name: 'receiptAccumulatorAccount';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'receiptsCount';
+						type: 'u32';
+					},
+					{
+						name: 'root';
+						type: {
+							array: ['u8', 32];
+						};
+					},
+					{
+						name: 'filledSubtrees';
+						type: {
+							array: [
+								{
+									array: ['u8', 32];
+								},
+								20,
+							];
+						};
+					},
+					{
+						name: 'zeros';
+						type: {
+							array: [
+								{
+									array: ['u8', 32];
+								},
+								20,
+							];
+						};
+					},
+				];
+			};
+		},
+		{
+			name: 'resultQueued';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'talliedAt';
+						type: 'i64';
+					},
+					{
+						name: 'executionEta';
+						type: 'i64';
+					},
+				];
+			};
+		},
+		{
+			name: 'resultReleased';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'releasedAt';
+						type: 'i64';
+					},
+				];
+			};
+		},
+		{
+			name: 'resultVetoed';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'vetoCouncil';
+						type: 'pubkey';
+					},
+					{
+						name: 'vetoedAt';
+						type: 'i64';
+					},
+				];
+			};
+		},
+		{
+			name: 'rewardClaimed';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'voter';
+						type: 'pubkey';
+					},
+					{
+						name: 'mint';
+						type: {
+							option: 'pubkey';
+						};
+					},
+					{
+						name: 'amount';
+						type: 'u64';
+					},
+				];
+			};
+		},
+		{
+			name: 'rewardPoolAccount';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'funder';
+						type: 'pubkey';
+					},
+					{
+						name: 'mint';
+						type: {
+							option: 'pubkey';
+						};
+					},
+					{
+						name: 'rewardPerVoter';
+						type: 'u64';
+					},
+					{
+						name: 'fundedAmount';
+						type: 'u64';
+					},
+					{
+						name: 'claimedAmount';
+						type: 'u64';
+					},
+					{
+						name: 'claimsCount';
+						type: 'u32';
+					},
+					{
+						name: 'claimDuration';
+						type: 'i64';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+				];
+			};
+		},
+		{
+			name: 'rewardPoolFunded';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'funder';
+						type: 'pubkey';
+					},
+					{
+						name: 'mint';
+						type: {
+							option: 'pubkey';
+						};
+					},
+					{
+						name: 'amount';
+						type: 'u64';
+					},
+					{
+						name: 'rewardPerVoter';
+						type: 'u64';
+					},
+					{
+						name: 'claimDuration';
+						type: 'i64';
+					},
+				];
+			};
+		},
+		{
+			name: 'rewardPoolReclaimed';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'funder';
+						type: 'pubkey';
+					},
+					{
+						name: 'mint';
+						type: {
+							option: 'pubkey';
+						};
+					},
+					{
+						name: 'amount';
+						type: 'u64';
+					},
+				];
+			};
+		},
+		{
+			name: 'sessionAccount';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'name';
+						type: 'string';
+					},
+					{
+						name: 'description';
+						type: 'string';
+					},
+					{
+						name: 'status';
+						type: {
+							defined: {
+								name: 'sessionWorkflowStatus';
+							};
+						};
+					},
+					{
+						name: 'admin';
+						type: 'pubkey';
+					},
+					{
+						name: 'votersCount';
+						type: 'u32';
+					},
+					{
+						name: 'proposalsCount';
+						type: 'u8';
+					},
+					{
+						name: 'result';
+						type: {
+							defined: {
+								name: 'sessionResult';
+							};
+						};
+					},
+					{
+						name: 'proposalsConfig';
+						type: {
+							defined: {
+								name: 'proposalsConfig';
+							};
+						};
+					},
+					{
+						name: 'executionDelay';
+						type: 'i64';
+					},
+					{
+						name: 'vetoCouncil';
+						type: {
+							option: 'pubkey';
+						};
+					},
+					{
+						name: 'talliedAt';
+						type: 'i64';
+					},
+					{
+						name: 'executionEta';
+						type: 'i64';
+					},
+					{
+						name: 'vetoedAt';
+						type: 'i64';
+					},
+					{
+						name: 'releasedAt';
+						type: 'i64';
+					},
+					{
+						name: 'organizationId';
+						type: {
+							option: 'u64';
+						};
+					},
+					{
+						name: 'organizationMembersSeq';
+						type: 'u32';
+					},
+					{
+						name: 'metadataUri';
+						type: 'string';
+					},
+					{
+						name: 'metadataHash';
+						type: {
+							array: ['u8', 32];
+						};
+					},
+					{
+						name: 'questionsCount';
+						type: 'u8';
+					},
+					{
+						name: 'votingMode';
+						type: {
+							defined: {
+								name: 'votingMode';
+							};
+						};
+					},
+					{
+						name: 'ballotsCount';
+						type: 'u32';
+					},
+					{
+						name: 'seats';
+						type: 'u8';
+					},
+					{
+						name: 'votersWeight';
+						type: 'u32';
+					},
+					{
+						name: 'ballotsWeight';
+						type: 'u32';
+					},
+					{
+						name: 'lockMint';
+						type: {
+							option: 'pubkey';
+						};
+					},
+					{
+						name: 'votingStartedAt';
+						type: 'i64';
+					},
+					{
+						name: 'snapshotSlot';
+						type: 'u64';
+					},
+					{
+						name: 'encryption';
+						type: {
+							option: {
+								defined: {
+									name: 'encryptionConfig';
+								};
+							};
+						};
+					},
+					{
+						name: 'anonymous';
+						type: 'bool';
+					},
+					{
+						name: 'badgeMint';
+						type: {
+							option: 'pubkey';
+						};
+					},
+					{
+						name: 'badgeMintBump';
+						type: 'u8';
+					},
+				];
+			};
 		},
 		{
-			name: 'proposalAccount';
-			discriminator: [164, 190, 4, 248, 203, 124, 243, 64];
+			name: 'sessionCreated';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'name';
+						type: 'string';
+					},
+					{
+						name: 'description';
+						type: 'string';
+					},
+					{
+						name: 'metadataUri';
+						type: 'string';
+					},
+					{
+						name: 'metadataHash';
+						type: {
+							array: ['u8', 32];
+						};
+					},
+				];
+			};
 		},
 		{
-			name: 'sessionAccount';
-			discriminator: [74, 34, 65, 133, 96, 163, 80, 69];
+			name: 'sessionIndexAccount';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'owner';
+						type: 'pubkey';
+					},
+					{
+						name: 'sessionsCount';
+						type: 'u32';
+					},
+				];
+			};
 		},
 		{
-			name: 'voterAccount';
-			discriminator: [24, 202, 161, 124, 196, 184, 105, 236];
+			name: 'sessionIndexPageAccount';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'owner';
+						type: 'pubkey';
+					},
+					{
+						name: 'page';
+						type: 'u32';
+					},
+					{
+						name: 'sessionIds';
+						type: {
+							vec: 'u64';
+						};
+					},
+				];
+			};
 		},
-	];
-	events: [
 		{
-			name: 'proposalRegistered';
-			discriminator: [194, 224, 148, 158, 74, 91, 11, 247];
+			name: 'sessionResult';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'totalVotes';
+						type: 'u32';
+					},
+					{
+						name: 'blankVotes';
+						type: 'u32';
+					},
+					{
+						name: 'abstention';
+						type: 'u32';
+					},
+					{
+						name: 'winningProposals';
+						type: 'bytes';
+					},
+					{
+						name: 'scoreDistribution';
+						type: {
+							vec: 'u32';
+						};
+					},
+					{
+						name: 'hasCondorcetWinner';
+						type: 'bool';
+					},
+					{
+						name: 'tiedProposals';
+						type: 'bytes';
+					},
+					{
+						name: 'votedCount';
+						type: 'u32';
+					},
+					{
+						name: 'abstentionCount';
+						type: 'u32';
+					},
+					{
+						name: 'receiptsRoot';
+						type: {
+							array: ['u8', 32];
+						};
+					},
+					{
+						name: 'receiptsCount';
+						type: 'u32';
+					},
+				];
+			};
 		},
 		{
-			name: 'sessionCreated';
-			discriminator: [107, 111, 254, 25, 21, 122, 220, 225];
+			name: 'sessionWorkflowStatus';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'none';
+					},
+					{
+						name: 'registeringVoters';
+					},
+					{
+						name: 'proposalsRegistrationStarted';
+					},
+					{
+						name: 'proposalsRegistrationEnded';
+					},
+					{
+						name: 'votingSessionStarted';
+					},
+					{
+						name: 'votingSessionEnded';
+					},
+					{
+						name: 'votesTallied';
+					},
+					{
+						name: 'vetoed';
+					},
+				];
+			};
 		},
 		{
 			name: 'sessionWorkflowStatusChanged';
-			discriminator: [37, 80, 31, 154, 111, 190, 223, 237];
-		},
-		{
-			name: 'voted';
-			discriminator: [189, 74, 101, 127, 109, 214, 95, 130];
-		},
-		{
-			name: 'voterRegistered';
-			discriminator: [184, 179, 209, 46, 125, 60, 51, 197];
-		},
-		{
-			name: 'votesTallied';
-			discriminator: [209, 23, 244, 97, 94, 140, 180, 165];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'previousStatus';
+						type: {
+							defined: {
+								name: 'sessionWorkflowStatus';
+							};
+						};
+					},
+					{
+						name: 'currentStatus';
+						type: {
+							defined: {
+								name: 'sessionWorkflowStatus';
+							};
+						};
+					},
+				];
+			};
 		},
-	];
-	errors: [
 		{
-			code: 6000;
-			name: 'unexpectedSessionStatus';
-			msg: 'Unexpected session status';
+			name: 'signedBallotsRelayed';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'relayedCount';
+						type: 'u32';
+					},
+					{
+						name: 'skippedVoters';
+						type: {
+							vec: 'pubkey';
+						};
+					},
+				];
+			};
 		},
 		{
-			code: 6001;
-			name: 'adminForbiddenAsVoter';
-			msg: 'Voting session administrator can not be registered as voter';
+			name: 'tokensLocked';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'owner';
+						type: 'pubkey';
+					},
+					{
+						name: 'mint';
+						type: 'pubkey';
+					},
+					{
+						name: 'amount';
+						type: 'u64';
+					},
+					{
+						name: 'unlockAt';
+						type: 'i64';
+					},
+				];
+			};
 		},
 		{
-			code: 6002;
-			name: 'forbiddenAsNonAdmin';
-			msg: 'Forbidden as non administrator';
+			name: 'tokensUnlocked';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'owner';
+						type: 'pubkey';
+					},
+					{
+						name: 'mint';
+						type: 'pubkey';
+					},
+					{
+						name: 'amount';
+						type: 'u64';
+					},
+				];
+			};
 		},
 		{
-			code: 6003;
-			name: 'forbiddenAsAdmin';
-			msg: 'Forbidden as administrator';
+			name: 'treasuryAccount';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+				];
+			};
 		},
 		{
-			code: 6004;
-			name: 'voterAlreadyRegistered';
-			msg: 'Voter already registered';
+			name: 'treasuryDeposited';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'depositor';
+						type: 'pubkey';
+					},
+					{
+						name: 'mint';
+						type: {
+							option: 'pubkey';
+						};
+					},
+					{
+						name: 'amount';
+						type: 'u64';
+					},
+				];
+			};
 		},
 		{
-			code: 6005;
-			name: 'proposerNotRegistered';
-			msg: 'Proposer must be registered as voter';
+			name: 'treasuryTransfer';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'recipient';
+						type: 'pubkey';
+					},
+					{
+						name: 'mint';
+						type: {
+							option: 'pubkey';
+						};
+					},
+					{
+						name: 'amount';
+						type: 'u64';
+					},
+					{
+						name: 'executed';
+						type: 'bool';
+					},
+				];
+			};
 		},
 		{
-			code: 6006;
-			name: 'voterAlreadyVoted';
-			msg: 'Voter already voted';
+			name: 'treasuryTransferRequested';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'proposalId';
+						type: 'u8';
+					},
+					{
+						name: 'recipient';
+						type: 'pubkey';
+					},
+					{
+						name: 'mint';
+						type: {
+							option: 'pubkey';
+						};
+					},
+					{
+						name: 'amount';
+						type: 'u64';
+					},
+				];
+			};
 		},
 		{
-			code: 6007;
-			name: 'unexpectedVoter';
-			msg: 'Unexpected voter';
+			name: 'treasuryWithdrawn';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'proposalId';
+						type: 'u8';
+					},
+					{
+						name: 'recipient';
+						type: 'pubkey';
+					},
+					{
+						name: 'mint';
+						type: {
+							option: 'pubkey';
+						};
+					},
+					{
+						name: 'amount';
+						type: 'u64';
+					},
+				];
+			};
 		},
 		{
-			code: 6008;
-			name: 'forbiddenAbstention';
-			msg: 'Abstention can not be voted';
+			name: 'trustee';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'authority';
+						type: 'pubkey';
+					},
+					{
+						name: 'verificationKey';
+						type: {
+							array: ['u8', 32];
+						};
+					},
+				];
+			};
 		},
 		{
-			code: 6009;
-			name: 'invalidAccountType';
-			msg: 'Invalid account';
+			name: 'vaultAccount';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sessionId';
+						type: 'u64';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+				];
+			};
 		},
 		{
-			code: 6010;
-			name: 'invalidProposalId';
-			msg: 'Invalid proposal';
+			name: 'verifyingKey';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'alphaG1';
+						type: {
+							array: ['u8', 64];
+						};
+					},
+					{
+						name: 'betaG2';
+						type: {
+							array: ['u8', 128];
+						};
+					},
+					{
+						name: 'gammaG2';
+						type: {
+							array: ['u8', 128];
+						};
+					},
+					{
+						name: 'deltaG2';
+						type: {
+							array: ['u8', 128];
+						};
+					},
+					{
+						name: 'ic';
+						type: {
+							array: [
+								{
+									array: ['u8', 64];
+								},
+								5,
+							];
+						};
+					},
+				];
+			};
 		},
 		{
-			code: 6011;
-			name: 'badProposalAccountsCount';
-			msg: 'Bad proposals accounts count';
+			name: 'verifyingKeyAccount';
+			docs: [
+				'Groth16 verifying key of the Semaphore membership circuit, for the identity tree depth.',
+				'Points are big endian, G2 coordinates being (imaginary, real) as in EIP-197.',
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'alphaG1';
+						type: {
+							array: ['u8', 64];
+						};
+					},
+					{
+						name: 'betaG2';
+						type: {
+							array: ['u8', 128];
+						};
+					},
+					{
+						name: 'gammaG2';
+						type: {
+							array: ['u8', 128];
+						};
+					},
+					{
+						name: 'deltaG2';
+						type: {
+							array: ['u8', 128];
+						};
+					},
+					{
+						name: 'ic';
+						type: {
+							array: [
+								{
+									array: ['u8', 64];
+								},
+								5,
+							];
+						};
+					},
+				];
+			};
 		},
-	];
-	types: [
 		{
-			name: 'globalAccount';
+			name: 'verifyingKeyUpdated';
 			type: {
 				kind: 'struct';
 				fields: [
 					{
-						name: 'sessionCount';
-						type: 'u64';
+						name: 'authority';
+						type: 'pubkey';
 					},
 				];
 			};
 		},
 		{
-			name: 'proposalAccount';
+			name: 'voteReceiptAdded';
 			type: {
 				kind: 'struct';
 				fields: [
@@ -454,26 +6711,38 @@ export type Voting = {
 						type: 'u64';
 					},
 					{
-						name: 'proposalId';
-						type: 'u8';
+						name: 'voter';
+						type: 'pubkey';
 					},
 					{
-						name: 'description';
-						type: 'string';
+						name: 'ballot';
+						type: 'bytes';
 					},
 					{
-						name: 'proposer';
-						type: 'pubkey';
+						name: 'slot';
+						type: 'u64';
 					},
 					{
-						name: 'voteCount';
+						name: 'receipt';
+						type: {
+							array: ['u8', 32];
+						};
+					},
+					{
+						name: 'receiptIndex';
 						type: 'u32';
 					},
+					{
+						name: 'root';
+						type: {
+							array: ['u8', 32];
+						};
+					},
 				];
 			};
 		},
 		{
-			name: 'proposalRegistered';
+			name: 'voted';
 			type: {
 				kind: 'struct';
 				fields: [
@@ -481,19 +6750,23 @@ export type Voting = {
 						name: 'sessionId';
 						type: 'u64';
 					},
+					{
+						name: 'questionId';
+						type: 'u8';
+					},
 					{
 						name: 'proposalId';
 						type: 'u8';
 					},
 					{
-						name: 'description';
-						type: 'string';
+						name: 'voter';
+						type: 'pubkey';
 					},
 				];
 			};
 		},
 		{
-			name: 'sessionAccount';
+			name: 'voterAccount';
 			type: {
 				kind: 'struct';
 				fields: [
@@ -502,46 +6775,78 @@ export type Voting = {
 						type: 'u64';
 					},
 					{
-						name: 'name';
-						type: 'string';
+						name: 'voter';
+						type: 'pubkey';
 					},
 					{
-						name: 'description';
-						type: 'string';
+						name: 'voterId';
+						type: 'u32';
 					},
 					{
-						name: 'status';
+						name: 'hasVoted';
+						type: 'bool';
+					},
+					{
+						name: 'votedProposalId';
+						type: 'u8';
+					},
+					{
+						name: 'nbProposals';
+						type: 'u8';
+					},
+					{
+						name: 'questionVotes';
 						type: {
-							defined: {
-								name: 'sessionWorkflowStatus';
-							};
+							array: ['u8', 16];
 						};
 					},
 					{
-						name: 'admin';
-						type: 'pubkey';
+						name: 'seatVotes';
+						type: {
+							array: ['u8', 10];
+						};
 					},
 					{
-						name: 'votersCount';
+						name: 'credits';
+						type: {
+							option: 'u32';
+						};
+					},
+					{
+						name: 'creditsSpent';
 						type: 'u32';
 					},
 					{
-						name: 'proposalsCount';
-						type: 'u8';
+						name: 'weight';
+						type: 'u32';
 					},
 					{
-						name: 'result';
+						name: 'nonce';
+						type: 'u64';
+					},
+					{
+						name: 'identityCommitment';
 						type: {
-							defined: {
-								name: 'sessionResult';
-							};
+							array: ['u8', 32];
 						};
 					},
+					{
+						name: 'rewardClaimed';
+						type: 'bool';
+					},
+					{
+						name: 'badgeMinted';
+						type: 'bool';
+					},
+					{
+						name: 'powerApplied';
+						type: 'bool';
+					},
 				];
 			};
 		},
 		{
-			name: 'sessionCreated';
+			name: 'voterRegistered';
 			type: {
 				kind: 'struct';
 				fields: [
@@ -550,71 +6855,74 @@ export type Voting = {
 						type: 'u64';
 					},
 					{
-						name: 'name';
-						type: 'string';
+						name: 'voter';
+						type: 'pubkey';
 					},
 					{
-						name: 'description';
-						type: 'string';
+						name: 'weight';
+						type: 'u32';
 					},
 				];
 			};
 		},
 		{
-			name: 'sessionResult';
+			name: 'voterSnapshotAccount';
 			type: {
 				kind: 'struct';
 				fields: [
 					{
-						name: 'totalVotes';
-						type: 'u32';
+						name: 'sessionId';
+						type: 'u64';
 					},
 					{
-						name: 'blankVotes';
-						type: 'u32';
+						name: 'voter';
+						type: 'pubkey';
 					},
 					{
-						name: 'abstention';
-						type: 'u32';
+						name: 'slot';
+						type: 'u64';
 					},
 					{
-						name: 'winningProposals';
-						type: 'bytes';
+						name: 'amount';
+						type: 'u64';
+					},
+					{
+						name: 'unlockAt';
+						type: 'i64';
 					},
 				];
 			};
 		},
 		{
-			name: 'sessionWorkflowStatus';
+			name: 'voterSnapshotTaken';
 			type: {
-				kind: 'enum';
-				variants: [
-					{
-						name: 'none';
-					},
-					{
-						name: 'registeringVoters';
-					},
+				kind: 'struct';
+				fields: [
 					{
-						name: 'proposalsRegistrationStarted';
+						name: 'sessionId';
+						type: 'u64';
 					},
 					{
-						name: 'proposalsRegistrationEnded';
+						name: 'voter';
+						type: 'pubkey';
 					},
 					{
-						name: 'votingSessionStarted';
+						name: 'slot';
+						type: 'u64';
 					},
 					{
-						name: 'votingSessionEnded';
+						name: 'amount';
+						type: 'u64';
 					},
 					{
-						name: 'votesTallied';
+						name: 'unlockAt';
+						type: 'i64';
 					},
 				];
 			};
 		},
 		{
-			name: 'sessionWorkflowStatusChanged';
+			name: 'votesTallied';
 			type: {
 				kind: 'struct';
 				fields: [
@@ -623,46 +6931,115 @@ export type Voting = {
 						type: 'u64';
 					},
 					{
-						name: 'previousStatus';
+						name: 'votersCount';
+						type: 'u32';
+					},
+					{
+						name: 'totalVotes';
+						type: 'u32';
+					},
+					{
+						name: 'blankVotes';
+						type: 'u32';
+					},
+					{
+						name: 'abstention';
+						type: 'u32';
+					},
+					{
+						name: 'winningProposals';
+						type: 'bytes';
+					},
+					{
+						name: 'questions';
 						type: {
-							defined: {
-								name: 'sessionWorkflowStatus';
+							vec: {
+								defined: {
+									name: 'questionResult';
+								};
 							};
 						};
 					},
 					{
-						name: 'currentStatus';
+						name: 'scoreDistribution';
 						type: {
-							defined: {
-								name: 'sessionWorkflowStatus';
-							};
+							vec: 'u32';
+						};
+					},
+					{
+						name: 'hasCondorcetWinner';
+						type: 'bool';
+					},
+					{
+						name: 'tiedProposals';
+						type: 'bytes';
+					},
+					{
+						name: 'votedCount';
+						type: 'u32';
+					},
+					{
+						name: 'abstentionCount';
+						type: 'u32';
+					},
+					{
+						name: 'receiptsRoot';
+						type: {
+							array: ['u8', 32];
 						};
 					},
+					{
+						name: 'receiptsCount';
+						type: 'u32';
+					},
 				];
 			};
 		},
 		{
-			name: 'voted';
+			name: 'votingMode';
 			type: {
-				kind: 'struct';
-				fields: [
+				kind: 'enum';
+				variants: [
 					{
-						name: 'sessionId';
-						type: 'u64';
+						name: 'plurality';
 					},
 					{
-						name: 'proposalId';
-						type: 'u8';
+						name: 'score';
+						fields: [
+							{
+								name: 'maxScore';
+								type: 'u8';
+							},
+						];
 					},
 					{
-						name: 'voter';
-						type: 'pubkey';
+						name: 'condorcet';
+					},
+					{
+						name: 'quadratic';
+						fields: [
+							{
+								name: 'credits';
+								type: 'u32';
+							},
+						];
+					},
+					{
+						name: 'cumulative';
+						fields: [
+							{
+								name: 'points';
+								type: {
+									option: 'u8';
+								};
+							},
+						];
 					},
 				];
 			};
 		},
 		{
-			name: 'voterAccount';
+			name: 'votingModeConfigured';
 			type: {
 				kind: 'struct';
 				fields: [
@@ -671,30 +7048,22 @@ export type Voting = {
 						type: 'u64';
 					},
 					{
-						name: 'voter';
-						type: 'pubkey';
-					},
-					{
-						name: 'voterId';
-						type: 'u32';
-					},
-					{
-						name: 'hasVoted';
-						type: 'bool';
-					},
-					{
-						name: 'votedProposalId';
-						type: 'u8';
+						name: 'votingMode';
+						type: {
+							defined: {
+								name: 'votingMode';
+							};
+						};
 					},
 					{
-						name: 'nbProposals';
+						name: 'seats';
 						type: 'u8';
 					},
 				];
 			};
 		},
 		{
-			name: 'voterRegistered';
+			name: 'votingPowerApplied';
 			type: {
 				kind: 'struct';
 				fields: [
@@ -706,11 +7075,15 @@ export type Voting = {
 						name: 'voter';
 						type: 'pubkey';
 					},
+					{
+						name: 'weight';
+						type: 'u32';
+					},
 				];
 			};
 		},
 		{
-			name: 'votesTallied';
+			name: 'votingPowerConfigured';
 			type: {
 				kind: 'struct';
 				fields: [
@@ -719,24 +7092,10 @@ export type Voting = {
 						type: 'u64';
 					},
 					{
-						name: 'votersCount';
-						type: 'u32';
-					},
-					{
-						name: 'totalVotes';
-						type: 'u32';
-					},
-					{
-						name: 'blankVotes';
-						type: 'u32';
-					},
-					{
-						name: 'abstention';
-						type: 'u32';
-					},
-					{
-						name: 'winningProposals';
-						type: 'bytes';
+						name: 'lockMint';
+						type: {
+							option: 'pubkey';
+						};
 					},
 				];
 			};
@@ -1,7 +1,7 @@
 import { Connection, Keypair } from '@solana/web3.js';
 import idl from '../target/idl/voting.json';
-import { AnchorProvider, Program, Wallet } from '@coral-xyz/anchor';
-import { Voting, VotingClient } from '../client';
+import { AnchorProvider, BN, Program, Wallet } from '@coral-xyz/anchor';
+import { DEFAULT_GLOBAL_CONFIG, Voting, VotingClient } from '../client';
 import dotenv from 'dotenv';
 
 dotenv.config();
@@ -14,6 +14,8 @@ const anchorProviderUrl = process.env.ANCHOR_PROVIDER_URL;
 if (!anchorProviderUrl) {
 	throw new Error('Missing ANCHOR_PROVIDER_URL in .env');
 }
+// optional lamports paid to the program authority on each session creation
+const sessionFee = new BN(process.env.SESSION_FEE || 0);
 const secretKey = Uint8Array.from(JSON.parse(walletSecretKey));
 const walletKeypair = Keypair.fromSecretKey(secretKey);
 
@@ -24,7 +26,8 @@ const deploy = async () => {
 	const program = new Program<Voting>(idl as Voting, provider);
 	const votingClient = new VotingClient(program, { skipPreflight: false });
 
-	const { tx } = await votingClient.initGlobal(wallet);
+	// the wallet must be the program upgrade authority
+	const { tx } = await votingClient.initGlobal(wallet, { ...DEFAULT_GLOBAL_CONFIG, sessionFee });
 
 	console.log('Transaction successful, global account created with TX:', tx);
 };
@@ -0,0 +1,376 @@
+use anchor_lang::{prelude::*, solana_program::hash::hashv};
+use solana_zk_token_sdk::curve25519::{
+    ristretto::{add_ristretto, multiply_ristretto, subtract_ristretto, PodRistrettoPoint},
+    scalar::PodScalar,
+};
+
+use crate::{
+    errors::VotingError,
+    state::encryption::{BitProof, Ciphertext, EqualityProof},
+};
+
+/// Compressed ristretto basepoint G.
+pub const BASEPOINT: [u8; 32] = [
+    0xe2, 0xf2, 0xae, 0x0a, 0x6a, 0xbc, 0x4e, 0x71, 0xa8, 0x84, 0xa9, 0x61, 0xc5, 0x00, 0x51, 0x5f,
+    0x58, 0xe3, 0x0b, 0x6a, 0xa5, 0x82, 0xdd, 0x8d, 0xb6, 0xa6, 0x59, 0x45, 0xe0, 0x8d, 0x2d, 0x76,
+];
+
+/// Compressed ristretto identity point, an encryption of 0 with no randomness.
+pub const IDENTITY: [u8; 32] = [0; 32];
+
+// group order l = 2^252 + 27742317777372353535851937790883648493, little endian limbs
+const GROUP_ORDER: [u64; 4] = [
+    0x5812631a5cf5d3ed,
+    0x14def9dea2f79cd6,
+    0,
+    0x1000000000000000,
+];
+
+pub const BIT_PROOF_LABEL: &[u8] = b"soltrain-bit-proof";
+pub const SUM_PROOF_LABEL: &[u8] = b"soltrain-sum-proof";
+pub const DECRYPTION_PROOF_LABEL: &[u8] = b"soltrain-decryption-proof";
+
+pub fn add(left: &[u8; 32], right: &[u8; 32]) -> Result<[u8; 32]> {
+    add_ristretto(&PodRistrettoPoint(*left), &PodRistrettoPoint(*right))
+        .map(|point| point.0)
+        .ok_or(error!(VotingError::InvalidCiphertext))
+}
+
+pub fn subtract(left: &[u8; 32], right: &[u8; 32]) -> Result<[u8; 32]> {
+    subtract_ristretto(&PodRistrettoPoint(*left), &PodRistrettoPoint(*right))
+        .map(|point| point.0)
+        .ok_or(error!(VotingError::InvalidCiphertext))
+}
+
+/// Multiplies a point by a canonical scalar, non canonical scalars being rejected.
+pub fn multiply(scalar: &[u8; 32], point: &[u8; 32]) -> Result<[u8; 32]> {
+    multiply_ristretto(&PodScalar(*scalar), &PodRistrettoPoint(*point))
+        .map(|point| point.0)
+        .ok_or(error!(VotingError::InvalidProof))
+}
+
+pub fn add_ciphertexts(left: &Ciphertext, right: &Ciphertext) -> Result<Ciphertext> {
+    Ok(Ciphertext {
+        c1: add(&left.c1, &right.c1)?,
+        c2: add(&left.c2, &right.c2)?,
+    })
+}
+
+pub fn multiply_ciphertext(scalar: &[u8; 32], ciphertext: &Ciphertext) -> Result<Ciphertext> {
+    Ok(Ciphertext {
+        c1: multiply(scalar, &ciphertext.c1)?,
+        c2: multiply(scalar, &ciphertext.c2)?,
+    })
+}
+
+/// Canonical scalar of a small signed integer, negative values being l - |value|.
+pub fn scalar_from_i64(value: i64) -> [u8; 32] {
+    let mut limbs = [value.unsigned_abs(), 0, 0, 0];
+    if value < 0 {
+        let mut borrow = 0;
+        for (limb, order) in limbs.iter_mut().zip(GROUP_ORDER) {
+            let (difference, overflow_a) = order.overflowing_sub(*limb);
+            let (difference, overflow_b) = difference.overflowing_sub(borrow);
+            *limb = difference;
+            borrow = (overflow_a || overflow_b) as u64;
+        }
+    }
+
+    let mut scalar = [0u8; 32];
+    for (bytes, limb) in scalar.chunks_exact_mut(8).zip(limbs) {
+        bytes.copy_from_slice(&limb.to_le_bytes());
+    }
+    scalar
+}
+
+/// Checks that the trustee verification keys and the election public key lie on the polynomial
+/// interpolated from the first `threshold` verification keys, the key of trustee i being p(i + 1)·G
+/// and the election public key p(0)·G. Lagrange coefficients are scaled by (threshold - 1)!, which
+/// every denominator divides, to be checked with integers only.
+pub fn verify_threshold_keys(
+    public_key: &[u8; 32],
+    verification_keys: &[[u8; 32]],
+    threshold: usize,
+) -> Result<()> {
+    let indexes: Vec<i64> = (1..=threshold as i64).collect();
+    let scale: i64 = (1..threshold as i64).product();
+    let interpolate = |x: i64| -> Result<[u8; 32]> {
+        let mut point = IDENTITY;
+        for (key, &index) in verification_keys.iter().zip(&indexes) {
+            let (numerator, denominator) = indexes.iter().filter(|&&other| other != index).fold(
+                (1i64, 1i64),
+                |(numerator, denominator), &other| {
+                    (numerator * (x - other), denominator * (index - other))
+                },
+            );
+            let coefficient = scalar_from_i64(numerator * (scale / denominator));
+            point = add(&point, &multiply(&coefficient, key)?)?;
+        }
+        Ok(point)
+    };
+
+    let scale = scalar_from_i64(scale);
+    require!(
+        multiply(&scale, public_key)? == interpolate(0)?,
+        VotingError::InvalidEncryptionConfig
+    );
+    for (key, x) in verification_keys.iter().zip(1i64..).skip(threshold) {
+        require!(
+            multiply(&scale, key)? == interpolate(x)?,
+            VotingError::InvalidEncryptionConfig
+        );
+    }
+    Ok(())
+}
+
+/// Fiat-Shamir challenge, truncated to 252 bits so that it is always a canonical scalar.
+fn challenge(label: &[u8], context: &[u8], points: &[&[u8; 32]]) -> [u8; 32] {
+    let mut data: Vec<&[u8]> = vec![label, context];
+    data.extend(points.iter().map(|point| point.as_slice()));
+    let mut challenge = hashv(&data).to_bytes();
+    challenge[31] &= 0x0f;
+    challenge
+}
+
+/// Verifies a Chaum-Pedersen proof that y1 = x·g1 and y2 = x·g2 for the same secret x.
+pub fn verify_equality_proof(
+    label: &[u8],
+    context: &[u8],
+    (g1, y1): (&[u8; 32], &[u8; 32]),
+    (g2, y2): (&[u8; 32], &[u8; 32]),
+    proof: &EqualityProof,
+) -> Result<()> {
+    let [a1, a2] = &proof.commitments;
+    let c = challenge(label, context, &[g1, y1, g2, y2, a1, a2]);
+
+    require!(
+        multiply(&proof.response, g1)? == add(a1, &multiply(&c, y1)?)?
+            && multiply(&proof.response, g2)? == add(a2, &multiply(&c, y2)?)?,
+        VotingError::InvalidProof
+    );
+    Ok(())
+}
+
+/// Verifies a disjunctive Chaum-Pedersen proof that a ciphertext encrypts 0 or 1 under the
+/// election public key, each branch k proving that (C1, C2 - k·G) encrypts 0.
+pub fn verify_bit_proof(
+    context: &[u8],
+    public_key: &[u8; 32],
+    ciphertext: &Ciphertext,
+    proof: &BitProof,
+) -> Result<()> {
+    let [a0, b0, a1, b1] = &proof.commitments;
+    let c = challenge(
+        BIT_PROOF_LABEL,
+        context,
+        &[public_key, &ciphertext.c1, &ciphertext.c2, a0, b0, a1, b1],
+    );
+
+    // branch challenges must split the Fiat-Shamir challenge
+    let [c0, c1] = &proof.challenges;
+    require!(
+        add(&multiply(c0, &BASEPOINT)?, &multiply(c1, &BASEPOINT)?)? == multiply(&c, &BASEPOINT)?,
+        VotingError::InvalidProof
+    );
+
+    let c2_minus_g = subtract(&ciphertext.c2, &BASEPOINT)?;
+    let branches = [(a0, b0, &ciphertext.c2), (a1, b1, &c2_minus_g)];
+    for ((a, b, c2), (challenge, response)) in branches
+        .into_iter()
+        .zip(proof.challenges.iter().zip(proof.responses.iter()))
+    {
+        require!(
+            multiply(response, &BASEPOINT)? == add(a, &multiply(challenge, &ciphertext.c1)?)?
+                && multiply(response, public_key)? == add(b, &multiply(challenge, c2)?)?,
+            VotingError::InvalidProof
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use curve25519_dalek::{
+        constants::RISTRETTO_BASEPOINT_POINT, ristretto::RistrettoPoint, scalar::Scalar,
+    };
+
+    const CONTEXT: &[u8] = b"session 1, proposal 2";
+
+    fn compress(point: RistrettoPoint) -> [u8; 32] {
+        point.compress().to_bytes()
+    }
+
+    fn public(secret: Scalar) -> [u8; 32] {
+        compress(secret * RISTRETTO_BASEPOINT_POINT)
+    }
+
+    /// Encrypts `vote` under `secret`·G with a bit proof, simulating the branch not taken.
+    fn encrypt_bit(secret: Scalar, vote: u64, randomness: Scalar) -> (Ciphertext, BitProof) {
+        let g = RISTRETTO_BASEPOINT_POINT;
+        let h = secret * g;
+        let (c1, c2) = (randomness * g, Scalar::from(vote) * g + randomness * h);
+
+        let (nonce, simulated_challenge, simulated_response) = (
+            Scalar::from(11u64),
+            Scalar::from(13u64),
+            Scalar::from(17u64),
+        );
+        let simulated = 1 - vote as usize;
+        let mut commitments = [[0; 32]; 4];
+        commitments[2 * vote as usize] = compress(nonce * g);
+        commitments[2 * vote as usize + 1] = compress(nonce * h);
+        commitments[2 * simulated] = compress(simulated_response * g - simulated_challenge * c1);
+        commitments[2 * simulated + 1] = compress(
+            simulated_response * h
+                - simulated_challenge * (c2 - Scalar::from(simulated as u64) * g),
+        );
+
+        let ciphertext = Ciphertext {
+            c1: compress(c1),
+            c2: compress(c2),
+        };
+        let [a0, b0, a1, b1] = &commitments;
+        let c = Scalar::from_canonical_bytes(challenge(
+            BIT_PROOF_LABEL,
+            CONTEXT,
+            &[&compress(h), &ciphertext.c1, &ciphertext.c2, a0, b0, a1, b1],
+        ))
+        .unwrap();
+        let real_challenge = c - simulated_challenge;
+
+        let mut challenges = [[0; 32]; 2];
+        let mut responses = [[0; 32]; 2];
+        challenges[vote as usize] = real_challenge.to_bytes();
+        responses[vote as usize] = (nonce + real_challenge * randomness).to_bytes();
+        challenges[simulated] = simulated_challenge.to_bytes();
+        responses[simulated] = simulated_response.to_bytes();
+
+        (
+            ciphertext,
+            BitProof {
+                commitments,
+                challenges,
+                responses,
+            },
+        )
+    }
+
+    #[test]
+    fn scalar_from_i64_is_canonical() {
+        for value in [0i64, 1, 5, 362_880, i64::MAX] {
+            assert_eq!(
+                scalar_from_i64(value),
+                Scalar::from(value as u64).to_bytes()
+            );
+            assert_eq!(
+                scalar_from_i64(-value),
+                (-Scalar::from(value as u64)).to_bytes()
+            );
+        }
+        assert_eq!(
+            scalar_from_i64(i64::MIN),
+            (-Scalar::from(i64::MIN.unsigned_abs())).to_bytes()
+        );
+        // accepted by the multiplication, which rejects non canonical scalars
+        assert_eq!(
+            multiply(&scalar_from_i64(-1), &BASEPOINT).unwrap(),
+            compress(-RISTRETTO_BASEPOINT_POINT)
+        );
+    }
+
+    #[test]
+    fn verify_bit_proof_accepts_encryptions_of_zero_or_one() {
+        let secret = Scalar::from(42u64);
+
+        for vote in [0, 1] {
+            let (ciphertext, proof) = encrypt_bit(secret, vote, Scalar::from(7u64));
+            assert!(verify_bit_proof(CONTEXT, &public(secret), &ciphertext, &proof).is_ok());
+        }
+    }
+
+    #[test]
+    fn verify_bit_proof_rejects_other_votes() {
+        let secret = Scalar::from(42u64);
+        let (mut ciphertext, proof) = encrypt_bit(secret, 1, Scalar::from(7u64));
+
+        // same proof for an encryption of 2
+        ciphertext.c2 = add(&ciphertext.c2, &BASEPOINT).unwrap();
+        assert_eq!(
+            verify_bit_proof(CONTEXT, &public(secret), &ciphertext, &proof).unwrap_err(),
+            VotingError::InvalidProof.into()
+        );
+    }
+
+    #[test]
+    fn verify_bit_proof_is_bound_to_its_context() {
+        let secret = Scalar::from(42u64);
+        let (ciphertext, proof) = encrypt_bit(secret, 0, Scalar::from(7u64));
+
+        assert_eq!(
+            verify_bit_proof(b"another proposal", &public(secret), &ciphertext, &proof)
+                .unwrap_err(),
+            VotingError::InvalidProof.into()
+        );
+        assert_eq!(
+            verify_bit_proof(CONTEXT, &public(Scalar::from(43u64)), &ciphertext, &proof)
+                .unwrap_err(),
+            VotingError::InvalidProof.into()
+        );
+    }
+
+    #[test]
+    fn verify_bit_proof_rejects_unbalanced_challenges() {
+        let secret = Scalar::from(42u64);
+        let (ciphertext, mut proof) = encrypt_bit(secret, 0, Scalar::from(7u64));
+
+        proof.challenges[1] = (Scalar::from_bits(proof.challenges[1]) + Scalar::one()).to_bytes();
+        assert_eq!(
+            verify_bit_proof(CONTEXT, &public(secret), &ciphertext, &proof).unwrap_err(),
+            VotingError::InvalidProof.into()
+        );
+    }
+
+    /// Election public key and verification keys of `trustees` shares of p(x) = 42 + 5x + 3x².
+    fn shared_keys(trustees: u64) -> ([u8; 32], Vec<[u8; 32]>) {
+        let p = |x: u64| Scalar::from(42 + 5 * x + 3 * x * x);
+        (public(p(0)), (1..=trustees).map(|x| public(p(x))).collect())
+    }
+
+    #[test]
+    fn verify_threshold_keys_accepts_shares_of_the_election_key() {
+        let (public_key, verification_keys) = shared_keys(5);
+
+        for trustees in 3..=5 {
+            assert!(verify_threshold_keys(&public_key, &verification_keys[..trustees], 3).is_ok());
+        }
+        // a single trustee holds the election secret key
+        assert!(verify_threshold_keys(&public_key, &[public_key, public_key], 1).is_ok());
+    }
+
+    #[test]
+    fn verify_threshold_keys_rejects_another_election_key() {
+        let (_, verification_keys) = shared_keys(3);
+
+        assert_eq!(
+            verify_threshold_keys(&public(Scalar::from(43u64)), &verification_keys, 3).unwrap_err(),
+            VotingError::InvalidEncryptionConfig.into()
+        );
+        // below the polynomial degree, shares do not combine into the secret key
+        assert_eq!(
+            verify_threshold_keys(&shared_keys(2).0, &verification_keys[..2], 2).unwrap_err(),
+            VotingError::InvalidEncryptionConfig.into()
+        );
+    }
+
+    #[test]
+    fn verify_threshold_keys_rejects_inconsistent_trustee() {
+        let (public_key, mut verification_keys) = shared_keys(5);
+        verification_keys[4] = public(Scalar::from(7u64));
+
+        assert_eq!(
+            verify_threshold_keys(&public_key, &verification_keys, 3).unwrap_err(),
+            VotingError::InvalidEncryptionConfig.into()
+        );
+    }
+}
@@ -26,4 +26,186 @@ pub enum VotingError {
     InvalidProposalId,
     #[msg("Bad proposals accounts count")]
     BadProposalAccountsCount,
+    #[msg("Forbidden as non protocol authority")]
+    ForbiddenAsNonAuthority,
+    #[msg("Unexpected protocol authority account")]
+    UnexpectedAuthority,
+    #[msg("Invalid global configuration")]
+    InvalidGlobalConfig,
+    #[msg("Program is paused")]
+    ProgramPaused,
+    #[msg("Session name is too long")]
+    SessionNameTooLong,
+    #[msg("Session description is too long")]
+    SessionDescriptionTooLong,
+    #[msg("Session name can not be empty")]
+    EmptySessionName,
+    #[msg("Proposal description is too long")]
+    ProposalDescriptionTooLong,
+    #[msg("Proposal description can not be empty")]
+    EmptyProposalDescription,
+    #[msg("Metadata URI is too long")]
+    MetadataUriTooLong,
+    #[msg("Metadata hash requires a metadata URI")]
+    MissingMetadataUri,
+    #[msg("Question title can not be empty")]
+    EmptyQuestionTitle,
+    #[msg("Question title is too long")]
+    QuestionTitleTooLong,
+    #[msg("Maximum number of questions reached")]
+    MaxQuestionsReached,
+    #[msg("Invalid question")]
+    InvalidQuestionId,
+    #[msg("Invalid voting mode")]
+    InvalidVotingMode,
+    #[msg("Unexpected voting mode")]
+    UnexpectedVotingMode,
+    #[msg("Score exceeds the session maximum score")]
+    InvalidScore,
+    #[msg("Invalid rank")]
+    InvalidRank,
+    #[msg("Invalid pairwise preferences account")]
+    InvalidPairwiseAccount,
+    #[msg("Voter already voted for this proposal")]
+    ProposalAlreadyVoted,
+    #[msg("Votes cost exceeds the voter credits or points")]
+    InsufficientCredits,
+    #[msg("Voter weight must be positive")]
+    InvalidVoterWeight,
+    #[msg("Invalid lock duration")]
+    InvalidLockDuration,
+    #[msg("Tokens are still locked")]
+    TokensStillLocked,
+    #[msg("Voter snapshot was taken after voting opened")]
+    SnapshotTooLate,
+    #[msg("Invalid signed ballot")]
+    InvalidSignedBallot,
+    #[msg("Invalid signed ballot nonce")]
+    InvalidBallotNonce,
+    #[msg("Bad ballot accounts count")]
+    BadBallotAccountsCount,
+    #[msg("Signed ballot is relayed twice in the batch")]
+    DuplicateSignedBallot,
+    #[msg("No voting power")]
+    NoVotingPower,
+    #[msg("Maximum number of voters reached")]
+    MaxVotersReached,
+    #[msg("Maximum number of proposals reached")]
+    MaxProposalsReached,
+    #[msg("Maximum number of proposals per voter reached")]
+    MaxProposalsPerVoterReached,
+    #[msg("Invalid proposals configuration")]
+    InvalidProposalsConfig,
+    #[msg("Invalid deposit mint")]
+    InvalidDepositMint,
+    #[msg("Missing deposit accounts")]
+    MissingDepositAccounts,
+    #[msg("Deposit already settled")]
+    DepositAlreadySettled,
+    #[msg("Invalid execution configuration")]
+    InvalidExecutionConfig,
+    #[msg("Proposal transaction is empty")]
+    EmptyProposalTransaction,
+    #[msg("Proposal transaction can not invoke the voting program")]
+    ForbiddenProposalInstruction,
+    #[msg("Proposal is not the single winning proposal")]
+    ProposalNotWinning,
+    #[msg("Execution timelock not elapsed")]
+    TimelockNotElapsed,
+    #[msg("Forbidden as non veto council")]
+    ForbiddenAsNonVetoCouncil,
+    #[msg("Veto window is closed")]
+    VetoWindowClosed,
+    #[msg("Result is not released")]
+    ResultNotReleased,
+    #[msg("Result already released")]
+    ResultAlreadyReleased,
+    #[msg("Organization name is too long")]
+    OrganizationNameTooLong,
+    #[msg("Forbidden as non organization administrator")]
+    ForbiddenAsNonOrganizationAdmin,
+    #[msg("Organization administrator already registered")]
+    OrganizationAdminAlreadyRegistered,
+    #[msg("Unexpected organization administrator")]
+    UnexpectedOrganizationAdmin,
+    #[msg("Maximum number of organization administrators reached")]
+    MaxOrganizationAdminsReached,
+    #[msg("Organization must keep at least one administrator")]
+    LastOrganizationAdmin,
+    #[msg("Member already registered")]
+    MemberAlreadyRegistered,
+    #[msg("Member not registered")]
+    MemberNotRegistered,
+    #[msg("Session does not belong to the member organization")]
+    NotOrganizationSession,
+    #[msg("Organization session voters are organization members")]
+    OrganizationSessionVoter,
+    #[msg("Proposal already executed")]
+    ProposalAlreadyExecuted,
+    #[msg("Missing account for proposal instruction")]
+    MissingInstructionAccount,
+    #[msg("Invalid amount")]
+    InvalidAmount,
+    #[msg("Missing token accounts")]
+    MissingTokenAccounts,
+    #[msg("Invalid mint")]
+    InvalidMint,
+    #[msg("Proposal has no treasury transfer")]
+    NoTreasuryTransfer,
+    #[msg("Treasury transfer already requested")]
+    TreasuryTransferAlreadyRequested,
+    #[msg("Insufficient treasury funds")]
+    InsufficientTreasuryFunds,
+    #[msg("Invalid encryption configuration")]
+    InvalidEncryptionConfig,
+    #[msg("Invalid ciphertext")]
+    InvalidCiphertext,
+    #[msg("Invalid proof")]
+    InvalidProof,
+    #[msg("Encrypted ballot does not hold a ciphertext per proposal")]
+    EncryptedBallotIncomplete,
+    #[msg("Only session trustees can post decryption shares")]
+    ForbiddenAsNonTrustee,
+    #[msg("Decryption share already posted")]
+    DecryptionShareAlreadyPosted,
+    #[msg("Not enough decryption shares")]
+    NotEnoughDecryptionShares,
+    #[msg("Invalid decryption")]
+    InvalidDecryption,
+    #[msg("Proposal votes already revealed")]
+    VotesAlreadyRevealed,
+    #[msg("Proposal votes not revealed")]
+    VotesNotRevealed,
+    #[msg("Invalid identity commitment")]
+    InvalidIdentityCommitment,
+    #[msg("Identity commitment already registered")]
+    IdentityAlreadyRegistered,
+    #[msg("Identity tree is full")]
+    IdentityTreeFull,
+    #[msg("Invalid membership proof")]
+    InvalidMembershipProof,
+    #[msg("Receipt accumulator is full")]
+    ReceiptAccumulatorFull,
+    #[msg("Invalid claim duration")]
+    InvalidClaimDuration,
+    #[msg("Voter did not vote")]
+    VoterDidNotVote,
+    #[msg("Reward already claimed")]
+    RewardAlreadyClaimed,
+    #[msg("Claim period ended")]
+    ClaimPeriodEnded,
+    #[msg("Claim period not ended")]
+    ClaimPeriodNotEnded,
+    #[msg("Insufficient reward pool funds")]
+    InsufficientRewardFunds,
+    #[msg("Only the reward pool funder can reclaim it")]
+    ForbiddenAsNonFunder,
+    #[msg("Badges already enabled")]
+    BadgesAlreadyEnabled,
+    #[msg("Badge already minted")]
+    BadgeAlreadyMinted,
+    #[msg("Voters weight overflow")]
+    VotersWeightOverflow,
+    #[msg("Verifying key changed since anonymous voting was configured")]
+    VerifyingKeyChanged,
 }
@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::VotingError,
+    instructions::register_proposal::*,
+    state::{global::GlobalAccount, proposal::ProposalAccount, question::*, voting_session::*},
+};
+
+#[derive(Accounts)]
+pub struct AddQuestionContextData<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut)]
+    pub session_account: Account<'info, SessionAccount>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + QuestionAccount::INIT_SPACE,
+        seeds = [
+            QuestionAccount::SEED_PREFIX.as_ref(),
+            &session_account.session_id.to_le_bytes(),
+            &[session_account.questions_count],
+        ],
+        bump
+    )]
+    pub question_account: Account<'info, QuestionAccount>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + ProposalAccount::INIT_SPACE,
+        seeds = [
+            ProposalAccount::SEED_PREFIX.as_ref(),
+            &session_account.session_id.to_le_bytes(),
+            &[session_account.proposals_count],
+        ],
+        bump
+    )]
+    pub blank_proposal_account: Account<'info, ProposalAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn add_question(ctx: Context<AddQuestionContextData>, title: String) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
+    let session_account = &mut ctx.accounts.session_account;
+    let question_account = &mut ctx.accounts.question_account;
+    let blank_proposal_account = &mut ctx.accounts.blank_proposal_account;
+
+    require!(
+        session_account.admin.key() == ctx.accounts.admin.key(),
+        VotingError::ForbiddenAsNonAdmin
+    );
+    require!(
+        session_account.status == SessionWorkflowStatus::ProposalsRegistrationStarted,
+        VotingError::UnexpectedSessionStatus
+    );
+    // other voting modes rank the session proposals as a whole
+    require!(
+        session_account.voting_mode == VotingMode::Plurality
+            && session_account.encryption.is_none()
+            && !session_account.anonymous,
+        VotingError::UnexpectedVotingMode
+    );
+    require!(!title.is_empty(), VotingError::EmptyQuestionTitle);
+    require!(
+        title.len() <= MAX_QUESTION_TITLE_LEN,
+        VotingError::QuestionTitleTooLong
+    );
+    require!(
+        (session_account.questions_count as usize) < MAX_QUESTIONS,
+        VotingError::MaxQuestionsReached
+    );
+    require!(
+        session_account.proposals_count < u8::MAX,
+        VotingError::MaxProposalsReached
+    );
+
+    // each question gets its own blank proposal
+    let question_id = session_account.questions_count;
+    let blank_proposal_id = session_account.proposals_count;
+
+    register_internal_proposal(
+        blank_proposal_account,
+        session_account.session_id,
+        session_account.admin,
+        blank_proposal_id,
+        "blank".to_string(),
+        String::new(),
+        [0; 32],
+    );
+    blank_proposal_account.question_id = question_id;
+
+    question_account.session_id = session_account.session_id;
+    question_account.question_id = question_id;
+    question_account.title = title.clone();
+    question_account.blank_proposal_id = blank_proposal_id;
+    question_account.result = QuestionResult {
+        question_id,
+        total_votes: 0,
+        blank_votes: 0,
+        abstention: 0,
+        winning_proposals: Vec::new(),
+        tied_proposals: Vec::new(),
+    };
+
+    session_account.questions_count += 1;
+    session_account.proposals_count += 1;
+
+    emit!(QuestionAdded {
+        session_id: session_account.session_id,
+        question_id,
+        title,
+        blank_proposal_id,
+    });
+
+    Ok(())
+}
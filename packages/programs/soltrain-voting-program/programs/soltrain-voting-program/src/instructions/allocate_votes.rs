@@ -0,0 +1,158 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::VotingError,
+    state::{
+        ballot::*, global::GlobalAccount, proposal::*, receipt::*, voter::*, voting_session::*,
+    },
+};
+
+#[derive(Accounts)]
+pub struct AllocateVotesContextData<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut)]
+    pub session_account: Account<'info, SessionAccount>,
+
+    #[account(mut)]
+    pub voter_account: Account<'info, VoterAccount>,
+
+    // allocations of many calls add up, up to every session proposal
+    #[account(
+        init_if_needed,
+        payer = voter,
+        space = BallotAccount::space(session_account.proposals_count as usize),
+        seeds = [
+            BallotAccount::SEED_PREFIX.as_ref(),
+            &session_account.session_id.to_le_bytes(),
+            voter.key().as_ref()
+        ],
+        bump
+    )]
+    pub ballot_account: Account<'info, BallotAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            ReceiptAccumulatorAccount::SEED_PREFIX.as_ref(),
+            &session_account.session_id.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub receipts_account: Box<Account<'info, ReceiptAccumulatorAccount>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn allocate_votes<'info>(
+    ctx: Context<'_, '_, 'info, 'info, AllocateVotesContextData<'info>>,
+    allocations: Vec<BallotEntry>,
+) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
+    let voter = &ctx.accounts.voter;
+    let session_account = &mut ctx.accounts.session_account;
+    let voter_account = &mut ctx.accounts.voter_account;
+
+    require!(
+        session_account.status == SessionWorkflowStatus::VotingSessionStarted,
+        VotingError::UnexpectedSessionStatus
+    );
+    require!(
+        session_account.admin.key() != voter.key(),
+        VotingError::AdminForbiddenAsVoter
+    );
+    require!(
+        voter_account.voter.key() == voter.key()
+            && voter_account.session_id == session_account.session_id,
+        VotingError::UnexpectedVoter
+    );
+    session_account.require_voting_power(voter_account)?;
+
+    // n votes on a proposal cost n² credits in quadratic mode, n points in cumulative mode
+    let (cost, credits): (u64, u64) = match session_account.voting_mode {
+        VotingMode::Quadratic { credits } => (
+            allocations
+                .iter()
+                .map(|entry| entry.value as u64 * entry.value as u64)
+                .sum(),
+            voter_account.credits.unwrap_or(credits) as u64,
+        ),
+        VotingMode::Cumulative { points } => (
+            allocations.iter().map(|entry| entry.value as u64).sum(),
+            points.unwrap_or(session_account.seats) as u64,
+        ),
+        _ => return err!(VotingError::UnexpectedVotingMode),
+    };
+    // the voter spends down the remaining credits across allocations
+    let credits_spent = voter_account.credits_spent as u64 + cost;
+    require!(credits_spent <= credits, VotingError::InsufficientCredits);
+
+    // remaining accounts are the voted proposals, in the ballot order
+    require!(
+        ctx.remaining_accounts.len() == allocations.len(),
+        VotingError::BadProposalAccountsCount
+    );
+
+    let ballot_account = &mut ctx.accounts.ballot_account;
+    let mut previous_proposal_id = 0;
+    for (entry, proposal_info) in allocations.iter().zip(ctx.remaining_accounts.iter()) {
+        // strictly increasing ids, not allocated by a previous call, so that a proposal can not be voted twice
+        require!(
+            entry.proposal_id > previous_proposal_id
+                && ballot_account
+                    .entries
+                    .iter()
+                    .all(|allocated| allocated.proposal_id != entry.proposal_id),
+            VotingError::InvalidProposalId
+        );
+        require!(proposal_info.is_writable, VotingError::InvalidProposalId);
+
+        let mut proposal: Account<ProposalAccount> = Account::try_from(proposal_info)?;
+        require!(
+            proposal.session_id == session_account.session_id
+                && proposal.proposal_id == entry.proposal_id,
+            VotingError::InvalidProposalId
+        );
+
+        proposal.vote_count += entry.value as u32 * voter_account.weight;
+        if entry.value > 0 {
+            proposal.score_count += voter_account.weight;
+        }
+        proposal.exit(&crate::ID)?;
+
+        previous_proposal_id = entry.proposal_id;
+    }
+
+    // the first allocation casts the voter ballot
+    if !voter_account.has_voted {
+        voter_account.has_voted = true;
+        session_account.ballots_count += 1;
+        session_account.ballots_weight += voter_account.weight;
+    }
+    voter_account.credits_spent = credits_spent as u32;
+
+    ballot_account.session_id = session_account.session_id;
+    ballot_account.voter = voter.key();
+    ballot_account.entries.extend(allocations.iter().cloned());
+
+    emit!(BallotCast {
+        session_id: session_account.session_id,
+        voter: voter.key(),
+        entries: allocations.clone(),
+    });
+
+    ctx.accounts
+        .receipts_account
+        .add_receipt(voter.key(), entries_ballot(&allocations))
+}
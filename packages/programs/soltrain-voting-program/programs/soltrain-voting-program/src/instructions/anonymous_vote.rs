@@ -0,0 +1,276 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::VotingError,
+    semaphore::*,
+    state::{
+        global::GlobalAccount, identity::*, proposal::*, receipt::*, voter::*, voting_session::*,
+    },
+};
+
+#[derive(Accounts)]
+pub struct ConfigureAnonymousVotingContextData<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut)]
+    pub session_account: Account<'info, SessionAccount>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + IdentityTreeAccount::INIT_SPACE,
+        seeds = [
+            IdentityTreeAccount::SEED_PREFIX.as_ref(),
+            &session_account.session_id.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub identity_tree_account: Box<Account<'info, IdentityTreeAccount>>,
+
+    #[account(seeds = [VerifyingKeyAccount::SEED.as_ref()], bump)]
+    pub verifying_key_account: Box<Account<'info, VerifyingKeyAccount>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterIdentityCommitmentContextData<'info> {
+    pub voter: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    pub session_account: Account<'info, SessionAccount>,
+
+    #[account(mut)]
+    pub voter_account: Account<'info, VoterAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            IdentityTreeAccount::SEED_PREFIX.as_ref(),
+            &session_account.session_id.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub identity_tree_account: Box<Account<'info, IdentityTreeAccount>>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u8, nullifier_hash: [u8; 32])]
+pub struct CastAnonymousVoteContextData<'info> {
+    // anyone relays the vote, so that the voter wallet is not linked to it
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut)]
+    pub session_account: Account<'info, SessionAccount>,
+
+    #[account(mut)]
+    pub proposal_account: Account<'info, ProposalAccount>,
+
+    #[account(
+        seeds = [
+            IdentityTreeAccount::SEED_PREFIX.as_ref(),
+            &session_account.session_id.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub identity_tree_account: Box<Account<'info, IdentityTreeAccount>>,
+
+    #[account(seeds = [VerifyingKeyAccount::SEED.as_ref()], bump)]
+    pub verifying_key_account: Box<Account<'info, VerifyingKeyAccount>>,
+
+    // creation fails if the nullifier was already used, preventing double voting
+    #[account(
+        init,
+        payer = relayer,
+        space = 8 + NullifierAccount::INIT_SPACE,
+        seeds = [
+            NullifierAccount::SEED_PREFIX.as_ref(),
+            &session_account.session_id.to_le_bytes(),
+            nullifier_hash.as_ref()
+        ],
+        bump
+    )]
+    pub nullifier_account: Account<'info, NullifierAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            ReceiptAccumulatorAccount::SEED_PREFIX.as_ref(),
+            &session_account.session_id.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub receipts_account: Box<Account<'info, ReceiptAccumulatorAccount>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn configure_anonymous_voting(ctx: Context<ConfigureAnonymousVotingContextData>) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
+    let session_account = &mut ctx.accounts.session_account;
+
+    require!(
+        session_account.admin.key() == ctx.accounts.admin.key(),
+        VotingError::ForbiddenAsNonAdmin
+    );
+    require!(
+        session_account.status == SessionWorkflowStatus::RegisteringVoters,
+        VotingError::UnexpectedSessionStatus
+    );
+    // a nullifier allows a single plurality choice on the main question
+    require!(
+        session_account.voting_mode == VotingMode::Plurality
+            && session_account.seats == 1
+            && session_account.questions_count == 1
+            && session_account.encryption.is_none(),
+        VotingError::UnexpectedVotingMode
+    );
+
+    let identity_tree_account = &mut ctx.accounts.identity_tree_account;
+    identity_tree_account.initialize(session_account.session_id)?;
+    // votes are only verified with this key, a key update stops the session anonymous votes
+    identity_tree_account.verifying_key_hash = ctx.accounts.verifying_key_account.hash();
+    session_account.anonymous = true;
+
+    emit!(AnonymousVotingConfigured {
+        session_id: session_account.session_id,
+        root: identity_tree_account.root,
+    });
+
+    Ok(())
+}
+
+pub fn register_identity_commitment(
+    ctx: Context<RegisterIdentityCommitmentContextData>,
+    identity_commitment: [u8; 32],
+) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
+    let session_account = &ctx.accounts.session_account;
+    let voter_account = &mut ctx.accounts.voter_account;
+
+    // the tree root is frozen once voting starts
+    require!(
+        matches!(
+            session_account.status,
+            SessionWorkflowStatus::RegisteringVoters
+                | SessionWorkflowStatus::ProposalsRegistrationStarted
+                | SessionWorkflowStatus::ProposalsRegistrationEnded
+        ),
+        VotingError::UnexpectedSessionStatus
+    );
+    require!(
+        voter_account.voter.key() == ctx.accounts.voter.key()
+            && voter_account.session_id == session_account.session_id,
+        VotingError::UnexpectedVoter
+    );
+    require!(
+        voter_account.identity_commitment == [0u8; 32],
+        VotingError::IdentityAlreadyRegistered
+    );
+    // every anonymous vote counts as one
+    require!(voter_account.weight == 1, VotingError::InvalidVoterWeight);
+    require!(
+        identity_commitment != [0u8; 32] && is_field_element(&identity_commitment),
+        VotingError::InvalidIdentityCommitment
+    );
+
+    let identity_tree_account = &mut ctx.accounts.identity_tree_account;
+    let leaf_index = identity_tree_account.insert(identity_commitment)?;
+    voter_account.identity_commitment = identity_commitment;
+
+    emit!(IdentityCommitmentAdded {
+        session_id: session_account.session_id,
+        identity_commitment,
+        leaf_index,
+        root: identity_tree_account.root,
+    });
+
+    Ok(())
+}
+
+pub fn cast_anonymous_vote(
+    ctx: Context<CastAnonymousVoteContextData>,
+    proposal_id: u8,
+    nullifier_hash: [u8; 32],
+    proof: Groth16Proof,
+) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
+    let session_account = &mut ctx.accounts.session_account;
+    let proposal_account = &mut ctx.accounts.proposal_account;
+
+    require!(
+        session_account.status == SessionWorkflowStatus::VotingSessionStarted,
+        VotingError::UnexpectedSessionStatus
+    );
+    require!(session_account.anonymous, VotingError::UnexpectedVotingMode);
+    require!(
+        proposal_account.session_id == session_account.session_id
+            && proposal_account.proposal_id == proposal_id,
+        VotingError::InvalidProposalId
+    );
+
+    require!(
+        ctx.accounts.verifying_key_account.hash()
+            == ctx.accounts.identity_tree_account.verifying_key_hash,
+        VotingError::VerifyingKeyChanged
+    );
+
+    // Semaphore public inputs, the session being the external nullifier and the choice the signal
+    let public_inputs = [
+        ctx.accounts.identity_tree_account.root,
+        nullifier_hash,
+        hash_to_field(proposal_id as u64),
+        hash_to_field(session_account.session_id),
+    ];
+    verify_groth16(&ctx.accounts.verifying_key_account, &proof, &public_inputs)?;
+
+    let nullifier_account = &mut ctx.accounts.nullifier_account;
+    nullifier_account.session_id = session_account.session_id;
+    nullifier_account.nullifier_hash = nullifier_hash;
+
+    proposal_account.vote_count += 1;
+    session_account.ballots_count += 1;
+    session_account.ballots_weight += 1;
+
+    emit!(AnonymousVoted {
+        session_id: session_account.session_id,
+        proposal_id,
+        nullifier_hash,
+    });
+
+    // the voter is only known by its nullifier hash
+    ctx.accounts
+        .receipts_account
+        .add_receipt(Pubkey::new_from_array(nullifier_hash), vec![proposal_id])
+}
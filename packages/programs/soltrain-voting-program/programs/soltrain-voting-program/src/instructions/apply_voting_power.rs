@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::{
+    errors::VotingError,
+    state::{
+        global::GlobalAccount, lock::VotingPowerApplied, snapshot::*, voter::*, voting_session::*,
+    },
+};
+
+#[derive(Accounts)]
+pub struct ApplyVotingPowerContextData<'info> {
+    pub voter: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut)]
+    pub session_account: Account<'info, SessionAccount>,
+
+    #[account(mut)]
+    pub voter_account: Account<'info, VoterAccount>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        seeds = [
+            VoterSnapshotAccount::SEED_PREFIX.as_ref(),
+            &session_account.session_id.to_le_bytes(),
+            voter.key().as_ref()
+        ],
+        bump
+    )]
+    pub snapshot_account: Account<'info, VoterSnapshotAccount>,
+}
+
+pub fn apply_voting_power(ctx: Context<ApplyVotingPowerContextData>) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
+    let session_account = &mut ctx.accounts.session_account;
+    let voter_account = &mut ctx.accounts.voter_account;
+    let snapshot_account = &ctx.accounts.snapshot_account;
+
+    require!(
+        session_account.lock_mint == Some(ctx.accounts.mint.key()),
+        VotingError::InvalidMint
+    );
+    require!(
+        session_account.status == SessionWorkflowStatus::VotingSessionStarted,
+        VotingError::UnexpectedSessionStatus
+    );
+    require!(
+        voter_account.voter.key() == ctx.accounts.voter.key()
+            && voter_account.session_id == session_account.session_id,
+        VotingError::UnexpectedVoter
+    );
+    require!(!voter_account.has_voted, VotingError::VoterAlreadyVoted);
+
+    // weight is the voting power in whole tokens, as of voting opening
+    let weight = snapshot_account.weight(
+        session_account.snapshot_slot,
+        session_account.voting_started_at,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    session_account.voters_weight = (session_account.voters_weight - voter_account.weight)
+        .checked_add(weight)
+        .ok_or(VotingError::VotersWeightOverflow)?;
+    voter_account.weight = weight;
+    voter_account.power_applied = true;
+
+    emit!(VotingPowerApplied {
+        session_id: session_account.session_id,
+        voter: voter_account.voter,
+        weight,
+    });
+
+    Ok(())
+}
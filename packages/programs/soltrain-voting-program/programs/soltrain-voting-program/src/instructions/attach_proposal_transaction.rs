@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::VotingError,
+    state::{global::GlobalAccount, proposal::*, proposal_transaction::*, voting_session::*},
+};
+
+#[derive(Accounts)]
+#[instruction(instructions: Vec<ProposalInstruction>)]
+pub struct AttachProposalTransactionContextData<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    pub session_account: Account<'info, SessionAccount>,
+
+    pub proposal_account: Account<'info, ProposalAccount>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + ProposalTransactionAccount::space(&instructions),
+        seeds = [
+            ProposalTransactionAccount::SEED_PREFIX.as_ref(),
+            &session_account.session_id.to_le_bytes(),
+            &[proposal_account.proposal_id],
+        ],
+        bump
+    )]
+    pub proposal_transaction_account: Account<'info, ProposalTransactionAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn attach_proposal_transaction(
+    ctx: Context<AttachProposalTransactionContextData>,
+    instructions: Vec<ProposalInstruction>,
+) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
+    let session_account = &ctx.accounts.session_account;
+    let proposal_account = &ctx.accounts.proposal_account;
+    let proposal_transaction_account = &mut ctx.accounts.proposal_transaction_account;
+
+    require!(
+        session_account.status == SessionWorkflowStatus::ProposalsRegistrationStarted,
+        VotingError::UnexpectedSessionStatus
+    );
+    require!(
+        proposal_account.session_id == session_account.session_id,
+        VotingError::InvalidProposalId
+    );
+    require!(
+        proposal_account.proposer == ctx.accounts.proposer.key(),
+        VotingError::UnexpectedVoter
+    );
+    require!(
+        !instructions.is_empty() && instructions.len() <= u8::MAX as usize,
+        VotingError::EmptyProposalTransaction
+    );
+    // a proposal can not re-enter the voting program with the session executor signature
+    require!(
+        instructions.iter().all(|ix| ix.program_id != crate::ID),
+        VotingError::ForbiddenProposalInstruction
+    );
+
+    proposal_transaction_account.session_id = session_account.session_id;
+    proposal_transaction_account.proposal_id = proposal_account.proposal_id;
+    proposal_transaction_account.executed = false;
+    proposal_transaction_account.instructions = instructions;
+
+    emit!(ProposalTransactionAttached {
+        session_id: session_account.session_id,
+        proposal_id: proposal_account.proposal_id,
+        instructions_count: proposal_transaction_account.instructions.len() as u8,
+    });
+
+    Ok(())
+}
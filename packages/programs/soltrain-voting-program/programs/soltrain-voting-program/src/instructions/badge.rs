@@ -0,0 +1,332 @@
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_2022::{
+        self,
+        spl_token_2022::{extension::ExtensionType, state::Mint as MintState},
+        InitializeMint2, MintTo, Token2022,
+    },
+    token_2022_extensions::{
+        metadata_pointer_initialize, non_transferable_mint_initialize,
+        spl_pod::optional_keys::OptionalNonZeroPubkey,
+        spl_token_metadata_interface::state::{Field, TokenMetadata},
+        token_metadata_initialize, token_metadata_update_field, MetadataPointerInitialize,
+        NonTransferableMintInitialize, TokenMetadataInitialize, TokenMetadataUpdateField,
+    },
+    token_interface::{Mint, TokenAccount},
+};
+
+use crate::{
+    errors::VotingError,
+    state::{badge::*, global::GlobalAccount, voter::VoterAccount, voting_session::*},
+};
+
+#[derive(Accounts)]
+pub struct EnableBadgesContextData<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut)]
+    pub session_account: Account<'info, SessionAccount>,
+
+    /// CHECK: badge mint, created with its Token-2022 extensions by the instruction
+    #[account(
+        mut,
+        seeds = [
+            BADGE_MINT_SEED_PREFIX.as_ref(),
+            &session_account.session_id.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub badge_mint: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MintBadgeContextData<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    pub session_account: Account<'info, SessionAccount>,
+
+    #[account(mut)]
+    pub voter_account: Account<'info, VoterAccount>,
+
+    #[account(mut)]
+    pub badge_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = voter,
+        associated_token::mint = badge_mint,
+        associated_token::authority = voter,
+        associated_token::token_program = token_program,
+    )]
+    pub voter_badge_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token2022>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn enable_badges(ctx: Context<EnableBadgesContextData>) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
+    let session_account = &mut ctx.accounts.session_account;
+    let badge_mint = &ctx.accounts.badge_mint;
+    let token_program = ctx.accounts.token_program.to_account_info();
+
+    require!(
+        session_account.admin.key() == ctx.accounts.admin.key(),
+        VotingError::ForbiddenAsNonAdmin
+    );
+    require!(
+        session_account.badge_mint.is_none(),
+        VotingError::BadgesAlreadyEnabled
+    );
+
+    let session_id = session_account.session_id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        BADGE_MINT_SEED_PREFIX.as_ref(),
+        &session_id,
+        &[ctx.bumps.badge_mint],
+    ]];
+
+    // metadata is stored in the mint itself, referencing the session
+    let metadata = TokenMetadata {
+        update_authority: OptionalNonZeroPubkey(badge_mint.key()),
+        mint: badge_mint.key(),
+        name: session_account.name.clone(),
+        symbol: BADGE_SYMBOL.to_string(),
+        uri: session_account.metadata_uri.clone(),
+        additional_metadata: vec![(
+            BADGE_SESSION_ID_KEY.to_string(),
+            session_account.session_id.to_string(),
+        )],
+    };
+    let space = ExtensionType::try_calculate_account_len::<MintState>(&[
+        ExtensionType::NonTransferable,
+        ExtensionType::MetadataPointer,
+    ])?;
+    // metadata is appended by the token program, the mint must already hold its rent
+    let lamports = Rent::get()?.minimum_balance(space + metadata.tlv_size_of()?);
+
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let current_lamports = badge_mint.lamports();
+    if current_lamports == 0 {
+        system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program,
+                system_program::CreateAccount {
+                    from: ctx.accounts.admin.to_account_info(),
+                    to: badge_mint.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            lamports,
+            space as u64,
+            &token_program.key(),
+        )?;
+    } else {
+        // the mint address is predictable, lamports sent to it beforehand must not block badges
+        let required_lamports = lamports.saturating_sub(current_lamports);
+        if required_lamports > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    system_program::Transfer {
+                        from: ctx.accounts.admin.to_account_info(),
+                        to: badge_mint.to_account_info(),
+                    },
+                ),
+                required_lamports,
+            )?;
+        }
+        system_program::allocate(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::Allocate {
+                    account_to_allocate: badge_mint.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            space as u64,
+        )?;
+        system_program::assign(
+            CpiContext::new_with_signer(
+                system_program,
+                system_program::Assign {
+                    account_to_assign: badge_mint.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            &token_program.key(),
+        )?;
+    }
+
+    // badges can not be transferred, they stay with the voter
+    non_transferable_mint_initialize(CpiContext::new(
+        token_program.clone(),
+        NonTransferableMintInitialize {
+            token_program_id: token_program.clone(),
+            mint: badge_mint.to_account_info(),
+        },
+    ))?;
+    metadata_pointer_initialize(
+        CpiContext::new(
+            token_program.clone(),
+            MetadataPointerInitialize {
+                token_program_id: token_program.clone(),
+                mint: badge_mint.to_account_info(),
+            },
+        ),
+        Some(badge_mint.key()),
+        Some(badge_mint.key()),
+    )?;
+    token_2022::initialize_mint2(
+        CpiContext::new(
+            token_program.clone(),
+            InitializeMint2 {
+                mint: badge_mint.to_account_info(),
+            },
+        ),
+        0,
+        &badge_mint.key(),
+        Some(&badge_mint.key()),
+    )?;
+
+    token_metadata_initialize(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            TokenMetadataInitialize {
+                token_program_id: token_program.clone(),
+                metadata: badge_mint.to_account_info(),
+                update_authority: badge_mint.to_account_info(),
+                mint_authority: badge_mint.to_account_info(),
+                mint: badge_mint.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        metadata.name,
+        metadata.symbol,
+        metadata.uri,
+    )?;
+    token_metadata_update_field(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            TokenMetadataUpdateField {
+                token_program_id: token_program.clone(),
+                metadata: badge_mint.to_account_info(),
+                update_authority: badge_mint.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        Field::Key(BADGE_SESSION_ID_KEY.to_string()),
+        session_account.session_id.to_string(),
+    )?;
+
+    session_account.badge_mint = Some(badge_mint.key());
+    session_account.badge_mint_bump = ctx.bumps.badge_mint;
+
+    emit!(BadgesEnabled {
+        session_id: session_account.session_id,
+        mint: badge_mint.key(),
+    });
+
+    Ok(())
+}
+
+pub fn mint_badge(ctx: Context<MintBadgeContextData>) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+    require!(
+        ctx.accounts.voter_account.voter.key() == ctx.accounts.voter.key(),
+        VotingError::UnexpectedVoter
+    );
+    require!(
+        !ctx.accounts.voter_account.badge_minted,
+        VotingError::BadgeAlreadyMinted
+    );
+
+    mint_badge_to(
+        &ctx.accounts.session_account,
+        &mut ctx.accounts.voter_account,
+        &ctx.accounts.badge_mint,
+        &ctx.accounts.voter_badge_account,
+        &ctx.accounts.token_program,
+    )
+}
+
+/// Mints the session badge to a voter who voted, voters already holding it being skipped.
+pub fn mint_badge_to<'info>(
+    session_account: &SessionAccount,
+    voter_account: &mut VoterAccount,
+    badge_mint: &InterfaceAccount<'info, Mint>,
+    voter_badge_account: &InterfaceAccount<'info, TokenAccount>,
+    token_program: &Program<'info, Token2022>,
+) -> Result<()> {
+    require!(
+        session_account.badge_mint == Some(badge_mint.key()),
+        VotingError::InvalidMint
+    );
+    require!(
+        voter_account.session_id == session_account.session_id,
+        VotingError::UnexpectedVoter
+    );
+    require!(voter_account.has_voted, VotingError::VoterDidNotVote);
+    // voters voting on many questions or seats get a single badge
+    if voter_account.badge_minted {
+        return Ok(());
+    }
+
+    let session_id = session_account.session_id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        BADGE_MINT_SEED_PREFIX.as_ref(),
+        &session_id,
+        &[session_account.badge_mint_bump],
+    ]];
+    token_2022::mint_to(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            MintTo {
+                mint: badge_mint.to_account_info(),
+                to: voter_badge_account.to_account_info(),
+                authority: badge_mint.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        1,
+    )?;
+
+    voter_account.badge_minted = true;
+
+    emit!(BadgeMinted {
+        session_id: session_account.session_id,
+        voter: voter_account.voter,
+        mint: badge_mint.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    elgamal::{verify_threshold_keys, IDENTITY},
+    errors::VotingError,
+    state::{encryption::*, global::GlobalAccount, voting_session::*},
+};
+
+#[derive(Accounts)]
+pub struct ConfigureEncryptionContextData<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut)]
+    pub session_account: Account<'info, SessionAccount>,
+}
+
+pub fn configure_encryption(
+    ctx: Context<ConfigureEncryptionContextData>,
+    election_public_key: [u8; 32],
+    threshold: u8,
+    trustees: Vec<Trustee>,
+) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
+    let session_account = &mut ctx.accounts.session_account;
+
+    require!(
+        session_account.admin.key() == ctx.accounts.admin.key(),
+        VotingError::ForbiddenAsNonAdmin
+    );
+    require!(
+        session_account.status == SessionWorkflowStatus::RegisteringVoters,
+        VotingError::UnexpectedSessionStatus
+    );
+    // encrypted ballots hold a single plurality choice on the main question
+    require!(
+        session_account.voting_mode == VotingMode::Plurality
+            && session_account.seats == 1
+            && session_account.questions_count == 1
+            && !session_account.anonymous,
+        VotingError::UnexpectedVotingMode
+    );
+    require!(
+        !trustees.is_empty()
+            && trustees.len() <= MAX_TRUSTEES
+            && threshold > 0
+            && threshold as usize <= trustees.len()
+            && election_public_key != IDENTITY,
+        VotingError::InvalidEncryptionConfig
+    );
+    for (index, trustee) in trustees.iter().enumerate() {
+        require!(
+            trustee.verification_key != IDENTITY
+                && !trustees[..index]
+                    .iter()
+                    .any(|other| other.authority == trustee.authority),
+            VotingError::InvalidEncryptionConfig
+        );
+    }
+    // shares of any `threshold` trustees must combine into the election secret key
+    let verification_keys: Vec<[u8; 32]> = trustees
+        .iter()
+        .map(|trustee| trustee.verification_key)
+        .collect();
+    verify_threshold_keys(&election_public_key, &verification_keys, threshold as usize)?;
+
+    let trustee_authorities = trustees.iter().map(|trustee| trustee.authority).collect();
+    session_account.encryption = Some(EncryptionConfig {
+        election_public_key,
+        threshold,
+        trustees,
+    });
+
+    emit!(EncryptionConfigured {
+        session_id: session_account.session_id,
+        election_public_key,
+        threshold,
+        trustees: trustee_authorities,
+    });
+
+    Ok(())
+}
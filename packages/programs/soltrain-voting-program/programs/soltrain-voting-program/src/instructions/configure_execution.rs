@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::VotingError,
+    state::{global::GlobalAccount, voting_session::*},
+};
+
+#[derive(Accounts)]
+pub struct ConfigureExecutionContextData<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut)]
+    pub session_account: Account<'info, SessionAccount>,
+}
+
+pub fn configure_execution(
+    ctx: Context<ConfigureExecutionContextData>,
+    execution_delay: i64,
+    veto_council: Option<Pubkey>,
+) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
+    let session_account = &mut ctx.accounts.session_account;
+
+    require!(
+        session_account.admin.key() == ctx.accounts.admin.key(),
+        VotingError::ForbiddenAsNonAdmin
+    );
+    require!(
+        session_account.status == SessionWorkflowStatus::RegisteringVoters,
+        VotingError::UnexpectedSessionStatus
+    );
+    require!(
+        (0..=MAX_EXECUTION_DELAY).contains(&execution_delay),
+        VotingError::InvalidExecutionConfig
+    );
+
+    session_account.execution_delay = execution_delay;
+    session_account.veto_council = veto_council;
+
+    emit!(ExecutionConfigured {
+        session_id: session_account.session_id,
+        execution_delay,
+        veto_council,
+    });
+
+    Ok(())
+}
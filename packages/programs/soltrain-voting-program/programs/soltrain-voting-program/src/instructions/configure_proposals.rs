@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::{
+    errors::VotingError,
+    state::{global::GlobalAccount, vault::VaultAccount, voting_session::*},
+};
+
+#[derive(Accounts)]
+pub struct ConfigureProposalsContextData<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut)]
+    pub session_account: Account<'info, SessionAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + VaultAccount::INIT_SPACE,
+        seeds = [
+            VaultAccount::SEED_PREFIX.as_ref(),
+            &session_account.session_id.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+
+    pub deposit_mint: Option<Account<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = deposit_mint,
+        associated_token::authority = vault_account,
+    )]
+    pub vault_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn configure_proposals(
+    ctx: Context<ConfigureProposalsContextData>,
+    config: ProposalsConfig,
+) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
+    let session_account = &mut ctx.accounts.session_account;
+    let vault_account = &mut ctx.accounts.vault_account;
+
+    require!(
+        session_account.admin.key() == ctx.accounts.admin.key(),
+        VotingError::ForbiddenAsNonAdmin
+    );
+    require!(
+        session_account.status == SessionWorkflowStatus::RegisteringVoters,
+        VotingError::UnexpectedSessionStatus
+    );
+    require!(
+        config.refund_threshold_bps <= 10_000 && config.max_proposals_per_voter > 0,
+        VotingError::InvalidProposalsConfig
+    );
+    if let Some(mint) = config.deposit_mint {
+        // token deposits need the vault token account to be created here
+        require!(
+            ctx.accounts.deposit_mint.as_ref().map(|m| m.key()) == Some(mint)
+                && ctx.accounts.vault_token_account.is_some(),
+            VotingError::InvalidDepositMint
+        );
+    }
+
+    vault_account.session_id = session_account.session_id;
+    vault_account.bump = ctx.bumps.vault_account;
+
+    session_account.proposals_config = config.clone();
+
+    emit!(ProposalsConfigured {
+        session_id: session_account.session_id,
+        deposit_amount: config.deposit_amount,
+        deposit_mint: config.deposit_mint,
+        refund_threshold_bps: config.refund_threshold_bps,
+        max_proposals_per_voter: config.max_proposals_per_voter,
+    });
+
+    Ok(())
+}
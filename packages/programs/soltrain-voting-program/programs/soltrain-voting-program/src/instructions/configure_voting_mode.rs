@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::VotingError,
+    state::{ballot::MAX_SCORE, global::GlobalAccount, voting_session::*},
+};
+
+#[derive(Accounts)]
+pub struct ConfigureVotingModeContextData<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut)]
+    pub session_account: Account<'info, SessionAccount>,
+}
+
+pub fn configure_voting_mode(
+    ctx: Context<ConfigureVotingModeContextData>,
+    voting_mode: VotingMode,
+    seats: u8,
+) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
+    let session_account = &mut ctx.accounts.session_account;
+
+    require!(
+        session_account.admin.key() == ctx.accounts.admin.key(),
+        VotingError::ForbiddenAsNonAdmin
+    );
+    require!(
+        session_account.status == SessionWorkflowStatus::RegisteringVoters,
+        VotingError::UnexpectedSessionStatus
+    );
+    // encryption and anonymous voting are configured for a plurality single seat session
+    require!(
+        session_account.encryption.is_none() && !session_account.anonymous,
+        VotingError::UnexpectedVotingMode
+    );
+
+    match voting_mode {
+        VotingMode::Plurality | VotingMode::Condorcet => {}
+        VotingMode::Score { max_score } => {
+            require!(
+                max_score > 0 && max_score <= MAX_SCORE,
+                VotingError::InvalidVotingMode
+            );
+        }
+        VotingMode::Quadratic { credits } => {
+            require!(credits > 0, VotingError::InvalidVotingMode);
+        }
+        VotingMode::Cumulative { points } => {
+            require!(points != Some(0), VotingError::InvalidVotingMode);
+        }
+    }
+
+    require!(
+        seats > 0 && seats as usize <= MAX_SEATS,
+        VotingError::InvalidVotingMode
+    );
+
+    session_account.voting_mode = voting_mode;
+    session_account.seats = seats;
+
+    emit!(VotingModeConfigured {
+        session_id: session_account.session_id,
+        voting_mode,
+        seats,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::VotingError,
+    state::{global::GlobalAccount, voting_session::*},
+};
+
+#[derive(Accounts)]
+pub struct ConfigureVotingPowerContextData<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut)]
+    pub session_account: Account<'info, SessionAccount>,
+}
+
+pub fn configure_voting_power(
+    ctx: Context<ConfigureVotingPowerContextData>,
+    lock_mint: Option<Pubkey>,
+) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
+    let session_account = &mut ctx.accounts.session_account;
+
+    require!(
+        session_account.admin.key() == ctx.accounts.admin.key(),
+        VotingError::ForbiddenAsNonAdmin
+    );
+    require!(
+        session_account.status == SessionWorkflowStatus::RegisteringVoters,
+        VotingError::UnexpectedSessionStatus
+    );
+
+    session_account.lock_mint = lock_mint;
+
+    emit!(VotingPowerConfigured {
+        session_id: session_account.session_id,
+        lock_mint,
+    });
+
+    Ok(())
+}
@@ -1,15 +1,27 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, system_program};
 
-use crate::state::{global::*, voting_session::*};
+use crate::{
+    errors::VotingError,
+    state::{global::*, organization::OrganizationAccount, session_index::*, voting_session::*},
+    utils::{index_session, validate_metadata},
+};
 
 #[derive(Accounts)]
 pub struct CreateVotingSessionContextData<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
     pub global_account: Account<'info, GlobalAccount>,
 
+    /// CHECK: protocol authority receiving the session fee, checked against global account
+    #[account(mut)]
+    pub authority: UncheckedAccount<'info>,
+
     #[account(
         init,
         payer = owner,
@@ -22,6 +34,33 @@ pub struct CreateVotingSessionContextData<'info> {
     )]
     pub session_account: Account<'info, SessionAccount>,
 
+    pub organization_account: Option<Account<'info, OrganizationAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + SessionIndexAccount::INIT_SPACE,
+        seeds = [
+            SessionIndexAccount::ADMIN_SEED_PREFIX.as_ref(),
+            owner.key().as_ref()
+        ],
+        bump
+    )]
+    pub admin_session_index: Account<'info, SessionIndexAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + SessionIndexPageAccount::INIT_SPACE,
+        seeds = [
+            SessionIndexAccount::ADMIN_SEED_PREFIX.as_ref(),
+            owner.key().as_ref(),
+            &admin_session_index.current_page().to_le_bytes()
+        ],
+        bump
+    )]
+    pub admin_session_index_page: Account<'info, SessionIndexPageAccount>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -29,26 +68,112 @@ pub fn create_voting_session(
     ctx: Context<CreateVotingSessionContextData>,
     name: String,
     description: String,
+    metadata_uri: String,
+    metadata_hash: [u8; 32],
 ) -> Result<()> {
     let global_account = &mut ctx.accounts.global_account;
     let session_account = &mut ctx.accounts.session_account;
+
+    require!(!global_account.paused, VotingError::ProgramPaused);
+    require!(
+        global_account.authority == ctx.accounts.authority.key(),
+        VotingError::UnexpectedAuthority
+    );
+    require!(!name.is_empty(), VotingError::EmptySessionName);
+    require!(
+        name.len() <= global_account.config.max_name_len as usize,
+        VotingError::SessionNameTooLong
+    );
+    require!(
+        description.len() <= global_account.config.max_description_len as usize,
+        VotingError::SessionDescriptionTooLong
+    );
+    validate_metadata(&metadata_uri, &metadata_hash)?;
+
+    if global_account.config.session_fee > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.owner.to_account_info(),
+                    to: ctx.accounts.authority.to_account_info(),
+                },
+            ),
+            global_account.config.session_fee,
+        )?;
+    }
+
     session_account.status = SessionWorkflowStatus::RegisteringVoters;
 
     session_account.session_id = global_account.session_count;
     session_account.admin = ctx.accounts.owner.key();
     session_account.name = name.clone();
     session_account.description = description.clone();
+    session_account.metadata_uri = metadata_uri.clone();
+    session_account.metadata_hash = metadata_hash;
+    session_account.questions_count = 1;
+    session_account.voting_mode = VotingMode::Plurality;
+    session_account.ballots_count = 0;
+    session_account.seats = 1;
+    session_account.voters_weight = 0;
+    session_account.ballots_weight = 0;
+    session_account.lock_mint = None;
+    session_account.voting_started_at = 0;
+    session_account.snapshot_slot = 0;
+    session_account.encryption = None;
+    session_account.anonymous = false;
+    session_account.badge_mint = None;
+    session_account.badge_mint_bump = 0;
     session_account.proposals_count = 1; // 0 is abstention vote
     session_account.voters_count = 0;
     session_account.result = SessionResult {
         total_votes: 0,
         blank_votes: 0,
         abstention: 0,
-        winning_proposals: Vec::with_capacity(10),
+        winning_proposals: Vec::with_capacity(MAX_SEATS),
+        score_distribution: Vec::new(),
+        has_condorcet_winner: false,
+        tied_proposals: Vec::new(),
+        voted_count: 0,
+        abstention_count: 0,
+        receipts_root: [0; 32],
+        receipts_count: 0,
     };
+    session_account.proposals_config = ProposalsConfig::default();
+    session_account.execution_delay = 0;
+    session_account.veto_council = None;
+    session_account.tallied_at = 0;
+    session_account.execution_eta = 0;
+    session_account.vetoed_at = 0;
+    session_account.released_at = 0;
+
+    // organization members registered at session creation are eligible, they are counted as they join
+    match &ctx.accounts.organization_account {
+        Some(organization_account) => {
+            require!(
+                organization_account
+                    .admins
+                    .contains(&ctx.accounts.owner.key()),
+                VotingError::ForbiddenAsNonOrganizationAdmin
+            );
+            session_account.organization_id = Some(organization_account.organization_id);
+            session_account.organization_members_seq = organization_account.members_seq;
+        }
+        None => {
+            session_account.organization_id = None;
+            session_account.organization_members_seq = 0;
+        }
+    }
 
     global_account.session_count += 1;
 
+    index_session(
+        &mut ctx.accounts.admin_session_index,
+        &mut ctx.accounts.admin_session_index_page,
+        ctx.accounts.owner.key(),
+        session_account.session_id,
+    );
+
     emit!(SessionWorkflowStatusChanged {
         session_id: session_account.session_id,
         previous_status: SessionWorkflowStatus::None,
@@ -58,6 +183,8 @@ pub fn create_voting_session(
         session_id: session_account.session_id,
         name,
         description,
+        metadata_uri,
+        metadata_hash,
     });
     Ok(())
 }
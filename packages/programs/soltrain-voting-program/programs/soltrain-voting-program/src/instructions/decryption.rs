@@ -0,0 +1,199 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    elgamal::*,
+    errors::VotingError,
+    state::{encryption::*, global::GlobalAccount, proposal::*, voting_session::*},
+};
+
+#[derive(Accounts)]
+pub struct PostDecryptionShareContextData<'info> {
+    #[account(mut)]
+    pub trustee: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    pub session_account: Account<'info, SessionAccount>,
+
+    #[account(mut)]
+    pub proposal_account: Account<'info, ProposalAccount>,
+}
+
+#[derive(Accounts)]
+pub struct RevealProposalVotesContextData<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    pub session_account: Account<'info, SessionAccount>,
+
+    #[account(mut)]
+    pub proposal_account: Account<'info, ProposalAccount>,
+}
+
+pub fn post_decryption_share(
+    ctx: Context<PostDecryptionShareContextData>,
+    share: [u8; 32],
+    proof: EqualityProof,
+) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
+    let session_account = &ctx.accounts.session_account;
+    let proposal_account = &mut ctx.accounts.proposal_account;
+
+    require!(
+        session_account.status == SessionWorkflowStatus::VotingSessionEnded,
+        VotingError::UnexpectedSessionStatus
+    );
+    let Some(encryption) = &session_account.encryption else {
+        return err!(VotingError::UnexpectedVotingMode);
+    };
+    require!(
+        proposal_account.session_id == session_account.session_id,
+        VotingError::InvalidProposalId
+    );
+
+    let Some(trustee_index) = encryption
+        .trustees
+        .iter()
+        .position(|trustee| trustee.authority == ctx.accounts.trustee.key())
+    else {
+        return err!(VotingError::ForbiddenAsNonTrustee);
+    };
+    let trustee_index = trustee_index as u8;
+    require!(
+        !proposal_account
+            .decryption_shares
+            .iter()
+            .any(|share| share.trustee_index == trustee_index),
+        VotingError::DecryptionShareAlreadyPosted
+    );
+
+    // the share is the trustee secret applied to C1, same secret as its verification key
+    let context = [
+        session_account.session_id.to_le_bytes().as_slice(),
+        &[proposal_account.proposal_id, trustee_index],
+    ]
+    .concat();
+    verify_equality_proof(
+        DECRYPTION_PROOF_LABEL,
+        &context,
+        (
+            &BASEPOINT,
+            &encryption.trustees[trustee_index as usize].verification_key,
+        ),
+        (&proposal_account.encrypted_votes.c1, &share),
+        &proof,
+    )?;
+
+    proposal_account.decryption_shares.push(DecryptionShare {
+        trustee_index,
+        share,
+    });
+
+    emit!(DecryptionSharePosted {
+        session_id: session_account.session_id,
+        proposal_id: proposal_account.proposal_id,
+        trustee_index,
+    });
+
+    Ok(())
+}
+
+/// Reveals the aggregated votes of a proposal from the first `threshold` decryption shares.
+/// Lagrange coefficients at 0 are given by the caller and checked on-chain, the discrete log
+/// of the count being brute-forced off-chain and checked against the combined decryption.
+pub fn reveal_proposal_votes(
+    ctx: Context<RevealProposalVotesContextData>,
+    vote_count: u32,
+    coefficients: Vec<[u8; 32]>,
+) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
+    let session_account = &ctx.accounts.session_account;
+    let proposal_account = &mut ctx.accounts.proposal_account;
+
+    require!(
+        session_account.status == SessionWorkflowStatus::VotingSessionEnded,
+        VotingError::UnexpectedSessionStatus
+    );
+    let Some(encryption) = &session_account.encryption else {
+        return err!(VotingError::UnexpectedVotingMode);
+    };
+    require!(
+        proposal_account.session_id == session_account.session_id,
+        VotingError::InvalidProposalId
+    );
+    require!(
+        !proposal_account.votes_revealed,
+        VotingError::VotesAlreadyRevealed
+    );
+
+    let threshold = encryption.threshold as usize;
+    require!(
+        proposal_account.decryption_shares.len() >= threshold,
+        VotingError::NotEnoughDecryptionShares
+    );
+    require!(
+        coefficients.len() == threshold,
+        VotingError::InvalidDecryption
+    );
+
+    let shares = &proposal_account.decryption_shares[..threshold];
+    let indexes: Vec<i64> = shares
+        .iter()
+        .map(|share| share.trustee_index as i64 + 1)
+        .collect();
+
+    let mut combined_share = IDENTITY;
+    for ((share, coefficient), &index) in shares.iter().zip(coefficients.iter()).zip(&indexes) {
+        // lambda_i = prod(x_j) / prod(x_j - x_i), checked as lambda_i·denominator == numerator
+        let (numerator, denominator) = indexes
+            .iter()
+            .filter(|&&other| other != index)
+            .fold((1i64, 1i64), |(numerator, denominator), &other| {
+                (numerator * other, denominator * (other - index))
+            });
+        require!(
+            multiply(
+                &scalar_from_i64(denominator),
+                &multiply(coefficient, &BASEPOINT)?
+            )? == multiply(&scalar_from_i64(numerator), &BASEPOINT)?,
+            VotingError::InvalidDecryption
+        );
+        combined_share = add(&combined_share, &multiply(coefficient, &share.share)?)?;
+    }
+
+    // C2 - s·C1 = m·G
+    require!(
+        subtract(&proposal_account.encrypted_votes.c2, &combined_share)?
+            == multiply(&scalar_from_i64(vote_count as i64), &BASEPOINT)?,
+        VotingError::InvalidDecryption
+    );
+
+    proposal_account.vote_count = vote_count;
+    proposal_account.votes_revealed = true;
+
+    emit!(ProposalVotesRevealed {
+        session_id: session_account.session_id,
+        proposal_id: proposal_account.proposal_id,
+        vote_count,
+    });
+
+    Ok(())
+}
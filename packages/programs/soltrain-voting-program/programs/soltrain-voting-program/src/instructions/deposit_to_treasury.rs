@@ -0,0 +1,115 @@
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::{
+    errors::VotingError,
+    state::{global::GlobalAccount, treasury::*, voting_session::SessionAccount},
+    utils::transfer_tokens,
+};
+
+#[derive(Accounts)]
+pub struct DepositToTreasuryContextData<'info> {
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    pub session_account: Account<'info, SessionAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        space = 8 + TreasuryAccount::INIT_SPACE,
+        seeds = [
+            TreasuryAccount::SEED_PREFIX.as_ref(),
+            &session_account.session_id.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub treasury_account: Account<'info, TreasuryAccount>,
+
+    pub mint: Option<Account<'info, Mint>>,
+
+    #[account(mut, token::mint = mint, token::authority = depositor)]
+    pub depositor_token_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        associated_token::mint = mint,
+        associated_token::authority = treasury_account,
+    )]
+    pub treasury_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn deposit_to_treasury(ctx: Context<DepositToTreasuryContextData>, amount: u64) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+    require!(amount > 0, VotingError::InvalidAmount);
+
+    let session_account = &ctx.accounts.session_account;
+    let treasury_account = &mut ctx.accounts.treasury_account;
+
+    treasury_account.session_id = session_account.session_id;
+    treasury_account.bump = ctx.bumps.treasury_account;
+
+    // SOL deposit when no mint is given
+    let mint = match &ctx.accounts.mint {
+        None => {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.depositor.to_account_info(),
+                        to: treasury_account.to_account_info(),
+                    },
+                ),
+                amount,
+            )?;
+            None
+        }
+        Some(mint) => {
+            let (Some(depositor_token_account), Some(treasury_token_account), Some(token_program)) = (
+                &ctx.accounts.depositor_token_account,
+                &ctx.accounts.treasury_token_account,
+                &ctx.accounts.token_program,
+            ) else {
+                return err!(VotingError::MissingTokenAccounts);
+            };
+
+            transfer_tokens(
+                token_program,
+                mint,
+                depositor_token_account,
+                treasury_token_account,
+                ctx.accounts.depositor.to_account_info(),
+                amount,
+            )?;
+            Some(mint.key())
+        }
+    };
+
+    emit!(TreasuryDeposited {
+        session_id: session_account.session_id,
+        depositor: ctx.accounts.depositor.key(),
+        mint,
+        amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,242 @@
+use anchor_lang::{prelude::*, solana_program::hash::hashv};
+
+use crate::{
+    elgamal::*,
+    errors::VotingError,
+    state::{
+        encryption::*, global::GlobalAccount, proposal::*, receipt::*, voter::*, voting_session::*,
+    },
+};
+
+#[derive(Accounts)]
+pub struct CastEncryptedVoteContextData<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    pub session_account: Account<'info, SessionAccount>,
+
+    pub voter_account: Account<'info, VoterAccount>,
+
+    // one ciphertext per proposal, blank proposal included
+    #[account(
+        init_if_needed,
+        payer = voter,
+        space = EncryptedBallotAccount::space(session_account.proposals_count as usize - 1),
+        seeds = [
+            EncryptedBallotAccount::SEED_PREFIX.as_ref(),
+            &session_account.session_id.to_le_bytes(),
+            voter.key().as_ref()
+        ],
+        bump
+    )]
+    pub encrypted_ballot_account: Account<'info, EncryptedBallotAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SealEncryptedBallotContextData<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut)]
+    pub session_account: Account<'info, SessionAccount>,
+
+    #[account(mut)]
+    pub voter_account: Account<'info, VoterAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            EncryptedBallotAccount::SEED_PREFIX.as_ref(),
+            &session_account.session_id.to_le_bytes(),
+            voter.key().as_ref()
+        ],
+        bump
+    )]
+    pub encrypted_ballot_account: Account<'info, EncryptedBallotAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            ReceiptAccumulatorAccount::SEED_PREFIX.as_ref(),
+            &session_account.session_id.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub receipts_account: Box<Account<'info, ReceiptAccumulatorAccount>>,
+}
+
+/// Checks that the signer is a voter of the encrypted session who has not voted yet,
+/// and returns the election public key.
+fn check_encrypted_voter(
+    session_account: &SessionAccount,
+    voter_account: &VoterAccount,
+    voter: &Pubkey,
+) -> Result<[u8; 32]> {
+    require!(
+        session_account.status == SessionWorkflowStatus::VotingSessionStarted,
+        VotingError::UnexpectedSessionStatus
+    );
+    let Some(encryption) = &session_account.encryption else {
+        return err!(VotingError::UnexpectedVotingMode);
+    };
+    require!(
+        session_account.admin.key() != *voter,
+        VotingError::AdminForbiddenAsVoter
+    );
+    require!(
+        voter_account.voter.key() == *voter
+            && voter_account.session_id == session_account.session_id,
+        VotingError::UnexpectedVoter
+    );
+    require!(!voter_account.has_voted, VotingError::VoterAlreadyVoted);
+    session_account.require_voting_power(voter_account)?;
+
+    Ok(encryption.election_public_key)
+}
+
+pub fn cast_encrypted_vote(
+    ctx: Context<CastEncryptedVoteContextData>,
+    proposal_id: u8,
+    ciphertext: Ciphertext,
+    proof: BitProof,
+) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
+    let voter = ctx.accounts.voter.key();
+    let session_account = &ctx.accounts.session_account;
+    let election_public_key =
+        check_encrypted_voter(session_account, &ctx.accounts.voter_account, &voter)?;
+
+    let encrypted_ballot_account = &mut ctx.accounts.encrypted_ballot_account;
+    encrypted_ballot_account.session_id = session_account.session_id;
+    encrypted_ballot_account.voter = voter;
+
+    // ciphertexts are appended in proposal order, so that none can be skipped or repeated
+    require!(
+        proposal_id as usize == encrypted_ballot_account.ciphertexts.len() + 1
+            && proposal_id < session_account.proposals_count,
+        VotingError::InvalidProposalId
+    );
+
+    let context = [
+        session_account.session_id.to_le_bytes().as_slice(),
+        voter.as_ref(),
+        &[proposal_id],
+    ]
+    .concat();
+    verify_bit_proof(&context, &election_public_key, &ciphertext, &proof)?;
+
+    encrypted_ballot_account.ciphertexts.push(ciphertext);
+
+    Ok(())
+}
+
+pub fn seal_encrypted_ballot<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SealEncryptedBallotContextData<'info>>,
+    sum_proof: EqualityProof,
+) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
+    let voter = ctx.accounts.voter.key();
+    let session_account = &mut ctx.accounts.session_account;
+    let voter_account = &mut ctx.accounts.voter_account;
+    let election_public_key = check_encrypted_voter(session_account, voter_account, &voter)?;
+
+    let encrypted_ballot_account = &mut ctx.accounts.encrypted_ballot_account;
+    let ciphertexts = &encrypted_ballot_account.ciphertexts;
+    let proposals_count = session_account.proposals_count as usize - 1;
+    require!(
+        ciphertexts.len() == proposals_count,
+        VotingError::EncryptedBallotIncomplete
+    );
+
+    // ciphertexts all encrypt 0 or 1, their sum encrypting 1 makes a single choice
+    let mut sum = Ciphertext {
+        c1: IDENTITY,
+        c2: IDENTITY,
+    };
+    for ciphertext in ciphertexts {
+        sum = add_ciphertexts(&sum, ciphertext)?;
+    }
+    let context = [
+        session_account.session_id.to_le_bytes().as_slice(),
+        voter.as_ref(),
+    ]
+    .concat();
+    verify_equality_proof(
+        SUM_PROOF_LABEL,
+        &context,
+        (&BASEPOINT, &sum.c1),
+        (&election_public_key, &subtract(&sum.c2, &BASEPOINT)?),
+        &sum_proof,
+    )?;
+
+    // remaining accounts are all the session proposals, in proposal order
+    require!(
+        ctx.remaining_accounts.len() == proposals_count,
+        VotingError::BadProposalAccountsCount
+    );
+
+    let weight = scalar_from_i64(voter_account.weight as i64);
+    for ((index, proposal_info), ciphertext) in
+        ctx.remaining_accounts.iter().enumerate().zip(ciphertexts)
+    {
+        require!(proposal_info.is_writable, VotingError::InvalidProposalId);
+
+        let mut proposal: Account<ProposalAccount> = Account::try_from(proposal_info)?;
+        require!(
+            proposal.session_id == session_account.session_id
+                && proposal.proposal_id as usize == index + 1,
+            VotingError::InvalidProposalId
+        );
+
+        // homomorphic addition, a weighted voter counts as many votes
+        let ciphertext = if voter_account.weight == 1 {
+            *ciphertext
+        } else {
+            multiply_ciphertext(&weight, ciphertext)?
+        };
+        proposal.encrypted_votes = add_ciphertexts(&proposal.encrypted_votes, &ciphertext)?;
+        proposal.exit(&crate::ID)?;
+    }
+
+    encrypted_ballot_account.sealed = true;
+    voter_account.has_voted = true;
+    session_account.ballots_count += 1;
+    session_account.ballots_weight += voter_account.weight;
+
+    emit!(EncryptedBallotSealed {
+        session_id: session_account.session_id,
+        voter,
+    });
+
+    // the choice is secret, the ballot being the hash of the ciphertexts
+    let ballot: Vec<&[u8]> = encrypted_ballot_account
+        .ciphertexts
+        .iter()
+        .flat_map(|ciphertext| [ciphertext.c1.as_slice(), &ciphertext.c2])
+        .collect();
+    ctx.accounts
+        .receipts_account
+        .add_receipt(voter, hashv(&ballot).to_bytes().to_vec())
+}
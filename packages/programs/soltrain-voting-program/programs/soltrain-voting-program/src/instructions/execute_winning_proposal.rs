@@ -0,0 +1,114 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{instruction::Instruction, program::invoke_signed},
+};
+
+use crate::{
+    errors::VotingError,
+    state::{global::GlobalAccount, proposal_transaction::*, voting_session::*},
+};
+
+#[derive(Accounts)]
+pub struct ExecuteWinningProposalContextData<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    pub session_account: Account<'info, SessionAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            ProposalTransactionAccount::SEED_PREFIX.as_ref(),
+            &session_account.session_id.to_le_bytes(),
+            &[proposal_transaction_account.proposal_id],
+        ],
+        bump
+    )]
+    pub proposal_transaction_account: Account<'info, ProposalTransactionAccount>,
+
+    /// CHECK: session owned PDA signing proposal instructions, writable to spend its lamports
+    #[account(
+        mut,
+        seeds = [
+            ProposalTransactionAccount::EXECUTOR_SEED_PREFIX.as_ref(),
+            &session_account.session_id.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub executor: UncheckedAccount<'info>,
+}
+
+pub fn execute_winning_proposal<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExecuteWinningProposalContextData<'info>>,
+) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
+    let session_account = &ctx.accounts.session_account;
+    let proposal_transaction_account = &mut ctx.accounts.proposal_transaction_account;
+    let proposal_id = proposal_transaction_account.proposal_id;
+
+    require!(
+        session_account.status == SessionWorkflowStatus::VotesTallied,
+        VotingError::UnexpectedSessionStatus
+    );
+    session_account.require_elected(proposal_id)?;
+    require!(
+        session_account.released_at > 0,
+        VotingError::ResultNotReleased
+    );
+    require!(
+        !proposal_transaction_account.executed,
+        VotingError::ProposalAlreadyExecuted
+    );
+
+    proposal_transaction_account.executed = true;
+
+    let session_id = session_account.session_id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        ProposalTransactionAccount::EXECUTOR_SEED_PREFIX.as_ref(),
+        &session_id,
+        &[ctx.bumps.executor],
+    ]];
+
+    for proposal_instruction in proposal_transaction_account.instructions.iter() {
+        let instruction = Instruction::from(proposal_instruction);
+        let mut account_infos = Vec::with_capacity(instruction.accounts.len() + 1);
+        for meta in instruction.accounts.iter() {
+            let account_info = if meta.pubkey == ctx.accounts.executor.key() {
+                ctx.accounts.executor.to_account_info()
+            } else {
+                ctx.remaining_accounts
+                    .iter()
+                    .find(|account| account.key() == meta.pubkey)
+                    .ok_or(VotingError::MissingInstructionAccount)?
+                    .clone()
+            };
+            account_infos.push(account_info);
+        }
+        account_infos.push(
+            ctx.remaining_accounts
+                .iter()
+                .find(|account| account.key() == instruction.program_id)
+                .ok_or(VotingError::MissingInstructionAccount)?
+                .clone(),
+        );
+
+        invoke_signed(&instruction, &account_infos, signer_seeds)?;
+    }
+
+    emit!(ProposalExecuted {
+        session_id: session_account.session_id,
+        proposal_id,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{proposal::ProposalAccount, question::QuestionAccount, voting_session::*};
+
+#[derive(Accounts)]
+pub struct GetProposalOutcomeContextData<'info> {
+    pub session_account: Account<'info, SessionAccount>,
+
+    pub proposal_account: Account<'info, ProposalAccount>,
+
+    // required for proposals of added questions, their result is stored in the question account
+    pub question_account: Option<Account<'info, QuestionAccount>>,
+}
+
+// read only, results can still be read while the program is paused
+pub fn get_proposal_outcome(
+    ctx: Context<GetProposalOutcomeContextData>,
+) -> Result<ProposalOutcome> {
+    ctx.accounts.session_account.proposal_outcome(
+        &ctx.accounts.proposal_account,
+        ctx.accounts.question_account.as_deref(),
+    )
+}
@@ -1,6 +1,10 @@
 use anchor_lang::prelude::*;
 
-use crate::state::global::*;
+use crate::{
+    errors::VotingError,
+    program::Voting,
+    state::{global::*, identity::*, voting_session::*},
+};
 
 #[derive(Accounts)]
 pub struct InitGlobalContextData<'info> {
@@ -16,11 +20,161 @@ pub struct InitGlobalContextData<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
 
+    pub program: Program<'info, Voting>,
+
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateGlobalConfigContextData<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+}
+
+#[derive(Accounts)]
+pub struct SetPausedContextData<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+}
+
+#[derive(Accounts)]
+pub struct SetVerifyingKeyContextData<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + VerifyingKeyAccount::INIT_SPACE,
+        seeds = [VerifyingKeyAccount::SEED.as_ref()],
+        bump,
+    )]
+    pub verifying_key_account: Box<Account<'info, VerifyingKeyAccount>>,
+
     pub system_program: Program<'info, System>,
 }
 
-pub fn init_global(ctx: Context<InitGlobalContextData>) -> Result<()> {
+pub fn init_global(ctx: Context<InitGlobalContextData>, config: GlobalConfig) -> Result<()> {
     let global_account = &mut ctx.accounts.global_account;
+    let owner = &ctx.accounts.owner;
+
+    // only the program upgrade authority can claim protocol authority
+    require!(
+        ctx.accounts.program.programdata_address()? == Some(ctx.accounts.program_data.key()),
+        VotingError::InvalidAccountType
+    );
+    require!(
+        ctx.accounts.program_data.upgrade_authority_address == Some(owner.key()),
+        VotingError::ForbiddenAsNonAuthority
+    );
+    validate_global_config(&config)?;
+
     global_account.session_count = 0;
+    global_account.authority = owner.key();
+    global_account.config = config;
+    global_account.paused = false;
+    global_account.organization_count = 0;
+
+    emit_global_config_updated(global_account);
+    Ok(())
+}
+
+pub fn update_global_config(
+    ctx: Context<UpdateGlobalConfigContextData>,
+    config: GlobalConfig,
+) -> Result<()> {
+    let global_account = &mut ctx.accounts.global_account;
+
+    require!(
+        global_account.authority == ctx.accounts.authority.key(),
+        VotingError::ForbiddenAsNonAuthority
+    );
+    validate_global_config(&config)?;
+
+    global_account.config = config;
+
+    emit_global_config_updated(global_account);
+    Ok(())
+}
+
+pub fn set_paused(ctx: Context<SetPausedContextData>, paused: bool) -> Result<()> {
+    let global_account = &mut ctx.accounts.global_account;
+
+    require!(
+        global_account.authority == ctx.accounts.authority.key(),
+        VotingError::ForbiddenAsNonAuthority
+    );
+
+    global_account.paused = paused;
+
+    emit!(PausedChanged {
+        authority: global_account.authority,
+        paused,
+    });
     Ok(())
 }
+
+pub fn set_verifying_key(
+    ctx: Context<SetVerifyingKeyContextData>,
+    verifying_key: VerifyingKey,
+) -> Result<()> {
+    require!(
+        ctx.accounts.global_account.authority == ctx.accounts.authority.key(),
+        VotingError::ForbiddenAsNonAuthority
+    );
+
+    let verifying_key_account = &mut ctx.accounts.verifying_key_account;
+    verifying_key_account.alpha_g1 = verifying_key.alpha_g1;
+    verifying_key_account.beta_g2 = verifying_key.beta_g2;
+    verifying_key_account.gamma_g2 = verifying_key.gamma_g2;
+    verifying_key_account.delta_g2 = verifying_key.delta_g2;
+    verifying_key_account.ic = verifying_key.ic;
+
+    emit!(VerifyingKeyUpdated {
+        authority: ctx.accounts.authority.key(),
+    });
+    Ok(())
+}
+
+fn validate_global_config(config: &GlobalConfig) -> Result<()> {
+    require!(
+        config.max_name_len > 0 && config.max_name_len as usize <= MAX_SESSION_NAME_LEN,
+        VotingError::InvalidGlobalConfig
+    );
+    require!(
+        config.max_description_len as usize <= MAX_SESSION_DESCRIPTION_LEN,
+        VotingError::InvalidGlobalConfig
+    );
+    require!(config.max_voters > 0, VotingError::InvalidGlobalConfig);
+    Ok(())
+}
+
+fn emit_global_config_updated(global_account: &GlobalAccount) {
+    emit!(GlobalConfigUpdated {
+        authority: global_account.authority,
+        session_fee: global_account.config.session_fee,
+        max_name_len: global_account.config.max_name_len,
+        max_description_len: global_account.config.max_description_len,
+        max_voters: global_account.config.max_voters,
+    });
+}
@@ -0,0 +1,147 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::VotingError,
+    state::{
+        global::GlobalAccount, organization::*, question::MAX_QUESTIONS, session_index::*,
+        voter::*, voting_session::*,
+    },
+    utils::index_session,
+};
+
+#[derive(Accounts)]
+pub struct JoinSessionContextData<'info> {
+    #[account(mut)]
+    pub member: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut)]
+    pub session_account: Account<'info, SessionAccount>,
+
+    #[account(
+        seeds = [
+            OrganizationMemberAccount::SEED_PREFIX.as_ref(),
+            &member_account.organization_id.to_le_bytes(),
+            member.key().as_ref()
+        ],
+        bump
+    )]
+    pub member_account: Account<'info, OrganizationMemberAccount>,
+
+    #[account(
+        init,
+        payer = member,
+        space = 8 + VoterAccount::INIT_SPACE,
+        seeds = [
+            VoterAccount::SEED_PREFIX.as_ref(),
+            &session_account.session_id.to_le_bytes(),
+            member.key().as_ref()
+        ],
+        bump
+    )]
+    pub voter_account: Account<'info, VoterAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = member,
+        space = 8 + SessionIndexAccount::INIT_SPACE,
+        seeds = [
+            SessionIndexAccount::VOTER_SEED_PREFIX.as_ref(),
+            member.key().as_ref()
+        ],
+        bump
+    )]
+    pub voter_session_index: Account<'info, SessionIndexAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = member,
+        space = 8 + SessionIndexPageAccount::INIT_SPACE,
+        seeds = [
+            SessionIndexAccount::VOTER_SEED_PREFIX.as_ref(),
+            member.key().as_ref(),
+            &voter_session_index.current_page().to_le_bytes()
+        ],
+        bump
+    )]
+    pub voter_session_index_page: Account<'info, SessionIndexPageAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn join_session(ctx: Context<JoinSessionContextData>) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
+    let session_account = &mut ctx.accounts.session_account;
+    let member_account = &ctx.accounts.member_account;
+    let voter_account = &mut ctx.accounts.voter_account;
+
+    require!(
+        session_account.organization_id == Some(member_account.organization_id),
+        VotingError::NotOrganizationSession
+    );
+    require!(
+        session_account.admin.key() != ctx.accounts.member.key(),
+        VotingError::AdminForbiddenAsVoter
+    );
+    // eligibility is the standing membership at session creation
+    require!(
+        member_account.active
+            && member_account.member_id <= session_account.organization_members_seq,
+        VotingError::MemberNotRegistered
+    );
+    require!(
+        matches!(
+            session_account.status,
+            SessionWorkflowStatus::RegisteringVoters
+                | SessionWorkflowStatus::ProposalsRegistrationStarted
+                | SessionWorkflowStatus::ProposalsRegistrationEnded
+        ),
+        VotingError::UnexpectedSessionStatus
+    );
+    require!(
+        session_account.voters_count < ctx.accounts.global_account.config.max_voters,
+        VotingError::MaxVotersReached
+    );
+
+    session_account.voters_count += 1;
+    session_account.voters_weight += 1;
+
+    voter_account.session_id = session_account.session_id;
+    voter_account.voter = ctx.accounts.member.key();
+    voter_account.voter_id = member_account.member_id;
+    voter_account.voted_proposal_id = 0;
+    voter_account.nb_proposals = 0;
+    voter_account.question_votes = [0; MAX_QUESTIONS];
+    voter_account.seat_votes = [0; MAX_SEATS];
+    voter_account.credits = None;
+    voter_account.credits_spent = 0;
+    voter_account.weight = 1;
+    voter_account.nonce = 0;
+    voter_account.identity_commitment = [0; 32];
+    voter_account.reward_claimed = false;
+    voter_account.badge_minted = false;
+    voter_account.power_applied = false;
+
+    index_session(
+        &mut ctx.accounts.voter_session_index,
+        &mut ctx.accounts.voter_session_index_page,
+        voter_account.voter,
+        session_account.session_id,
+    );
+
+    emit!(VoterRegistered {
+        session_id: session_account.session_id,
+        voter: voter_account.voter,
+        weight: voter_account.weight,
+    });
+    Ok(())
+}
@@ -0,0 +1,190 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::{
+    errors::VotingError,
+    state::{global::GlobalAccount, lock::*},
+    utils::{transfer_tokens, transfer_tokens_signed},
+};
+
+#[derive(Accounts)]
+pub struct LockTokensContextData<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + LockAccount::INIT_SPACE,
+        seeds = [
+            LockAccount::SEED_PREFIX.as_ref(),
+            mint.key().as_ref(),
+            owner.key().as_ref()
+        ],
+        bump
+    )]
+    pub lock_account: Account<'info, LockAccount>,
+
+    #[account(mut, token::mint = mint, token::authority = owner)]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = mint,
+        associated_token::authority = lock_account,
+    )]
+    pub lock_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UnlockTokensContextData<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [
+            LockAccount::SEED_PREFIX.as_ref(),
+            mint.key().as_ref(),
+            owner.key().as_ref()
+        ],
+        bump = lock_account.bump
+    )]
+    pub lock_account: Account<'info, LockAccount>,
+
+    #[account(mut, token::mint = mint, token::authority = owner)]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = lock_account,
+    )]
+    pub lock_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn lock_tokens(ctx: Context<LockTokensContextData>, amount: u64, duration: i64) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+    require!(
+        duration > 0 && duration <= MAX_LOCK_DURATION,
+        VotingError::InvalidLockDuration
+    );
+
+    let lock_account = &mut ctx.accounts.lock_account;
+    let now = Clock::get()?.unix_timestamp;
+
+    // a lock can be topped up or extended, never shortened
+    let unlock_at = now + duration;
+    require!(
+        unlock_at >= lock_account.unlock_at,
+        VotingError::InvalidLockDuration
+    );
+    require!(
+        amount > 0 || lock_account.amount > 0,
+        VotingError::InvalidAmount
+    );
+
+    if amount > 0 {
+        transfer_tokens(
+            &ctx.accounts.token_program,
+            &ctx.accounts.mint,
+            &ctx.accounts.owner_token_account,
+            &ctx.accounts.lock_token_account,
+            ctx.accounts.owner.to_account_info(),
+            amount,
+        )?;
+    }
+
+    lock_account.owner = ctx.accounts.owner.key();
+    lock_account.mint = ctx.accounts.mint.key();
+    lock_account.amount += amount;
+    lock_account.unlock_at = unlock_at;
+    lock_account.updated_at = now;
+    lock_account.bump = ctx.bumps.lock_account;
+
+    emit!(TokensLocked {
+        owner: lock_account.owner,
+        mint: lock_account.mint,
+        amount: lock_account.amount,
+        unlock_at,
+    });
+
+    Ok(())
+}
+
+pub fn unlock_tokens(ctx: Context<UnlockTokensContextData>) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
+    let lock_account = &mut ctx.accounts.lock_account;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        now >= lock_account.unlock_at,
+        VotingError::TokensStillLocked
+    );
+    require!(lock_account.amount > 0, VotingError::InvalidAmount);
+
+    let amount = lock_account.amount;
+    let mint = ctx.accounts.mint.key();
+    let owner = ctx.accounts.owner.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        LockAccount::SEED_PREFIX.as_ref(),
+        mint.as_ref(),
+        owner.as_ref(),
+        &[lock_account.bump],
+    ]];
+    transfer_tokens_signed(
+        &ctx.accounts.token_program,
+        &ctx.accounts.mint,
+        &ctx.accounts.lock_token_account,
+        &ctx.accounts.owner_token_account,
+        lock_account.to_account_info(),
+        signer_seeds,
+        amount,
+    )?;
+
+    lock_account.amount = 0;
+    lock_account.updated_at = now;
+
+    emit!(TokensUnlocked {
+        owner,
+        mint,
+        amount,
+    });
+
+    Ok(())
+}
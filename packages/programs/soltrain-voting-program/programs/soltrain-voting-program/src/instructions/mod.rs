@@ -1,10 +1,40 @@
+pub mod add_question;
+pub mod allocate_votes;
+pub mod anonymous_vote;
+pub mod apply_voting_power;
+pub mod attach_proposal_transaction;
+pub mod badge;
+pub mod configure_encryption;
+pub mod configure_execution;
+pub mod configure_proposals;
+pub mod configure_voting_mode;
+pub mod configure_voting_power;
 pub mod create_voting_session;
+pub mod decryption;
+pub mod deposit_to_treasury;
+pub mod encrypted_vote;
+pub mod execute_winning_proposal;
+pub mod get_proposal_outcome;
 pub mod global;
+pub mod join_session;
+pub mod lock;
+pub mod organization;
 pub mod register_proposal;
 pub mod register_voter;
+pub mod relay_vote;
+pub mod relay_votes;
+pub mod release_result;
+pub mod request_treasury_transfer;
+pub mod reward;
+pub mod settle_proposal_deposit;
+pub mod snapshot_voting_power;
 pub mod start_proposals_registration;
 pub mod start_voting_session;
 pub mod stop_proposals_registration;
 pub mod stop_voting_session;
 pub mod tally_votes;
+pub mod veto_result;
 pub mod vote;
+pub mod vote_ranked;
+pub mod vote_scores;
+pub mod withdraw_from_treasury;
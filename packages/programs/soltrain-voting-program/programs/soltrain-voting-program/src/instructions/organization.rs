@@ -0,0 +1,267 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::VotingError,
+    state::{global::GlobalAccount, organization::*},
+};
+
+#[derive(Accounts)]
+pub struct CreateOrganizationContextData<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + OrganizationAccount::INIT_SPACE,
+        seeds = [
+            OrganizationAccount::SEED_PREFIX.as_ref(),
+            global_account.organization_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub organization_account: Account<'info, OrganizationAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ManageOrganizationAdminContextData<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut)]
+    pub organization_account: Account<'info, OrganizationAccount>,
+}
+
+#[derive(Accounts)]
+#[instruction(member: Pubkey)]
+pub struct AddOrganizationMemberContextData<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut)]
+    pub organization_account: Account<'info, OrganizationAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + OrganizationMemberAccount::INIT_SPACE,
+        seeds = [
+            OrganizationMemberAccount::SEED_PREFIX.as_ref(),
+            &organization_account.organization_id.to_le_bytes(),
+            member.key().as_ref()
+        ],
+        bump
+    )]
+    pub member_account: Account<'info, OrganizationMemberAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveOrganizationMemberContextData<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut)]
+    pub organization_account: Account<'info, OrganizationAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            OrganizationMemberAccount::SEED_PREFIX.as_ref(),
+            &organization_account.organization_id.to_le_bytes(),
+            member_account.member.as_ref()
+        ],
+        bump
+    )]
+    pub member_account: Account<'info, OrganizationMemberAccount>,
+}
+
+pub fn create_organization(
+    ctx: Context<CreateOrganizationContextData>,
+    name: String,
+) -> Result<()> {
+    let global_account = &mut ctx.accounts.global_account;
+    let organization_account = &mut ctx.accounts.organization_account;
+
+    require!(!global_account.paused, VotingError::ProgramPaused);
+    require!(
+        name.len() <= MAX_ORGANIZATION_NAME_LEN,
+        VotingError::OrganizationNameTooLong
+    );
+
+    organization_account.organization_id = global_account.organization_count;
+    organization_account.name = name.clone();
+    organization_account.admins = vec![ctx.accounts.owner.key()];
+    organization_account.members_count = 0;
+    organization_account.members_seq = 0;
+
+    global_account.organization_count += 1;
+
+    emit!(OrganizationCreated {
+        organization_id: organization_account.organization_id,
+        name,
+        admin: ctx.accounts.owner.key(),
+    });
+    Ok(())
+}
+
+pub fn add_organization_admin(
+    ctx: Context<ManageOrganizationAdminContextData>,
+    admin: Pubkey,
+) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
+    let organization_account = &mut ctx.accounts.organization_account;
+
+    require!(
+        organization_account
+            .admins
+            .contains(&ctx.accounts.admin.key()),
+        VotingError::ForbiddenAsNonOrganizationAdmin
+    );
+    require!(
+        !organization_account.admins.contains(&admin),
+        VotingError::OrganizationAdminAlreadyRegistered
+    );
+    require!(
+        organization_account.admins.len() < MAX_ORGANIZATION_ADMINS,
+        VotingError::MaxOrganizationAdminsReached
+    );
+
+    organization_account.admins.push(admin);
+
+    emit!(OrganizationAdminAdded {
+        organization_id: organization_account.organization_id,
+        admin,
+    });
+    Ok(())
+}
+
+pub fn remove_organization_admin(
+    ctx: Context<ManageOrganizationAdminContextData>,
+    admin: Pubkey,
+) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
+    let organization_account = &mut ctx.accounts.organization_account;
+
+    require!(
+        organization_account
+            .admins
+            .contains(&ctx.accounts.admin.key()),
+        VotingError::ForbiddenAsNonOrganizationAdmin
+    );
+    require!(
+        organization_account.admins.contains(&admin),
+        VotingError::UnexpectedOrganizationAdmin
+    );
+    // an organization always keeps at least one administrator
+    require!(
+        organization_account.admins.len() > 1,
+        VotingError::LastOrganizationAdmin
+    );
+
+    organization_account.admins.retain(|a| *a != admin);
+
+    emit!(OrganizationAdminRemoved {
+        organization_id: organization_account.organization_id,
+        admin,
+    });
+    Ok(())
+}
+
+pub fn add_organization_member(
+    ctx: Context<AddOrganizationMemberContextData>,
+    member: Pubkey,
+) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
+    let organization_account = &mut ctx.accounts.organization_account;
+    let member_account = &mut ctx.accounts.member_account;
+
+    require!(
+        organization_account
+            .admins
+            .contains(&ctx.accounts.admin.key()),
+        VotingError::ForbiddenAsNonOrganizationAdmin
+    );
+    require!(!member_account.active, VotingError::MemberAlreadyRegistered);
+
+    // a re-added member gets a new id so that it is not eligible to sessions created while inactive
+    organization_account.members_seq += 1;
+    organization_account.members_count += 1;
+
+    member_account.organization_id = organization_account.organization_id;
+    member_account.member = member;
+    member_account.member_id = organization_account.members_seq;
+    member_account.active = true;
+
+    emit!(OrganizationMemberAdded {
+        organization_id: organization_account.organization_id,
+        member,
+        member_id: member_account.member_id,
+    });
+    Ok(())
+}
+
+pub fn remove_organization_member(ctx: Context<RemoveOrganizationMemberContextData>) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
+    let organization_account = &mut ctx.accounts.organization_account;
+    let member_account = &mut ctx.accounts.member_account;
+
+    require!(
+        organization_account
+            .admins
+            .contains(&ctx.accounts.admin.key()),
+        VotingError::ForbiddenAsNonOrganizationAdmin
+    );
+    require!(member_account.active, VotingError::MemberNotRegistered);
+
+    organization_account.members_count -= 1;
+    member_account.active = false;
+
+    emit!(OrganizationMemberRemoved {
+        organization_id: organization_account.organization_id,
+        member: member_account.member,
+    });
+    Ok(())
+}
@@ -1,8 +1,15 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::token::{Mint, Token, TokenAccount};
 
 use crate::{
+    elgamal::IDENTITY,
     errors::VotingError,
-    state::{proposal::*, voter::VoterAccount, voting_session::*},
+    state::{
+        ballot::MAX_SCORE, encryption::Ciphertext, global::GlobalAccount,
+        pairwise::MAX_RANKED_PROPOSALS, proposal::*, question::QuestionAccount, vault::*,
+        voter::VoterAccount, voting_session::*,
+    },
+    utils::{transfer_tokens, validate_metadata},
 };
 
 #[derive(Accounts)]
@@ -10,6 +17,12 @@ pub struct RegisterProposalContextData<'info> {
     #[account(mut)]
     pub proposer: Signer<'info>,
 
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
     #[account(mut)]
     pub session_account: Account<'info, SessionAccount>,
 
@@ -29,13 +42,46 @@ pub struct RegisterProposalContextData<'info> {
     )]
     pub proposal_account: Account<'info, ProposalAccount>,
 
+    #[account(
+        mut,
+        seeds = [
+            VaultAccount::SEED_PREFIX.as_ref(),
+            &session_account.session_id.to_le_bytes(),
+        ],
+        bump = vault_account.bump
+    )]
+    pub vault_account: Option<Account<'info, VaultAccount>>,
+
+    pub deposit_mint: Option<Account<'info, Mint>>,
+
+    #[account(mut, token::mint = deposit_mint, token::authority = proposer)]
+    pub proposer_token_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = deposit_mint,
+        associated_token::authority = vault_account,
+    )]
+    pub vault_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    pub question_account: Option<Account<'info, QuestionAccount>>,
+
     pub system_program: Program<'info, System>,
 }
 
 pub fn register_proposal(
     ctx: Context<RegisterProposalContextData>,
     description: String,
+    metadata_uri: String,
+    metadata_hash: [u8; 32],
 ) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
     let session_account = &mut ctx.accounts.session_account;
     let voter_account = &mut ctx.accounts.voter_account;
     let proposal_account = &mut ctx.accounts.proposal_account;
@@ -46,7 +92,8 @@ pub fn register_proposal(
     );
 
     require!(
-        voter_account.voter.key() == ctx.accounts.proposer.key(),
+        voter_account.voter.key() == ctx.accounts.proposer.key()
+            && voter_account.session_id == session_account.session_id,
         VotingError::ProposerNotRegistered
     );
 
@@ -55,14 +102,110 @@ pub fn register_proposal(
         VotingError::UnexpectedSessionStatus
     );
 
+    require!(
+        !description.is_empty(),
+        VotingError::EmptyProposalDescription
+    );
+    require!(
+        description.len() <= MAX_PROPOSAL_DESCRIPTION_LEN,
+        VotingError::ProposalDescriptionTooLong
+    );
+    validate_metadata(&metadata_uri, &metadata_hash)?;
+
+    require!(
+        session_account.proposals_count < u8::MAX,
+        VotingError::MaxProposalsReached
+    );
+
+    // ranked candidates must fit in the pairwise preferences matrix
+    require!(
+        session_account.voting_mode != VotingMode::Condorcet
+            || (session_account.proposals_count as usize) < MAX_RANKED_PROPOSALS + 2,
+        VotingError::MaxProposalsReached
+    );
+
+    require!(
+        voter_account.nb_proposals < session_account.proposals_config.max_proposals_per_voter,
+        VotingError::MaxProposalsPerVoterReached
+    );
+
     register_internal_proposal(
         proposal_account,
         session_account.session_id,
         ctx.accounts.proposer.key(),
         session_account.proposals_count,
         description.to_string(),
+        metadata_uri,
+        metadata_hash,
     );
 
+    // proposals belong to the session main question unless a question account is given
+    if let Some(question_account) = &ctx.accounts.question_account {
+        require!(
+            question_account.session_id == session_account.session_id,
+            VotingError::InvalidQuestionId
+        );
+        proposal_account.question_id = question_account.question_id;
+    }
+
+    let deposit_amount = session_account.proposals_config.deposit_amount;
+    if deposit_amount > 0 {
+        let vault_account = ctx
+            .accounts
+            .vault_account
+            .as_ref()
+            .ok_or(VotingError::MissingDepositAccounts)?;
+
+        match session_account.proposals_config.deposit_mint {
+            None => system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.proposer.to_account_info(),
+                        to: vault_account.to_account_info(),
+                    },
+                ),
+                deposit_amount,
+            )?,
+            Some(mint) => {
+                let (
+                    Some(deposit_mint),
+                    Some(proposer_token_account),
+                    Some(vault_token_account),
+                    Some(token_program),
+                ) = (
+                    &ctx.accounts.deposit_mint,
+                    &ctx.accounts.proposer_token_account,
+                    &ctx.accounts.vault_token_account,
+                    &ctx.accounts.token_program,
+                )
+                else {
+                    return err!(VotingError::MissingDepositAccounts);
+                };
+                require!(deposit_mint.key() == mint, VotingError::InvalidDepositMint);
+
+                transfer_tokens(
+                    token_program,
+                    deposit_mint,
+                    proposer_token_account,
+                    vault_token_account,
+                    ctx.accounts.proposer.to_account_info(),
+                    deposit_amount,
+                )?;
+            }
+        }
+
+        proposal_account.deposit_amount = deposit_amount;
+
+        emit!(ProposalDepositEscrowed {
+            session_id: session_account.session_id,
+            proposal_id: proposal_account.proposal_id,
+            proposer: ctx.accounts.proposer.key(),
+            mint: session_account.proposals_config.deposit_mint,
+            amount: deposit_amount,
+        });
+    }
+
     session_account.proposals_count += 1;
 
     voter_account.nb_proposals += 1;
@@ -82,16 +225,35 @@ pub fn register_internal_proposal<'info>(
     proposer: Pubkey,
     proposal_id: u8,
     description: String,
+    metadata_uri: String,
+    metadata_hash: [u8; 32],
 ) {
     proposal.session_id = session_id;
     proposal.proposal_id = proposal_id;
     proposal.description = description.to_string();
     proposal.proposer = proposer;
     proposal.vote_count = 0;
+    proposal.deposit_amount = 0;
+    proposal.deposit_settled = false;
+    proposal.treasury_transfer = None;
+    proposal.metadata_uri = metadata_uri.clone();
+    proposal.metadata_hash = metadata_hash;
+    proposal.question_id = 0;
+    proposal.score_sum = 0;
+    proposal.score_count = 0;
+    proposal.score_distribution = [0; MAX_SCORE as usize + 1];
+    proposal.encrypted_votes = Ciphertext {
+        c1: IDENTITY,
+        c2: IDENTITY,
+    };
+    proposal.decryption_shares = Vec::new();
+    proposal.votes_revealed = false;
 
     emit!(ProposalRegistered {
         session_id,
         proposal_id,
         description,
+        metadata_uri,
+        metadata_hash,
     });
 }
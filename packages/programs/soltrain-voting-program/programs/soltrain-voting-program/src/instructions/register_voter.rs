@@ -1,7 +1,10 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::*;
-use crate::state::{voter::*, voting_session::*};
+use crate::state::{
+    global::GlobalAccount, question::MAX_QUESTIONS, session_index::*, voter::*, voting_session::*,
+};
+use crate::utils::index_session;
 
 #[derive(Accounts)]
 #[instruction(voter: Pubkey)]
@@ -9,6 +12,12 @@ pub struct RegisterVoterContextData<'info> {
     #[account(mut)]
     pub admin: Signer<'info>,
 
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
     #[account(mut)]
     pub session_account: Account<'info, SessionAccount>,
 
@@ -25,10 +34,45 @@ pub struct RegisterVoterContextData<'info> {
     )]
     pub voter_account: Account<'info, VoterAccount>,
 
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + SessionIndexAccount::INIT_SPACE,
+        seeds = [
+            SessionIndexAccount::VOTER_SEED_PREFIX.as_ref(),
+            voter.key().as_ref()
+        ],
+        bump
+    )]
+    pub voter_session_index: Account<'info, SessionIndexAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + SessionIndexPageAccount::INIT_SPACE,
+        seeds = [
+            SessionIndexAccount::VOTER_SEED_PREFIX.as_ref(),
+            voter.key().as_ref(),
+            &voter_session_index.current_page().to_le_bytes()
+        ],
+        bump
+    )]
+    pub voter_session_index_page: Account<'info, SessionIndexPageAccount>,
+
     pub system_program: Program<'info, System>,
 }
 
-pub fn register_voter(ctx: Context<RegisterVoterContextData>, voter: Pubkey) -> Result<()> {
+pub fn register_voter(
+    ctx: Context<RegisterVoterContextData>,
+    voter: Pubkey,
+    weight: Option<u32>,
+    credits: Option<u32>,
+) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
     let session_account = &mut ctx.accounts.session_account;
     let voter_account = &mut ctx.accounts.voter_account;
 
@@ -40,6 +84,10 @@ pub fn register_voter(ctx: Context<RegisterVoterContextData>, voter: Pubkey) ->
         session_account.status == SessionWorkflowStatus::RegisteringVoters,
         VotingError::UnexpectedSessionStatus
     );
+    require!(
+        session_account.organization_id.is_none(),
+        VotingError::OrganizationSessionVoter
+    );
     require!(
         session_account.admin.key() != voter.key(),
         VotingError::AdminForbiddenAsVoter
@@ -48,18 +96,47 @@ pub fn register_voter(ctx: Context<RegisterVoterContextData>, voter: Pubkey) ->
         voter_account.voter.key() != voter.key(),
         VotingError::VoterAlreadyRegistered
     );
+    require!(
+        session_account.voters_count < ctx.accounts.global_account.config.max_voters,
+        VotingError::MaxVotersReached
+    );
+
+    let weight = weight.unwrap_or(1);
+    require!(weight > 0, VotingError::InvalidVoterWeight);
 
     session_account.voters_count += 1;
+    session_account.voters_weight = session_account
+        .voters_weight
+        .checked_add(weight)
+        .ok_or(VotingError::VotersWeightOverflow)?;
 
     voter_account.session_id = session_account.session_id;
     voter_account.voter = voter;
     voter_account.voter_id = session_account.voters_count;
     voter_account.voted_proposal_id = 0;
     voter_account.nb_proposals = 0;
+    voter_account.question_votes = [0; MAX_QUESTIONS];
+    voter_account.seat_votes = [0; MAX_SEATS];
+    voter_account.credits = credits;
+    voter_account.credits_spent = 0;
+    voter_account.weight = weight;
+    voter_account.nonce = 0;
+    voter_account.identity_commitment = [0; 32];
+    voter_account.reward_claimed = false;
+    voter_account.badge_minted = false;
+    voter_account.power_applied = false;
+
+    index_session(
+        &mut ctx.accounts.voter_session_index,
+        &mut ctx.accounts.voter_session_index_page,
+        voter,
+        session_account.session_id,
+    );
 
     emit!(VoterRegistered {
         session_id: session_account.session_id,
         voter,
+        weight,
     });
     Ok(())
 }
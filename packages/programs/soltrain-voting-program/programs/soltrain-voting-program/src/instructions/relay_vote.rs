@@ -0,0 +1,92 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::sysvar::instructions::{
+        load_current_index_checked, load_instruction_at_checked,
+    },
+};
+
+use crate::{
+    errors::VotingError,
+    instructions::vote::cast_vote,
+    state::{
+        global::GlobalAccount, proposal::*, receipt::ReceiptAccumulatorAccount, voter::*,
+        voting_session::*,
+    },
+    utils::{get_ed25519_signatures, signed_ballot_message},
+};
+
+#[derive(Accounts)]
+pub struct RelayVoteContextData<'info> {
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut)]
+    pub session_account: Account<'info, SessionAccount>,
+
+    #[account(mut)]
+    pub voter_account: Account<'info, VoterAccount>,
+
+    #[account(mut)]
+    pub proposal_account: Account<'info, ProposalAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            ReceiptAccumulatorAccount::SEED_PREFIX.as_ref(),
+            &session_account.session_id.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub receipts_account: Box<Account<'info, ReceiptAccumulatorAccount>>,
+
+    /// CHECK: instructions sysvar, used to read the ed25519 signature verification instruction
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+pub fn relay_vote(ctx: Context<RelayVoteContextData>, nonce: u64) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
+    let session_account = &mut ctx.accounts.session_account;
+    let voter_account = &mut ctx.accounts.voter_account;
+    let proposal_account = &mut ctx.accounts.proposal_account;
+
+    // the ballot signature is verified by the ed25519 program instruction preceding this one
+    let instructions_sysvar = ctx.accounts.instructions_sysvar.to_account_info();
+    let current_index = load_current_index_checked(&instructions_sysvar)?;
+    require!(current_index > 0, VotingError::InvalidSignedBallot);
+    let ed25519_instruction =
+        load_instruction_at_checked(current_index as usize - 1, &instructions_sysvar)?;
+
+    let message = signed_ballot_message(
+        session_account.session_id,
+        proposal_account.proposal_id,
+        nonce,
+    );
+    require!(
+        get_ed25519_signatures(&ed25519_instruction)?.contains(&(voter_account.voter, message)),
+        VotingError::InvalidSignedBallot
+    );
+    require!(
+        nonce == voter_account.nonce,
+        VotingError::InvalidBallotNonce
+    );
+
+    voter_account.nonce += 1;
+
+    cast_vote(
+        session_account,
+        voter_account,
+        proposal_account,
+        &mut ctx.accounts.receipts_account,
+    )
+}
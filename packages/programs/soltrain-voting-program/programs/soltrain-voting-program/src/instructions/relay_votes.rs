@@ -0,0 +1,150 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::sysvar::instructions::{
+        load_current_index_checked, load_instruction_at_checked,
+    },
+};
+
+use crate::{
+    errors::VotingError,
+    instructions::vote::cast_vote,
+    state::{
+        global::GlobalAccount, proposal::*, receipt::ReceiptAccumulatorAccount, voter::*,
+        voting_session::*,
+    },
+    utils::{get_ed25519_signatures, signed_ballot_message},
+};
+
+#[derive(Accounts)]
+pub struct RelayVotesContextData<'info> {
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut)]
+    pub session_account: Account<'info, SessionAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            ReceiptAccumulatorAccount::SEED_PREFIX.as_ref(),
+            &session_account.session_id.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub receipts_account: Box<Account<'info, ReceiptAccumulatorAccount>>,
+
+    /// CHECK: instructions sysvar, used to read the ed25519 signatures verification instruction
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+pub fn relay_votes<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RelayVotesContextData<'info>>,
+    nonces: Vec<u64>,
+) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
+    let session_account = &mut ctx.accounts.session_account;
+
+    // remaining accounts are a voter account and a proposal account per ballot
+    require!(
+        ctx.remaining_accounts.len() == nonces.len() * 2,
+        VotingError::BadBallotAccountsCount
+    );
+
+    // the ballots signatures are verified by the ed25519 program instruction preceding this one
+    let instructions_sysvar = ctx.accounts.instructions_sysvar.to_account_info();
+    let current_index = load_current_index_checked(&instructions_sysvar)?;
+    require!(current_index > 0, VotingError::InvalidSignedBallot);
+    let ed25519_instruction =
+        load_instruction_at_checked(current_index as usize - 1, &instructions_sysvar)?;
+    let signatures = get_ed25519_signatures(&ed25519_instruction)?;
+
+    // a voter may relay a ballot per seat, the same signed ballot is only relayed once
+    let mut ballots: Vec<(Pubkey, u8, u64)> = Vec::with_capacity(nonces.len());
+    // proposals are shared between ballots, they are loaded once and written back at the end
+    let mut proposals: Vec<Account<ProposalAccount>> = Vec::new();
+    let mut skipped_voters: Vec<Pubkey> = Vec::new();
+
+    for (nonce, accounts) in nonces.iter().zip(ctx.remaining_accounts.chunks(2)) {
+        let (voter_info, proposal_info) = (&accounts[0], &accounts[1]);
+        require!(
+            voter_info.is_writable && proposal_info.is_writable,
+            VotingError::BadBallotAccountsCount
+        );
+        let mut voter_account: Account<VoterAccount> = Account::try_from(voter_info)?;
+        let proposal_index = match proposals
+            .iter()
+            .position(|proposal| proposal.key() == proposal_info.key())
+        {
+            Some(index) => index,
+            None => {
+                proposals.push(Account::try_from(proposal_info)?);
+                proposals.len() - 1
+            }
+        };
+        let proposal_account = &mut proposals[proposal_index];
+
+        let ballot = (voter_info.key(), proposal_account.proposal_id, *nonce);
+        require!(
+            !ballots.contains(&ballot),
+            VotingError::DuplicateSignedBallot
+        );
+        ballots.push(ballot);
+
+        let message = signed_ballot_message(
+            session_account.session_id,
+            proposal_account.proposal_id,
+            *nonce,
+        );
+        require!(
+            signatures.contains(&(voter_account.voter, message)),
+            VotingError::InvalidSignedBallot
+        );
+
+        match cast_vote(
+            session_account,
+            &mut voter_account,
+            proposal_account,
+            &mut ctx.accounts.receipts_account,
+        ) {
+            Ok(()) => {
+                require!(
+                    *nonce == voter_account.nonce,
+                    VotingError::InvalidBallotNonce
+                );
+                voter_account.nonce += 1;
+                voter_account.exit(&crate::ID)?;
+            }
+            // already voted ballots are reported instead of failing the whole batch
+            Err(error)
+                if error == VotingError::VoterAlreadyVoted.into()
+                    || error == VotingError::ProposalAlreadyVoted.into() =>
+            {
+                skipped_voters.push(voter_account.voter);
+            }
+            Err(error) => return Err(error),
+        }
+    }
+
+    for proposal_account in proposals.iter() {
+        proposal_account.exit(&crate::ID)?;
+    }
+
+    emit!(SignedBallotsRelayed {
+        session_id: session_account.session_id,
+        relayed_count: (nonces.len() - skipped_voters.len()) as u32,
+        skipped_voters,
+    });
+
+    Ok(())
+}
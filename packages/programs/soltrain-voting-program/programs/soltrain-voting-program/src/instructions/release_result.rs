@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::VotingError,
+    state::{global::GlobalAccount, voting_session::*},
+};
+
+#[derive(Accounts)]
+pub struct ReleaseResultContextData<'info> {
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut)]
+    pub session_account: Account<'info, SessionAccount>,
+}
+
+pub fn release_result(ctx: Context<ReleaseResultContextData>) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
+    let session_account = &mut ctx.accounts.session_account;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        session_account.status == SessionWorkflowStatus::VotesTallied,
+        VotingError::UnexpectedSessionStatus
+    );
+    require!(
+        session_account.released_at == 0,
+        VotingError::ResultAlreadyReleased
+    );
+    require!(
+        now >= session_account.execution_eta,
+        VotingError::TimelockNotElapsed
+    );
+
+    session_account.released_at = now;
+
+    emit!(ResultReleased {
+        session_id: session_account.session_id,
+        released_at: now,
+    });
+
+    Ok(())
+}
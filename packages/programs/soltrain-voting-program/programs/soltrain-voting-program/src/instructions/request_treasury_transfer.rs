@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::VotingError,
+    state::{global::GlobalAccount, proposal::ProposalAccount, treasury::*, voting_session::*},
+};
+
+#[derive(Accounts)]
+pub struct RequestTreasuryTransferContextData<'info> {
+    pub proposer: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    pub session_account: Account<'info, SessionAccount>,
+
+    #[account(mut)]
+    pub proposal_account: Account<'info, ProposalAccount>,
+}
+
+pub fn request_treasury_transfer(
+    ctx: Context<RequestTreasuryTransferContextData>,
+    recipient: Pubkey,
+    mint: Option<Pubkey>,
+    amount: u64,
+) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
+    let session_account = &ctx.accounts.session_account;
+    let proposal_account = &mut ctx.accounts.proposal_account;
+
+    require!(
+        session_account.status == SessionWorkflowStatus::ProposalsRegistrationStarted,
+        VotingError::UnexpectedSessionStatus
+    );
+    require!(
+        proposal_account.session_id == session_account.session_id,
+        VotingError::InvalidProposalId
+    );
+    require!(
+        proposal_account.proposer == ctx.accounts.proposer.key(),
+        VotingError::UnexpectedVoter
+    );
+    require!(
+        proposal_account.treasury_transfer.is_none(),
+        VotingError::TreasuryTransferAlreadyRequested
+    );
+    require!(amount > 0, VotingError::InvalidAmount);
+
+    proposal_account.treasury_transfer = Some(TreasuryTransfer {
+        recipient,
+        mint,
+        amount,
+        executed: false,
+    });
+
+    emit!(TreasuryTransferRequested {
+        session_id: session_account.session_id,
+        proposal_id: proposal_account.proposal_id,
+        recipient,
+        mint,
+        amount,
+    });
+
+    Ok(())
+}
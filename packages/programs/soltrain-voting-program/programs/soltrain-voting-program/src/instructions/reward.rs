@@ -0,0 +1,403 @@
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, CloseAccount, Mint, Token, TokenAccount},
+};
+
+use crate::{
+    errors::VotingError,
+    state::{global::GlobalAccount, reward::*, voter::VoterAccount, voting_session::*},
+    utils::{transfer_tokens, transfer_tokens_signed},
+};
+
+#[derive(Accounts)]
+pub struct FundRewardPoolContextData<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    pub session_account: Account<'info, SessionAccount>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + RewardPoolAccount::INIT_SPACE,
+        seeds = [
+            RewardPoolAccount::SEED_PREFIX.as_ref(),
+            &session_account.session_id.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub reward_pool_account: Account<'info, RewardPoolAccount>,
+
+    pub mint: Option<Account<'info, Mint>>,
+
+    #[account(mut, token::mint = mint, token::authority = admin)]
+    pub admin_token_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = mint,
+        associated_token::authority = reward_pool_account,
+    )]
+    pub pool_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimRewardContextData<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    pub session_account: Account<'info, SessionAccount>,
+
+    #[account(mut)]
+    pub voter_account: Account<'info, VoterAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            RewardPoolAccount::SEED_PREFIX.as_ref(),
+            &session_account.session_id.to_le_bytes(),
+        ],
+        bump = reward_pool_account.bump
+    )]
+    pub reward_pool_account: Account<'info, RewardPoolAccount>,
+
+    pub mint: Option<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = reward_pool_account,
+    )]
+    pub pool_token_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(mut, token::mint = mint, token::authority = voter)]
+    pub voter_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+#[derive(Accounts)]
+pub struct ReclaimRewardPoolContextData<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    pub session_account: Account<'info, SessionAccount>,
+
+    // remaining SOL rewards are returned along with the account rent
+    #[account(
+        mut,
+        seeds = [
+            RewardPoolAccount::SEED_PREFIX.as_ref(),
+            &session_account.session_id.to_le_bytes(),
+        ],
+        bump = reward_pool_account.bump,
+        close = funder
+    )]
+    pub reward_pool_account: Account<'info, RewardPoolAccount>,
+
+    pub mint: Option<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = reward_pool_account,
+    )]
+    pub pool_token_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(mut, token::mint = mint, token::authority = funder)]
+    pub funder_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+pub fn fund_reward_pool(
+    ctx: Context<FundRewardPoolContextData>,
+    amount: u64,
+    reward_per_voter: u64,
+    claim_duration: i64,
+) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
+    let session_account = &ctx.accounts.session_account;
+    let reward_pool_account = &mut ctx.accounts.reward_pool_account;
+
+    require!(
+        session_account.admin.key() == ctx.accounts.admin.key(),
+        VotingError::ForbiddenAsNonAdmin
+    );
+    // the pool is funded before the result is known
+    require!(
+        matches!(
+            session_account.status,
+            SessionWorkflowStatus::RegisteringVoters
+                | SessionWorkflowStatus::ProposalsRegistrationStarted
+                | SessionWorkflowStatus::ProposalsRegistrationEnded
+                | SessionWorkflowStatus::VotingSessionStarted
+                | SessionWorkflowStatus::VotingSessionEnded
+        ),
+        VotingError::UnexpectedSessionStatus
+    );
+    require!(
+        amount > 0 && reward_per_voter > 0 && reward_per_voter <= amount,
+        VotingError::InvalidAmount
+    );
+    require!(
+        (1..=MAX_CLAIM_DURATION).contains(&claim_duration),
+        VotingError::InvalidClaimDuration
+    );
+
+    // SOL rewards when no mint is given
+    let mint = match &ctx.accounts.mint {
+        None => {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.admin.to_account_info(),
+                        to: reward_pool_account.to_account_info(),
+                    },
+                ),
+                amount,
+            )?;
+            None
+        }
+        Some(mint) => {
+            let (Some(admin_token_account), Some(pool_token_account), Some(token_program)) = (
+                &ctx.accounts.admin_token_account,
+                &ctx.accounts.pool_token_account,
+                &ctx.accounts.token_program,
+            ) else {
+                return err!(VotingError::MissingTokenAccounts);
+            };
+
+            transfer_tokens(
+                token_program,
+                mint,
+                admin_token_account,
+                pool_token_account,
+                ctx.accounts.admin.to_account_info(),
+                amount,
+            )?;
+            Some(mint.key())
+        }
+    };
+
+    reward_pool_account.session_id = session_account.session_id;
+    reward_pool_account.funder = ctx.accounts.admin.key();
+    reward_pool_account.mint = mint;
+    reward_pool_account.reward_per_voter = reward_per_voter;
+    reward_pool_account.funded_amount = amount;
+    reward_pool_account.claimed_amount = 0;
+    reward_pool_account.claims_count = 0;
+    reward_pool_account.claim_duration = claim_duration;
+    reward_pool_account.bump = ctx.bumps.reward_pool_account;
+
+    emit!(RewardPoolFunded {
+        session_id: session_account.session_id,
+        funder: reward_pool_account.funder,
+        mint,
+        amount,
+        reward_per_voter,
+        claim_duration,
+    });
+
+    Ok(())
+}
+
+pub fn claim_reward(ctx: Context<ClaimRewardContextData>) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
+    let session_account = &ctx.accounts.session_account;
+    let voter_account = &mut ctx.accounts.voter_account;
+    let reward_pool_account = &mut ctx.accounts.reward_pool_account;
+
+    require!(
+        session_account.status == SessionWorkflowStatus::VotesTallied,
+        VotingError::UnexpectedSessionStatus
+    );
+    require!(
+        voter_account.voter.key() == ctx.accounts.voter.key()
+            && voter_account.session_id == session_account.session_id,
+        VotingError::UnexpectedVoter
+    );
+    require!(voter_account.has_voted, VotingError::VoterDidNotVote);
+    require!(
+        !voter_account.reward_claimed,
+        VotingError::RewardAlreadyClaimed
+    );
+    require!(
+        Clock::get()?.unix_timestamp
+            <= reward_pool_account.claim_deadline(session_account.tallied_at),
+        VotingError::ClaimPeriodEnded
+    );
+
+    let amount = reward_pool_account.reward_per_voter;
+    require!(
+        reward_pool_account.funded_amount - reward_pool_account.claimed_amount >= amount,
+        VotingError::InsufficientRewardFunds
+    );
+
+    match reward_pool_account.mint {
+        None => {
+            reward_pool_account.sub_lamports(amount)?;
+            ctx.accounts.voter.add_lamports(amount)?;
+        }
+        Some(mint) => {
+            let (
+                Some(mint_account),
+                Some(pool_token_account),
+                Some(voter_token_account),
+                Some(token_program),
+            ) = (
+                &ctx.accounts.mint,
+                &ctx.accounts.pool_token_account,
+                &ctx.accounts.voter_token_account,
+                &ctx.accounts.token_program,
+            )
+            else {
+                return err!(VotingError::MissingTokenAccounts);
+            };
+            require!(mint_account.key() == mint, VotingError::InvalidMint);
+
+            let session_id = session_account.session_id.to_le_bytes();
+            let signer_seeds: &[&[&[u8]]] = &[&[
+                RewardPoolAccount::SEED_PREFIX.as_ref(),
+                &session_id,
+                &[reward_pool_account.bump],
+            ]];
+            transfer_tokens_signed(
+                token_program,
+                mint_account,
+                pool_token_account,
+                voter_token_account,
+                reward_pool_account.to_account_info(),
+                signer_seeds,
+                amount,
+            )?;
+        }
+    }
+
+    voter_account.reward_claimed = true;
+    reward_pool_account.claimed_amount += amount;
+    reward_pool_account.claims_count += 1;
+
+    emit!(RewardClaimed {
+        session_id: session_account.session_id,
+        voter: voter_account.voter,
+        mint: reward_pool_account.mint,
+        amount,
+    });
+
+    Ok(())
+}
+
+pub fn reclaim_reward_pool(ctx: Context<ReclaimRewardPoolContextData>) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
+    let session_account = &ctx.accounts.session_account;
+    let reward_pool_account = &ctx.accounts.reward_pool_account;
+
+    require!(
+        reward_pool_account.funder == ctx.accounts.funder.key(),
+        VotingError::ForbiddenAsNonFunder
+    );
+    // voters claim from the tally until the claim deadline, vetoed results paying no reward
+    match session_account.status {
+        SessionWorkflowStatus::VotesTallied => require!(
+            Clock::get()?.unix_timestamp
+                > reward_pool_account.claim_deadline(session_account.tallied_at),
+            VotingError::ClaimPeriodNotEnded
+        ),
+        SessionWorkflowStatus::Vetoed => {}
+        _ => return err!(VotingError::UnexpectedSessionStatus),
+    }
+
+    let amount = reward_pool_account.funded_amount - reward_pool_account.claimed_amount;
+
+    if let Some(mint) = reward_pool_account.mint {
+        let (
+            Some(mint_account),
+            Some(pool_token_account),
+            Some(funder_token_account),
+            Some(token_program),
+        ) = (
+            &ctx.accounts.mint,
+            &ctx.accounts.pool_token_account,
+            &ctx.accounts.funder_token_account,
+            &ctx.accounts.token_program,
+        )
+        else {
+            return err!(VotingError::MissingTokenAccounts);
+        };
+        require!(mint_account.key() == mint, VotingError::InvalidMint);
+
+        let session_id = session_account.session_id.to_le_bytes();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            RewardPoolAccount::SEED_PREFIX.as_ref(),
+            &session_id,
+            &[reward_pool_account.bump],
+        ]];
+        transfer_tokens_signed(
+            token_program,
+            mint_account,
+            pool_token_account,
+            funder_token_account,
+            reward_pool_account.to_account_info(),
+            signer_seeds,
+            pool_token_account.amount,
+        )?;
+        token::close_account(CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            CloseAccount {
+                account: pool_token_account.to_account_info(),
+                destination: ctx.accounts.funder.to_account_info(),
+                authority: reward_pool_account.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+    }
+
+    emit!(RewardPoolReclaimed {
+        session_id: session_account.session_id,
+        funder: reward_pool_account.funder,
+        mint: reward_pool_account.mint,
+        amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,197 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::{
+    errors::VotingError,
+    state::{
+        global::GlobalAccount, pairwise::PairwiseMatrixAccount, proposal::ProposalAccount,
+        question::QuestionAccount, treasury::TreasuryAccount, vault::*, voting_session::*,
+    },
+    utils::transfer_tokens_signed,
+};
+
+#[derive(Accounts)]
+pub struct SettleProposalDepositContextData<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    pub session_account: Account<'info, SessionAccount>,
+
+    #[account(mut)]
+    pub proposal_account: Account<'info, ProposalAccount>,
+
+    // ranked ballots support, only used in condorcet mode
+    pub pairwise_account: Option<Box<Account<'info, PairwiseMatrixAccount>>>,
+
+    // question of the proposal, only used for proposals of added questions
+    pub question_account: Option<Account<'info, QuestionAccount>>,
+
+    /// CHECK: refund receiver, checked against proposal proposer
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            VaultAccount::SEED_PREFIX.as_ref(),
+            &session_account.session_id.to_le_bytes(),
+        ],
+        bump = vault_account.bump
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + TreasuryAccount::INIT_SPACE,
+        seeds = [
+            TreasuryAccount::SEED_PREFIX.as_ref(),
+            &session_account.session_id.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub treasury_account: Account<'info, TreasuryAccount>,
+
+    pub deposit_mint: Option<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = deposit_mint,
+        associated_token::authority = vault_account,
+    )]
+    pub vault_token_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(mut, token::mint = deposit_mint, token::authority = proposer)]
+    pub proposer_token_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = deposit_mint,
+        associated_token::authority = treasury_account,
+    )]
+    pub treasury_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn settle_proposal_deposit(ctx: Context<SettleProposalDepositContextData>) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
+    let session_account = &ctx.accounts.session_account;
+    let proposal_account = &mut ctx.accounts.proposal_account;
+    let vault_account = &ctx.accounts.vault_account;
+    let treasury_account = &mut ctx.accounts.treasury_account;
+
+    // deposits are settled once the result is released, or vetoed
+    require!(
+        (session_account.status == SessionWorkflowStatus::VotesTallied
+            && session_account.released_at > 0)
+            || session_account.status == SessionWorkflowStatus::Vetoed,
+        VotingError::UnexpectedSessionStatus
+    );
+    require!(
+        proposal_account.session_id == session_account.session_id,
+        VotingError::InvalidProposalId
+    );
+    require!(
+        proposal_account.proposer == ctx.accounts.proposer.key(),
+        VotingError::UnexpectedVoter
+    );
+    require!(
+        !proposal_account.deposit_settled && proposal_account.deposit_amount > 0,
+        VotingError::DepositAlreadySettled
+    );
+
+    treasury_account.session_id = session_account.session_id;
+    treasury_account.bump = ctx.bumps.treasury_account;
+
+    // refund when the proposal support share in its question reached the configured threshold
+    let amount = proposal_account.deposit_amount;
+    let pairwise = ctx
+        .accounts
+        .pairwise_account
+        .as_deref()
+        .map(|account| &**account);
+    let (support, total) = session_account.proposal_support(
+        proposal_account,
+        ctx.accounts.question_account.as_deref(),
+        pairwise,
+    )?;
+    let refunded = session_account
+        .proposals_config
+        .deposit_refunded(support, total);
+
+    match session_account.proposals_config.deposit_mint {
+        None => {
+            let receiver = if refunded {
+                ctx.accounts.proposer.to_account_info()
+            } else {
+                treasury_account.to_account_info()
+            };
+            vault_account.sub_lamports(amount)?;
+            receiver.add_lamports(amount)?;
+        }
+        Some(mint) => {
+            let (Some(deposit_mint), Some(vault_token_account), Some(token_program)) = (
+                &ctx.accounts.deposit_mint,
+                &ctx.accounts.vault_token_account,
+                &ctx.accounts.token_program,
+            ) else {
+                return err!(VotingError::MissingDepositAccounts);
+            };
+            require!(deposit_mint.key() == mint, VotingError::InvalidDepositMint);
+
+            let receiver = if refunded {
+                &ctx.accounts.proposer_token_account
+            } else {
+                &ctx.accounts.treasury_token_account
+            }
+            .as_ref()
+            .ok_or(VotingError::MissingDepositAccounts)?;
+
+            let session_id = session_account.session_id.to_le_bytes();
+            let signer_seeds: &[&[&[u8]]] = &[&[
+                VaultAccount::SEED_PREFIX.as_ref(),
+                &session_id,
+                &[vault_account.bump],
+            ]];
+            transfer_tokens_signed(
+                token_program,
+                deposit_mint,
+                vault_token_account,
+                receiver,
+                vault_account.to_account_info(),
+                signer_seeds,
+                amount,
+            )?;
+        }
+    }
+
+    proposal_account.deposit_settled = true;
+
+    emit!(ProposalDepositSettled {
+        session_id: session_account.session_id,
+        proposal_id: proposal_account.proposal_id,
+        refunded,
+        amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::VotingError,
+    state::{global::GlobalAccount, lock::*, snapshot::*, voter::*, voting_session::*},
+};
+
+#[derive(Accounts)]
+pub struct SnapshotVotingPowerContextData<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    pub session_account: Account<'info, SessionAccount>,
+
+    pub voter_account: Account<'info, VoterAccount>,
+
+    #[account(
+        seeds = [
+            LockAccount::SEED_PREFIX.as_ref(),
+            lock_account.mint.as_ref(),
+            voter.key().as_ref()
+        ],
+        bump = lock_account.bump
+    )]
+    pub lock_account: Account<'info, LockAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = voter,
+        space = 8 + VoterSnapshotAccount::INIT_SPACE,
+        seeds = [
+            VoterSnapshotAccount::SEED_PREFIX.as_ref(),
+            &session_account.session_id.to_le_bytes(),
+            voter.key().as_ref()
+        ],
+        bump
+    )]
+    pub snapshot_account: Account<'info, VoterSnapshotAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn snapshot_voting_power(ctx: Context<SnapshotVotingPowerContextData>) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
+    let session_account = &ctx.accounts.session_account;
+    let voter_account = &ctx.accounts.voter_account;
+    let lock_account = &ctx.accounts.lock_account;
+    let snapshot_account = &mut ctx.accounts.snapshot_account;
+
+    require!(
+        session_account.lock_mint == Some(lock_account.mint),
+        VotingError::InvalidMint
+    );
+    // snapshots can be refreshed until voting opens
+    require!(
+        matches!(
+            session_account.status,
+            SessionWorkflowStatus::RegisteringVoters
+                | SessionWorkflowStatus::ProposalsRegistrationStarted
+                | SessionWorkflowStatus::ProposalsRegistrationEnded
+        ),
+        VotingError::UnexpectedSessionStatus
+    );
+    require!(
+        voter_account.voter.key() == ctx.accounts.voter.key()
+            && voter_account.session_id == session_account.session_id,
+        VotingError::UnexpectedVoter
+    );
+
+    // escrowed tokens only leave the lock once expired, so a snapshot can not be double counted
+    snapshot_account.session_id = session_account.session_id;
+    snapshot_account.voter = voter_account.voter;
+    snapshot_account.slot = Clock::get()?.slot;
+    snapshot_account.amount = lock_account.amount;
+    snapshot_account.unlock_at = lock_account.unlock_at;
+
+    emit!(VoterSnapshotTaken {
+        session_id: session_account.session_id,
+        voter: snapshot_account.voter,
+        slot: snapshot_account.slot,
+        amount: snapshot_account.amount,
+        unlock_at: snapshot_account.unlock_at,
+    });
+
+    Ok(())
+}
@@ -3,7 +3,7 @@ use anchor_lang::prelude::*;
 use crate::{
     errors::VotingError,
     instructions::register_proposal::*,
-    state::{proposal::ProposalAccount, voting_session::*},
+    state::{global::GlobalAccount, proposal::ProposalAccount, voting_session::*},
 };
 
 #[derive(Accounts)]
@@ -11,6 +11,12 @@ pub struct StartProposalRegistrationContextData<'info> {
     #[account(mut)]
     pub admin: Signer<'info>,
 
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
     #[account(mut)]
     pub session_account: Account<'info, SessionAccount>,
 
@@ -32,6 +38,11 @@ pub struct StartProposalRegistrationContextData<'info> {
 pub fn start_proposals_registration(
     ctx: Context<StartProposalRegistrationContextData>,
 ) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
     let session_account = &mut ctx.accounts.session_account;
     let blank_proposal_account = &mut ctx.accounts.blank_proposal_account;
 
@@ -50,6 +61,8 @@ pub fn start_proposals_registration(
         session_account.admin,
         1,
         "blank".to_string(),
+        String::new(),
+        [0; 32],
     );
 
     session_account.proposals_count += 1;
@@ -1,19 +1,45 @@
 use anchor_lang::prelude::*;
 
-use crate::{errors::VotingError, state::voting_session::*};
+use crate::{
+    errors::VotingError,
+    state::{global::GlobalAccount, receipt::ReceiptAccumulatorAccount, voting_session::*},
+};
 
 #[derive(Accounts)]
 pub struct StartVotingSessionContextData<'info> {
     #[account(mut)]
     pub admin: Signer<'info>,
 
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
     #[account(mut)]
     pub session_account: Account<'info, SessionAccount>,
 
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + ReceiptAccumulatorAccount::INIT_SPACE,
+        seeds = [
+            ReceiptAccumulatorAccount::SEED_PREFIX.as_ref(),
+            &session_account.session_id.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub receipts_account: Box<Account<'info, ReceiptAccumulatorAccount>>,
+
     pub system_program: Program<'info, System>,
 }
 
 pub fn start_voting_session(ctx: Context<StartVotingSessionContextData>) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
     let session_account = &mut ctx.accounts.session_account;
 
     require!(
@@ -27,6 +53,12 @@ pub fn start_voting_session(ctx: Context<StartVotingSessionContextData>) -> Resu
     );
 
     session_account.status = SessionWorkflowStatus::VotingSessionStarted;
+    let clock = Clock::get()?;
+    session_account.voting_started_at = clock.unix_timestamp;
+    session_account.snapshot_slot = clock.slot;
+    ctx.accounts
+        .receipts_account
+        .initialize(session_account.session_id);
 
     emit!(SessionWorkflowStatusChanged {
         session_id: session_account.session_id,
@@ -1,12 +1,21 @@
 use anchor_lang::prelude::*;
 
-use crate::{errors::VotingError, state::voting_session::*};
+use crate::{
+    errors::VotingError,
+    state::{global::GlobalAccount, voting_session::*},
+};
 
 #[derive(Accounts)]
 pub struct StopProposalRegistrationContextData<'info> {
     #[account(mut)]
     pub admin: Signer<'info>,
 
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
     #[account(mut)]
     pub session_account: Account<'info, SessionAccount>,
 
@@ -16,6 +25,11 @@ pub struct StopProposalRegistrationContextData<'info> {
 pub fn stop_proposals_registration(
     ctx: Context<StopProposalRegistrationContextData>,
 ) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
     let session_account = &mut ctx.accounts.session_account;
 
     require!(
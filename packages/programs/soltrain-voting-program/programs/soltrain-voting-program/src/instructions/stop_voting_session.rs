@@ -1,12 +1,21 @@
 use anchor_lang::prelude::*;
 
-use crate::{errors::VotingError, state::voting_session::*};
+use crate::{
+    errors::VotingError,
+    state::{global::GlobalAccount, voting_session::*},
+};
 
 #[derive(Accounts)]
 pub struct StopVotingSessionContextData<'info> {
     #[account(mut)]
     pub admin: Signer<'info>,
 
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
     #[account(mut)]
     pub session_account: Account<'info, SessionAccount>,
 
@@ -14,6 +23,11 @@ pub struct StopVotingSessionContextData<'info> {
 }
 
 pub fn stop_voting_session(ctx: Context<StopVotingSessionContextData>) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
     let session_account = &mut ctx.accounts.session_account;
 
     require!(
@@ -1,10 +1,13 @@
-use std::collections::HashMap;
+use std::cmp::Ordering;
 
 use anchor_lang::{prelude::*, solana_program::log::sol_log_compute_units};
 
 use crate::{
     errors::VotingError,
-    state::{proposal::*, voting_session::*},
+    state::{
+        global::GlobalAccount, pairwise::*, proposal::*, question::*,
+        receipt::ReceiptAccumulatorAccount, voting_session::*,
+    },
 };
 
 #[derive(Accounts)]
@@ -12,15 +15,38 @@ pub struct TallyVotesContextData<'info> {
     #[account(mut)]
     pub admin: Signer<'info>,
 
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
     #[account(mut)]
     pub session_account: Account<'info, SessionAccount>,
 
+    // created by the first ranked ballot, only used in condorcet mode
+    pub pairwise_account: Option<Box<Account<'info, PairwiseMatrixAccount>>>,
+
+    #[account(
+        seeds = [
+            ReceiptAccumulatorAccount::SEED_PREFIX.as_ref(),
+            &session_account.session_id.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub receipts_account: Box<Account<'info, ReceiptAccumulatorAccount>>,
+
     pub system_program: Program<'info, System>,
 }
 
 pub fn tally_votes<'info>(
     ctx: Context<'_, '_, 'info, 'info, TallyVotesContextData<'info>>,
 ) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
     let session_account = &mut ctx.accounts.session_account;
 
     require!(
@@ -29,63 +55,189 @@ pub fn tally_votes<'info>(
     );
 
     let voters_count = session_account.voters_count;
+    let voters_weight = session_account.voters_weight;
     let proposals_count = session_account.proposals_count as usize - 1;
+    let questions_count = session_account.questions_count as usize;
     let remaining_accounts = &ctx.remaining_accounts;
 
+    // proposal accounts first, then the added question accounts (question 0 is implicit)
     require!(
-        remaining_accounts.len() == proposals_count,
+        remaining_accounts.len() == proposals_count + questions_count - 1,
         VotingError::BadProposalAccountsCount
     );
 
-    let proposal_accounts = &ctx.remaining_accounts[..proposals_count];
+    let proposal_accounts = &remaining_accounts[..proposals_count];
+    let question_accounts = &remaining_accounts[proposals_count..];
+
+    // blank proposal of the main question is always the first one
+    let mut blank_proposal_ids: Vec<u8> = vec![1];
+    let mut added_questions: Vec<Account<QuestionAccount>> = Vec::new();
+    for (index, question_account) in question_accounts.iter().enumerate() {
+        // added questions results are stored in their question account
+        require!(question_account.is_writable, VotingError::InvalidQuestionId);
+        let question: Account<QuestionAccount> = Account::try_from(question_account)?;
+        require!(
+            question.session_id == session_account.session_id
+                && question.question_id as usize == index + 1,
+            VotingError::InvalidQuestionId
+        );
+        blank_proposal_ids.push(question.blank_proposal_id);
+        added_questions.push(question);
+    }
 
-    let mut total_votes = 0;
-    let mut blank_votes = 0;
-    let mut votes_count: HashMap<u8, u32> = HashMap::new();
+    let mut questions: Vec<QuestionResult> = (0..questions_count)
+        .map(|question_id| QuestionResult {
+            question_id: question_id as u8,
+            total_votes: 0,
+            blank_votes: 0,
+            abstention: 0,
+            winning_proposals: Vec::new(),
+            tied_proposals: Vec::new(),
+        })
+        .collect();
+    let mut votes_count: Vec<Vec<(u8, u32)>> = vec![Vec::new(); questions_count];
+    let mut scores: Vec<(u8, u64)> = Vec::new();
+    let mut score_distribution: Vec<u32> = Vec::new();
+    if let VotingMode::Score { max_score } = session_account.voting_mode {
+        score_distribution = vec![0; max_score as usize + 1];
+    }
 
-    for proposal_account in proposal_accounts.iter() {
+    for (index, proposal_account) in proposal_accounts.iter().enumerate() {
         let proposal: Account<ProposalAccount> = Account::try_from(proposal_account)?;
+        require!(
+            proposal.session_id == session_account.session_id
+                && proposal.proposal_id as usize == index + 1,
+            VotingError::InvalidProposalId
+        );
+        // encrypted votes count is only known once decrypted by the trustees
+        require!(
+            session_account.encryption.is_none() || proposal.votes_revealed,
+            VotingError::VotesNotRevealed
+        );
+        let question_id = proposal.question_id as usize;
+        require!(
+            question_id < questions_count,
+            VotingError::InvalidQuestionId
+        );
         let vote_count = proposal.vote_count;
         if vote_count > 0 {
-            if proposal.proposal_id == 1 {
-                blank_votes += vote_count;
+            let question = &mut questions[question_id];
+            if proposal.proposal_id == blank_proposal_ids[question_id] {
+                question.blank_votes += vote_count;
             } else {
-                votes_count.insert(proposal.proposal_id, vote_count);
+                votes_count[question_id].push((proposal.proposal_id, vote_count));
+            }
+            question.total_votes += vote_count;
+        }
+        if proposal.score_count > 0 {
+            scores.push((proposal.proposal_id, proposal.score_sum));
+            for (total, count) in score_distribution
+                .iter_mut()
+                .zip(proposal.score_distribution.iter())
+            {
+                *total += count;
             }
-            total_votes += vote_count;
         }
     }
-    let abstention = voters_count - total_votes;
 
-    // sort to order by votes count
-    let mut winning_proposals: Vec<(u8, u32)> = votes_count.into_iter().collect();
-    winning_proposals.sort_by(|a, b| b.1.cmp(&a.1));
+    let mut main_votes_count = Vec::new();
+    for (question, votes_count) in questions.iter_mut().zip(votes_count) {
+        if question.question_id == 0 {
+            main_votes_count = votes_count;
+        } else {
+            question.abstention = voters_weight - question.total_votes;
+            (question.winning_proposals, question.tied_proposals) =
+                elect(votes_count, 1, |a, b| a.cmp(b));
+        }
+    }
 
-    // keep max votes count
-    let max_votes = winning_proposals
-        .first()
-        .map(|&(_, votes)| votes)
-        .unwrap_or(0);
+    // main question elects the session seats, according to the voting mode
+    let seats = session_account.seats as usize;
+    let mut has_condorcet_winner = false;
+    // voters may have many votes on the main question, so abstention is based on ballots
+    questions[0].abstention = voters_weight - session_account.ballots_weight;
+    let (winning_proposals, tied_proposals) = match session_account.voting_mode {
+        VotingMode::Plurality | VotingMode::Quadratic { .. } | VotingMode::Cumulative { .. } => {
+            elect(main_votes_count, seats, |a, b| a.cmp(b))
+        }
+        VotingMode::Score { .. } => {
+            questions[0].total_votes = session_account.ballots_weight;
 
-    let winning_proposals: Vec<u8> = winning_proposals
-        .iter()
-        .filter(|&&(_, votes)| votes == max_votes)
-        .map(|&(id, _)| id)
-        .collect();
+            // a proposal left unscored by a ballot is scored 0, so means are taken over the
+            // same ballots weight for every proposal and score sums give the same ranking
+            let scores = scores.into_iter().filter(|&(_, sum)| sum > 0).collect();
+            elect(scores, seats, |a, b| a.cmp(b))
+        }
+        VotingMode::Condorcet => {
+            questions[0].total_votes = session_account.ballots_weight;
+
+            match &ctx.accounts.pairwise_account {
+                Some(pairwise_account) => {
+                    require!(
+                        pairwise_account.session_id == session_account.session_id,
+                        VotingError::InvalidPairwiseAccount
+                    );
+                    let (ranking, condorcet_winner) = get_schulze_ranking(pairwise_account);
+                    has_condorcet_winner = condorcet_winner;
+                    elect(ranking, seats, |a, b| a.cmp(b))
+                }
+                None => {
+                    require!(
+                        session_account.ballots_count == 0,
+                        VotingError::InvalidPairwiseAccount
+                    );
+                    (Vec::new(), Vec::new())
+                }
+            }
+        }
+    };
+    questions[0].winning_proposals = winning_proposals;
+    questions[0].tied_proposals = tied_proposals.clone();
+
+    let main_question = questions[0].clone();
+    for (question, result) in added_questions.iter_mut().zip(questions.iter().skip(1)) {
+        question.result = result.clone();
+        question.exit(&crate::ID)?;
+    }
 
     session_account.status = SessionWorkflowStatus::VotesTallied;
-    session_account.result.total_votes = total_votes;
-    session_account.result.blank_votes = blank_votes;
-    session_account.result.abstention = abstention;
-    session_account.result.winning_proposals = winning_proposals.clone();
+    session_account.tallied_at = Clock::get()?.unix_timestamp;
+    session_account.execution_eta = session_account
+        .tallied_at
+        .checked_add(session_account.execution_delay)
+        .ok_or(VotingError::InvalidExecutionConfig)?;
+    // without timelock there is no veto window, the result is released right away
+    if session_account.execution_delay == 0 {
+        session_account.released_at = session_account.tallied_at;
+    }
+    session_account.result.total_votes = main_question.total_votes;
+    session_account.result.blank_votes = main_question.blank_votes;
+    session_account.result.abstention = main_question.abstention;
+    session_account.result.winning_proposals = main_question.winning_proposals.clone();
+    session_account.result.score_distribution = score_distribution.clone();
+    session_account.result.has_condorcet_winner = has_condorcet_winner;
+    session_account.result.tied_proposals = tied_proposals.clone();
+    session_account.result.voted_count = session_account.ballots_count;
+    session_account.result.abstention_count = voters_count - session_account.ballots_count;
+    // votes can not be added anymore, the receipts root is final
+    session_account.result.receipts_root = ctx.accounts.receipts_account.root;
+    session_account.result.receipts_count = ctx.accounts.receipts_account.receipts_count;
 
     emit!(VotesTallied {
         session_id: session_account.session_id,
         voters_count,
-        total_votes,
-        blank_votes,
-        abstention,
-        winning_proposals,
+        total_votes: main_question.total_votes,
+        blank_votes: main_question.blank_votes,
+        abstention: main_question.abstention,
+        winning_proposals: main_question.winning_proposals,
+        questions,
+        score_distribution,
+        has_condorcet_winner,
+        tied_proposals,
+        voted_count: session_account.result.voted_count,
+        abstention_count: session_account.result.abstention_count,
+        receipts_root: session_account.result.receipts_root,
+        receipts_count: session_account.result.receipts_count,
     });
 
     emit!(SessionWorkflowStatusChanged {
@@ -96,7 +248,177 @@ pub fn tally_votes<'info>(
 
     session_account.status = SessionWorkflowStatus::VotesTallied;
 
+    emit!(ResultQueued {
+        session_id: session_account.session_id,
+        tallied_at: session_account.tallied_at,
+        execution_eta: session_account.execution_eta,
+    });
+
+    if session_account.released_at > 0 {
+        emit!(ResultReleased {
+            session_id: session_account.session_id,
+            released_at: session_account.released_at,
+        });
+    }
+
     sol_log_compute_units();
 
     Ok(())
 }
+
+/// Elects the `seats` best ranked proposals, best first, proposals being compared with `cmp`.
+/// Proposals tied at the last seat are not elected and are returned apart, in proposal order,
+/// so that a single seat tied at the top elects nobody.
+fn elect<T>(
+    mut ranking: Vec<(u8, T)>,
+    seats: usize,
+    cmp: impl Fn(&T, &T) -> Ordering,
+) -> (Vec<u8>, Vec<u8>) {
+    // stable sort keeps the proposal order between equal proposals
+    ranking.sort_by(|(_, a), (_, b)| cmp(b, a));
+
+    if ranking.len() <= seats {
+        return (ranking.into_iter().map(|(id, _)| id).collect(), Vec::new());
+    }
+
+    let last_seat = &ranking[seats - 1].1;
+    let tie_start = ranking
+        .iter()
+        .position(|(_, rank)| cmp(rank, last_seat) == Ordering::Equal)
+        .unwrap_or(seats - 1);
+    let tie_end = tie_start
+        + ranking[tie_start..]
+            .iter()
+            .take_while(|(_, rank)| cmp(rank, last_seat) == Ordering::Equal)
+            .count();
+
+    let ids: Vec<u8> = ranking.into_iter().map(|(id, _)| id).collect();
+    if tie_end == seats {
+        (ids[..seats].to_vec(), Vec::new())
+    } else {
+        (ids[..tie_start].to_vec(), ids[tie_start..tie_end].to_vec())
+    }
+}
+
+/// Returns the proposals with the number of proposals they beat through the Schulze strongest
+/// paths, and whether one of them beats every other proposal head-to-head (Condorcet winner).
+fn get_schulze_ranking(pairwise_account: &PairwiseMatrixAccount) -> (Vec<(u8, u32)>, bool) {
+    let count = pairwise_account.candidates_count as usize;
+    let preferences = &pairwise_account.preferences;
+
+    // strongest paths, starting from the direct wins
+    let mut paths = [[0u32; MAX_RANKED_PROPOSALS]; MAX_RANKED_PROPOSALS];
+    for a in 0..count {
+        for b in 0..count {
+            if a != b && preferences[a][b] > preferences[b][a] {
+                paths[a][b] = preferences[a][b];
+            }
+        }
+    }
+    for k in 0..count {
+        for a in (0..count).filter(|&a| a != k) {
+            for b in (0..count).filter(|&b| b != k && b != a) {
+                paths[a][b] = paths[a][b].max(paths[a][k].min(paths[k][b]));
+            }
+        }
+    }
+
+    // the Schulze relation is transitive, so beaten proposals count orders the proposals
+    let ranking = (0..count)
+        .map(|a| {
+            let beaten = (0..count).filter(|&b| paths[a][b] > paths[b][a]).count();
+            ((a + 2) as u8, beaten as u32)
+        })
+        .collect();
+    let has_condorcet_winner =
+        (0..count).any(|a| (0..count).all(|b| a == b || preferences[a][b] > preferences[b][a]));
+
+    (ranking, has_condorcet_winner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn elect_votes(ranking: &[(u8, u32)], seats: usize) -> (Vec<u8>, Vec<u8>) {
+        elect(ranking.to_vec(), seats, |a, b| a.cmp(b))
+    }
+
+    #[test]
+    fn elect_fills_seats_best_first() {
+        assert_eq!(
+            elect_votes(&[(2, 5), (3, 9), (4, 7), (5, 1)], 2),
+            (vec![3, 4], vec![])
+        );
+        assert_eq!(elect_votes(&[(2, 1)], 3), (vec![2], vec![]));
+        assert_eq!(elect_votes(&[], 1), (vec![], vec![]));
+    }
+
+    #[test]
+    fn elect_records_last_seat_ties_apart() {
+        assert_eq!(
+            elect_votes(&[(2, 5), (3, 9), (4, 5), (5, 1)], 2),
+            (vec![3], vec![2, 4])
+        );
+        // ties within the seats are all elected
+        assert_eq!(
+            elect_votes(&[(2, 5), (3, 5), (4, 1)], 2),
+            (vec![2, 3], vec![])
+        );
+    }
+
+    #[test]
+    fn elect_single_seat_records_top_ties_apart() {
+        assert_eq!(elect_votes(&[(2, 1), (3, 4)], 1), (vec![3], vec![]));
+        assert_eq!(
+            elect_votes(&[(2, 5), (3, 1), (4, 5)], 1),
+            (vec![], vec![2, 4])
+        );
+
+        // top ties are not bounded by MAX_SEATS
+        let ranking: Vec<(u8, u32)> = (2..=254).map(|id| (id, 1)).collect();
+        assert_eq!(elect_votes(&ranking, 1), (vec![], (2..=254).collect()));
+    }
+
+    /// Pairwise matrix of the given candidates, proposals 2.. in order.
+    fn pairwise(preferences: &[&[u32]]) -> PairwiseMatrixAccount {
+        let mut account = PairwiseMatrixAccount {
+            session_id: 0,
+            candidates_count: preferences.len() as u8,
+            preferences: [[0; MAX_RANKED_PROPOSALS]; MAX_RANKED_PROPOSALS],
+        };
+        for (a, row) in preferences.iter().enumerate() {
+            account.preferences[a][..row.len()].copy_from_slice(row);
+        }
+        account
+    }
+
+    #[test]
+    fn schulze_ranking_finds_condorcet_winner() {
+        let account = pairwise(&[&[0, 3, 4], &[2, 0, 3], &[1, 2, 0]]);
+
+        let (ranking, has_condorcet_winner) = get_schulze_ranking(&account);
+        assert_eq!(ranking, vec![(2, 2), (3, 1), (4, 0)]);
+        assert!(has_condorcet_winner);
+    }
+
+    #[test]
+    fn schulze_ranking_breaks_condorcet_cycle() {
+        // 2 beats 3 (6 to 3), 3 beats 4 (7 to 2) and 4 beats 2 (5 to 4)
+        let account = pairwise(&[&[0, 6, 4], &[3, 0, 7], &[5, 2, 0]]);
+
+        // strongest paths: 2 over 4 through 3 (6) is stronger than 4 over 2 (5)
+        let (ranking, has_condorcet_winner) = get_schulze_ranking(&account);
+        assert_eq!(ranking, vec![(2, 2), (3, 1), (4, 0)]);
+        assert!(!has_condorcet_winner);
+    }
+
+    #[test]
+    fn schulze_ranking_ties_without_preferences() {
+        let account = pairwise(&[&[0, 0], &[0, 0]]);
+
+        let (ranking, has_condorcet_winner) = get_schulze_ranking(&account);
+        assert_eq!(ranking, vec![(2, 0), (3, 0)]);
+        assert!(!has_condorcet_winner);
+    }
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::VotingError,
+    state::{global::GlobalAccount, voting_session::*},
+};
+
+#[derive(Accounts)]
+pub struct VetoResultContextData<'info> {
+    pub veto_council: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut)]
+    pub session_account: Account<'info, SessionAccount>,
+}
+
+pub fn veto_result(ctx: Context<VetoResultContextData>) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
+    let session_account = &mut ctx.accounts.session_account;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        session_account.status == SessionWorkflowStatus::VotesTallied,
+        VotingError::UnexpectedSessionStatus
+    );
+    require!(
+        session_account.veto_council == Some(ctx.accounts.veto_council.key()),
+        VotingError::ForbiddenAsNonVetoCouncil
+    );
+    require!(
+        now < session_account.execution_eta && session_account.released_at == 0,
+        VotingError::VetoWindowClosed
+    );
+
+    session_account.status = SessionWorkflowStatus::Vetoed;
+    session_account.vetoed_at = now;
+
+    emit!(ResultVetoed {
+        session_id: session_account.session_id,
+        veto_council: ctx.accounts.veto_council.key(),
+        vetoed_at: now,
+    });
+
+    emit!(SessionWorkflowStatusChanged {
+        session_id: session_account.session_id,
+        previous_status: SessionWorkflowStatus::VotesTallied,
+        current_status: SessionWorkflowStatus::Vetoed,
+    });
+
+    Ok(())
+}
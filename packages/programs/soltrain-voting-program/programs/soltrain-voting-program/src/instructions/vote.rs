@@ -1,13 +1,26 @@
 use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_2022::Token2022,
+    token_interface::{Mint, TokenAccount},
+};
 
 use crate::errors::*;
-use crate::state::{proposal::*, voter::*, voting_session::*};
+use crate::instructions::badge::mint_badge_to;
+use crate::state::{global::GlobalAccount, proposal::*, receipt::*, voter::*, voting_session::*};
 
 #[derive(Accounts)]
 pub struct VoteContextData<'info> {
     #[account(mut)]
     pub voter: Signer<'info>,
 
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut)]
     pub session_account: Account<'info, SessionAccount>,
 
     #[account(mut)]
@@ -16,38 +29,142 @@ pub struct VoteContextData<'info> {
     #[account(mut)]
     pub proposal_account: Account<'info, ProposalAccount>,
 
+    #[account(
+        mut,
+        seeds = [
+            ReceiptAccumulatorAccount::SEED_PREFIX.as_ref(),
+            &session_account.session_id.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub receipts_account: Box<Account<'info, ReceiptAccumulatorAccount>>,
+
+    // optional badge accounts, to mint the session participation badge along the vote
+    #[account(mut)]
+    pub badge_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        init_if_needed,
+        payer = voter,
+        associated_token::mint = badge_mint,
+        associated_token::authority = voter,
+        associated_token::token_program = token_program,
+    )]
+    pub voter_badge_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Option<Program<'info, Token2022>>,
+
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+
     pub system_program: Program<'info, System>,
 }
 
-
 pub fn vote(ctx: Context<VoteContextData>) -> Result<()> {
     let voter = &ctx.accounts.voter;
-    let session_account = &ctx.accounts.session_account;
     let voter_account = &mut ctx.accounts.voter_account;
-    let proposal_account = &mut ctx.accounts.proposal_account;
 
+    if ctx.accounts.global_account.paused {
+        return err!(VotingError::ProgramPaused);
+    };
+    if voter_account.voter.key() != voter.key() {
+        return err!(VotingError::UnexpectedVoter);
+    };
+
+    cast_vote(
+        &mut ctx.accounts.session_account,
+        voter_account,
+        &mut ctx.accounts.proposal_account,
+        &mut ctx.accounts.receipts_account,
+    )?;
+
+    match (
+        &ctx.accounts.badge_mint,
+        &ctx.accounts.voter_badge_account,
+        &ctx.accounts.token_program,
+    ) {
+        (None, None, None) => Ok(()),
+        (Some(badge_mint), Some(voter_badge_account), Some(token_program)) => mint_badge_to(
+            &ctx.accounts.session_account,
+            voter_account,
+            badge_mint,
+            voter_badge_account,
+            token_program,
+        ),
+        _ => err!(VotingError::MissingTokenAccounts),
+    }
+}
+
+/// Records a vote of an authenticated voter for a proposal, in plurality mode,
+/// and appends its receipt to the session receipts accumulator.
+pub fn cast_vote(
+    session_account: &mut SessionAccount,
+    voter_account: &mut VoterAccount,
+    proposal_account: &mut ProposalAccount,
+    receipts_account: &mut ReceiptAccumulatorAccount,
+) -> Result<()> {
     if session_account.status != SessionWorkflowStatus::VotingSessionStarted {
         return err!(VotingError::UnexpectedSessionStatus);
     };
-    if session_account.admin.key() == voter.key() {
+    if session_account.voting_mode != VotingMode::Plurality {
+        return err!(VotingError::UnexpectedVotingMode);
+    };
+    // encrypted and anonymous ballots are cast with their own instructions
+    if session_account.encryption.is_some() || session_account.anonymous {
+        return err!(VotingError::UnexpectedVotingMode);
+    };
+    if session_account.admin.key() == voter_account.voter.key() {
         return err!(VotingError::AdminForbiddenAsVoter);
     };
-    if voter_account.voter.key() != voter.key() {
+    if voter_account.session_id != session_account.session_id {
         return err!(VotingError::UnexpectedVoter);
     };
-    if voter_account.has_voted {
+    session_account.require_voting_power(voter_account)?;
+    if proposal_account.session_id != session_account.session_id {
+        return err!(VotingError::InvalidProposalId);
+    };
+
+    let question_id = proposal_account.question_id;
+    if question_id == 0 && session_account.seats > 1 {
+        // block voting, one vote per seat on distinct proposals
+        if voter_account
+            .seat_votes
+            .contains(&proposal_account.proposal_id)
+        {
+            return err!(VotingError::ProposalAlreadyVoted);
+        };
+        let Some(seat) = voter_account.seat_votes.iter().position(|&id| id == 0) else {
+            return err!(VotingError::VoterAlreadyVoted);
+        };
+        if seat >= session_account.seats as usize {
+            return err!(VotingError::VoterAlreadyVoted);
+        };
+        voter_account.seat_votes[seat] = proposal_account.proposal_id;
+    } else if voter_account.question_votes[question_id as usize] != 0 {
         return err!(VotingError::VoterAlreadyVoted);
     };
 
+    if question_id == 0 && voter_account.question_votes[0] == 0 {
+        session_account.ballots_count += 1;
+        session_account.ballots_weight += voter_account.weight;
+    };
+
+    // has_voted tracks a vote on any question, voted_proposal_id the main question choice
     voter_account.has_voted = true;
-    voter_account.voted_proposal_id = proposal_account.proposal_id;
+    if voter_account.question_votes[question_id as usize] == 0 {
+        voter_account.question_votes[question_id as usize] = proposal_account.proposal_id;
+    };
+    if question_id == 0 && voter_account.voted_proposal_id == 0 {
+        voter_account.voted_proposal_id = proposal_account.proposal_id;
+    }
 
-    proposal_account.vote_count += 1;
+    proposal_account.vote_count += voter_account.weight;
 
     emit!(Voted {
         session_id: session_account.session_id,
+        question_id,
         proposal_id: proposal_account.proposal_id,
         voter: voter_account.voter,
     });
-    Ok(())
+
+    receipts_account.add_receipt(voter_account.voter, vec![proposal_account.proposal_id])
 }
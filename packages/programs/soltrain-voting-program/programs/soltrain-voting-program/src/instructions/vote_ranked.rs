@@ -0,0 +1,145 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::VotingError,
+    state::{
+        ballot::*, global::GlobalAccount, pairwise::*, receipt::*, voter::*, voting_session::*,
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(rankings: Vec<BallotEntry>)]
+pub struct VoteRankedContextData<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut)]
+    pub session_account: Account<'info, SessionAccount>,
+
+    #[account(mut)]
+    pub voter_account: Account<'info, VoterAccount>,
+
+    #[account(
+        init,
+        payer = voter,
+        space = BallotAccount::space(rankings.len()),
+        seeds = [
+            BallotAccount::SEED_PREFIX.as_ref(),
+            &session_account.session_id.to_le_bytes(),
+            voter.key().as_ref()
+        ],
+        bump
+    )]
+    pub ballot_account: Account<'info, BallotAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            ReceiptAccumulatorAccount::SEED_PREFIX.as_ref(),
+            &session_account.session_id.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub receipts_account: Box<Account<'info, ReceiptAccumulatorAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = voter,
+        space = 8 + PairwiseMatrixAccount::INIT_SPACE,
+        seeds = [
+            PairwiseMatrixAccount::SEED_PREFIX.as_ref(),
+            &session_account.session_id.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub pairwise_account: Box<Account<'info, PairwiseMatrixAccount>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn vote_ranked(ctx: Context<VoteRankedContextData>, rankings: Vec<BallotEntry>) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
+    let voter = &ctx.accounts.voter;
+    let session_account = &mut ctx.accounts.session_account;
+    let voter_account = &mut ctx.accounts.voter_account;
+    let pairwise_account = &mut ctx.accounts.pairwise_account;
+
+    require!(
+        session_account.status == SessionWorkflowStatus::VotingSessionStarted,
+        VotingError::UnexpectedSessionStatus
+    );
+    require!(
+        session_account.admin.key() != voter.key(),
+        VotingError::AdminForbiddenAsVoter
+    );
+    require!(
+        voter_account.voter.key() == voter.key()
+            && voter_account.session_id == session_account.session_id,
+        VotingError::UnexpectedVoter
+    );
+    require!(!voter_account.has_voted, VotingError::VoterAlreadyVoted);
+    session_account.require_voting_power(voter_account)?;
+    require!(
+        session_account.voting_mode == VotingMode::Condorcet,
+        VotingError::UnexpectedVotingMode
+    );
+
+    let candidates_count = session_account.proposals_count as usize - 2;
+
+    // unranked candidates are ranked after every ranked one
+    let mut ranks = [u8::MAX; MAX_RANKED_PROPOSALS];
+    let mut previous_proposal_id = 1;
+    for entry in rankings.iter() {
+        // strictly increasing ids so that a proposal can not be ranked twice
+        require!(
+            entry.proposal_id > previous_proposal_id
+                && (entry.proposal_id as usize) < session_account.proposals_count as usize,
+            VotingError::InvalidProposalId
+        );
+        require!(
+            entry.value > 0 && entry.value < u8::MAX,
+            VotingError::InvalidRank
+        );
+        ranks[PairwiseMatrixAccount::candidate_index(entry.proposal_id)] = entry.value;
+        previous_proposal_id = entry.proposal_id;
+    }
+
+    pairwise_account.session_id = session_account.session_id;
+    pairwise_account.candidates_count = candidates_count as u8;
+    for a in 0..candidates_count {
+        for b in 0..candidates_count {
+            if ranks[a] < ranks[b] {
+                pairwise_account.preferences[a][b] += voter_account.weight;
+            }
+        }
+    }
+
+    voter_account.has_voted = true;
+    session_account.ballots_count += 1;
+    session_account.ballots_weight += voter_account.weight;
+
+    let ballot_account = &mut ctx.accounts.ballot_account;
+    ballot_account.session_id = session_account.session_id;
+    ballot_account.voter = voter.key();
+    ballot_account.entries = rankings.clone();
+
+    emit!(BallotCast {
+        session_id: session_account.session_id,
+        voter: voter.key(),
+        entries: rankings,
+    });
+
+    ctx.accounts
+        .receipts_account
+        .add_receipt(voter.key(), entries_ballot(&ballot_account.entries))
+}
@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::VotingError,
+    state::{
+        ballot::*, global::GlobalAccount, proposal::*, receipt::*, voter::*, voting_session::*,
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(scores: Vec<BallotEntry>)]
+pub struct VoteScoresContextData<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut)]
+    pub session_account: Account<'info, SessionAccount>,
+
+    #[account(mut)]
+    pub voter_account: Account<'info, VoterAccount>,
+
+    #[account(
+        init,
+        payer = voter,
+        space = BallotAccount::space(scores.len()),
+        seeds = [
+            BallotAccount::SEED_PREFIX.as_ref(),
+            &session_account.session_id.to_le_bytes(),
+            voter.key().as_ref()
+        ],
+        bump
+    )]
+    pub ballot_account: Account<'info, BallotAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            ReceiptAccumulatorAccount::SEED_PREFIX.as_ref(),
+            &session_account.session_id.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub receipts_account: Box<Account<'info, ReceiptAccumulatorAccount>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn vote_scores<'info>(
+    ctx: Context<'_, '_, 'info, 'info, VoteScoresContextData<'info>>,
+    scores: Vec<BallotEntry>,
+) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
+    let voter = &ctx.accounts.voter;
+    let session_account = &mut ctx.accounts.session_account;
+    let voter_account = &mut ctx.accounts.voter_account;
+
+    require!(
+        session_account.status == SessionWorkflowStatus::VotingSessionStarted,
+        VotingError::UnexpectedSessionStatus
+    );
+    require!(
+        session_account.admin.key() != voter.key(),
+        VotingError::AdminForbiddenAsVoter
+    );
+    require!(
+        voter_account.voter.key() == voter.key()
+            && voter_account.session_id == session_account.session_id,
+        VotingError::UnexpectedVoter
+    );
+    require!(!voter_account.has_voted, VotingError::VoterAlreadyVoted);
+    session_account.require_voting_power(voter_account)?;
+
+    let VotingMode::Score { max_score } = session_account.voting_mode else {
+        return err!(VotingError::UnexpectedVotingMode);
+    };
+
+    // remaining accounts are the scored proposals, in the ballot order
+    require!(
+        ctx.remaining_accounts.len() == scores.len(),
+        VotingError::BadProposalAccountsCount
+    );
+
+    let mut previous_proposal_id = 0;
+    for (entry, proposal_info) in scores.iter().zip(ctx.remaining_accounts.iter()) {
+        // strictly increasing ids so that a proposal can not be scored twice
+        require!(
+            entry.proposal_id > previous_proposal_id,
+            VotingError::InvalidProposalId
+        );
+        require!(entry.value <= max_score, VotingError::InvalidScore);
+        require!(proposal_info.is_writable, VotingError::InvalidProposalId);
+
+        let mut proposal: Account<ProposalAccount> = Account::try_from(proposal_info)?;
+        // blank proposal does not make sense with scores
+        require!(
+            proposal.session_id == session_account.session_id
+                && proposal.proposal_id == entry.proposal_id
+                && proposal.proposal_id != 1,
+            VotingError::InvalidProposalId
+        );
+
+        // a weighted voter counts as many ballots
+        proposal.score_sum += entry.value as u64 * voter_account.weight as u64;
+        proposal.score_count += voter_account.weight;
+        proposal.score_distribution[entry.value as usize] += voter_account.weight;
+        proposal.exit(&crate::ID)?;
+
+        previous_proposal_id = entry.proposal_id;
+    }
+
+    voter_account.has_voted = true;
+    session_account.ballots_count += 1;
+    session_account.ballots_weight += voter_account.weight;
+
+    let ballot_account = &mut ctx.accounts.ballot_account;
+    ballot_account.session_id = session_account.session_id;
+    ballot_account.voter = voter.key();
+    ballot_account.entries = scores.clone();
+
+    emit!(BallotCast {
+        session_id: session_account.session_id,
+        voter: voter.key(),
+        entries: scores,
+    });
+
+    ctx.accounts
+        .receipts_account
+        .add_receipt(voter.key(), entries_ballot(&ballot_account.entries))
+}
@@ -0,0 +1,151 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::{
+    errors::VotingError,
+    state::{global::GlobalAccount, proposal::ProposalAccount, treasury::*, voting_session::*},
+    utils::transfer_tokens_signed,
+};
+
+#[derive(Accounts)]
+pub struct WithdrawFromTreasuryContextData<'info> {
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [GlobalAccount::SEED.as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    pub session_account: Account<'info, SessionAccount>,
+
+    #[account(mut)]
+    pub proposal_account: Account<'info, ProposalAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            TreasuryAccount::SEED_PREFIX.as_ref(),
+            &session_account.session_id.to_le_bytes(),
+        ],
+        bump = treasury_account.bump
+    )]
+    pub treasury_account: Account<'info, TreasuryAccount>,
+
+    /// CHECK: transfer recipient, checked against proposal treasury transfer
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+
+    pub mint: Option<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = treasury_account,
+    )]
+    pub treasury_token_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(mut, token::mint = mint, token::authority = recipient)]
+    pub recipient_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+pub fn withdraw_from_treasury(ctx: Context<WithdrawFromTreasuryContextData>) -> Result<()> {
+    require!(
+        !ctx.accounts.global_account.paused,
+        VotingError::ProgramPaused
+    );
+
+    let session_account = &ctx.accounts.session_account;
+    let proposal_account = &mut ctx.accounts.proposal_account;
+    let treasury_account = &ctx.accounts.treasury_account;
+
+    require!(
+        session_account.status == SessionWorkflowStatus::VotesTallied,
+        VotingError::UnexpectedSessionStatus
+    );
+    require!(
+        proposal_account.session_id == session_account.session_id,
+        VotingError::InvalidProposalId
+    );
+    session_account.require_elected(proposal_account.proposal_id)?;
+    require!(
+        session_account.released_at > 0,
+        VotingError::ResultNotReleased
+    );
+
+    let proposal_id = proposal_account.proposal_id;
+    let transfer = proposal_account
+        .treasury_transfer
+        .as_mut()
+        .ok_or(VotingError::NoTreasuryTransfer)?;
+    require!(!transfer.executed, VotingError::ProposalAlreadyExecuted);
+    require!(
+        transfer.recipient == ctx.accounts.recipient.key(),
+        VotingError::UnexpectedVoter
+    );
+
+    match transfer.mint {
+        None => {
+            // the treasury account must stay rent exempt
+            let rent_exempt =
+                Rent::get()?.minimum_balance(treasury_account.to_account_info().data_len());
+            require!(
+                treasury_account.get_lamports() >= rent_exempt + transfer.amount,
+                VotingError::InsufficientTreasuryFunds
+            );
+            treasury_account.sub_lamports(transfer.amount)?;
+            ctx.accounts.recipient.add_lamports(transfer.amount)?;
+        }
+        Some(mint) => {
+            let (
+                Some(mint_account),
+                Some(treasury_token_account),
+                Some(recipient_token_account),
+                Some(token_program),
+            ) = (
+                &ctx.accounts.mint,
+                &ctx.accounts.treasury_token_account,
+                &ctx.accounts.recipient_token_account,
+                &ctx.accounts.token_program,
+            )
+            else {
+                return err!(VotingError::MissingTokenAccounts);
+            };
+            require!(mint_account.key() == mint, VotingError::InvalidMint);
+            require!(
+                treasury_token_account.amount >= transfer.amount,
+                VotingError::InsufficientTreasuryFunds
+            );
+
+            let session_id = session_account.session_id.to_le_bytes();
+            let signer_seeds: &[&[&[u8]]] = &[&[
+                TreasuryAccount::SEED_PREFIX.as_ref(),
+                &session_id,
+                &[treasury_account.bump],
+            ]];
+            transfer_tokens_signed(
+                token_program,
+                mint_account,
+                treasury_token_account,
+                recipient_token_account,
+                treasury_account.to_account_info(),
+                signer_seeds,
+                transfer.amount,
+            )?;
+        }
+    }
+
+    transfer.executed = true;
+
+    emit!(TreasuryWithdrawn {
+        session_id: session_account.session_id,
+        proposal_id,
+        recipient: transfer.recipient,
+        mint: transfer.mint,
+        amount: transfer.amount,
+    });
+
+    Ok(())
+}
@@ -1,14 +1,32 @@
 use anchor_lang::prelude::*;
 
 use crate::instructions::{
-    create_voting_session::*, global::*, register_proposal::*, register_voter::*,
-    start_proposals_registration::*, start_voting_session::*, stop_proposals_registration::*,
-    stop_voting_session::*, tally_votes::*, vote::*,
+    add_question::*, allocate_votes::*, anonymous_vote::*, apply_voting_power::*,
+    attach_proposal_transaction::*, badge::*, configure_encryption::*, configure_execution::*,
+    configure_proposals::*, configure_voting_mode::*, configure_voting_power::*,
+    create_voting_session::*, decryption::*, deposit_to_treasury::*, encrypted_vote::*,
+    execute_winning_proposal::*, get_proposal_outcome::*, global::*, join_session::*, lock::*,
+    organization::*, register_proposal::*, register_voter::*, relay_vote::*, relay_votes::*,
+    release_result::*, request_treasury_transfer::*, reward::*, settle_proposal_deposit::*,
+    snapshot_voting_power::*, start_proposals_registration::*, start_voting_session::*,
+    stop_proposals_registration::*, stop_voting_session::*, tally_votes::*, veto_result::*,
+    vote::*, vote_ranked::*, vote_scores::*, withdraw_from_treasury::*,
+};
+use crate::state::{
+    ballot::BallotEntry,
+    encryption::{BitProof, Ciphertext, EqualityProof, Trustee},
+    global::GlobalConfig,
+    identity::{Groth16Proof, VerifyingKey},
+    proposal_transaction::ProposalInstruction,
+    voting_session::{ProposalOutcome, ProposalsConfig, VotingMode},
 };
 
+pub mod elgamal;
 pub mod errors;
 pub mod instructions;
+pub mod semaphore;
 pub mod state;
+pub mod utils;
 
 declare_id!("8H7dfzoBA4kJjokGqDgQ4VsDeBqg7SuMWFUWowdvRQda");
 
@@ -19,36 +37,325 @@ pub mod voting {
 
     use super::*;
 
-    pub fn init_global(ctx: Context<InitGlobalContextData>) -> Result<()> {
-        global::init_global(ctx)
+    /**
+     * Program upgrade authority initializes the global account and becomes protocol authority.
+     *
+     * @dev An event GlobalConfigUpdated is emitted
+     *
+     * @param config The protocol configuration (session fee, name and description max lengths, max voters)
+     */
+    pub fn init_global(ctx: Context<InitGlobalContextData>, config: GlobalConfig) -> Result<()> {
+        global::init_global(ctx, config)
+    }
+
+    /**
+     * Protocol authority can update the protocol configuration.
+     *
+     * @dev An event GlobalConfigUpdated is emitted
+     *
+     * @param config The new protocol configuration
+     */
+    pub fn update_global_config(
+        ctx: Context<UpdateGlobalConfigContextData>,
+        config: GlobalConfig,
+    ) -> Result<()> {
+        global::update_global_config(ctx, config)
+    }
+
+    /**
+     * Protocol authority can pause or unpause every session instruction.
+     *
+     * @dev An event PausedChanged is emitted
+     *
+     * @param paused The new pause flag
+     */
+    pub fn set_paused(ctx: Context<SetPausedContextData>, paused: bool) -> Result<()> {
+        global::set_paused(ctx, paused)
+    }
+
+    /**
+     * Protocol authority sets the Groth16 verifying key of the anonymous voting membership circuit.
+     *
+     * @dev The key is the Semaphore circuit one, for the identity tree depth, points being big endian as expected by the alt_bn128 syscalls.
+     * Sessions configured with the previous key can not verify anonymous votes anymore.
+     * An event VerifyingKeyUpdated is emitted
+     *
+     * @param verifying_key The circuit verifying key
+     */
+    pub fn set_verifying_key(
+        ctx: Context<SetVerifyingKeyContextData>,
+        verifying_key: VerifyingKey,
+    ) -> Result<()> {
+        global::set_verifying_key(ctx, verifying_key)
+    }
+
+    /**
+     * Anyone can create a new organization. Organization's creator becomes its first administrator.
+     *
+     * @dev An event OrganizationCreated is emitted
+     *
+     * @param name The organization name
+     */
+    pub fn create_organization(
+        ctx: Context<CreateOrganizationContextData>,
+        name: String,
+    ) -> Result<()> {
+        organization::create_organization(ctx, name)
+    }
+
+    /**
+     * Organization administrators can add another administrator.
+     *
+     * @dev An event OrganizationAdminAdded is emitted
+     *
+     * @param admin The address to add into organization administrators
+     */
+    pub fn add_organization_admin(
+        ctx: Context<ManageOrganizationAdminContextData>,
+        admin: Pubkey,
+    ) -> Result<()> {
+        organization::add_organization_admin(ctx, admin)
+    }
+
+    /**
+     * Organization administrators can remove an administrator.
+     *
+     * @dev The last administrator can not be removed.
+     * An event OrganizationAdminRemoved is emitted
+     *
+     * @param admin The address to remove from organization administrators
+     */
+    pub fn remove_organization_admin(
+        ctx: Context<ManageOrganizationAdminContextData>,
+        admin: Pubkey,
+    ) -> Result<()> {
+        organization::remove_organization_admin(ctx, admin)
+    }
+
+    /**
+     * Organization administrators can register a standing member.
+     *
+     * @dev An event OrganizationMemberAdded is emitted
+     *
+     * @param member The address to add into organization members registry
+     */
+    pub fn add_organization_member(
+        ctx: Context<AddOrganizationMemberContextData>,
+        member: Pubkey,
+    ) -> Result<()> {
+        organization::add_organization_member(ctx, member)
+    }
+
+    /**
+     * Organization administrators can remove a standing member.
+     *
+     * @dev An event OrganizationMemberRemoved is emitted
+     */
+    pub fn remove_organization_member(
+        ctx: Context<RemoveOrganizationMemberContextData>,
+    ) -> Result<()> {
+        organization::remove_organization_member(ctx)
     }
 
     /**
      * Anyone can create new voting session. Session's creator becomes session administrator.
      *
-     * @dev An event SessionCreated is emitted
+     * @dev The global session fee is paid to the protocol authority.
+     * When an organization account is given, the creator must be one of its administrators
+     * and the organization members at creation time are eligible to join the session as voters.
+     * The session id is appended to the creator sessions index (`admin_sessions` PDAs).
+     * An event SessionCreated is emitted
      *
      * @param name The session name
      * @param description The session description
+     * @param metadata_uri The off-chain session document URI, empty if none
+     * @param metadata_hash The SHA-256 of the off-chain session document
      */
     pub fn create_voting_session(
         ctx: Context<CreateVotingSessionContextData>,
         name: String,
         description: String,
+        metadata_uri: String,
+        metadata_hash: [u8; 32],
     ) -> Result<()> {
-        create_voting_session::create_voting_session(ctx, name, description)
+        create_voting_session::create_voting_session(
+            ctx,
+            name,
+            description,
+            metadata_uri,
+            metadata_hash,
+        )
     }
 
     /**
      * Session administrator can register voters.
      *
      * @dev voters can be added only by session administrator when status is set to RegisteringVoters
+     * The session id is appended to the voter sessions index (`voter_sessions` PDAs).
      * An event VoterRegistered is emitted
      *
      * @param voter The address to add into voters registry
+     * @param weight The votes count of each voter vote, 1 if none
+     * @param credits The voter quadratic credits budget, the session credits if none
+     */
+    pub fn register_voter(
+        ctx: Context<RegisterVoterContextData>,
+        voter: Pubkey,
+        weight: Option<u32>,
+        credits: Option<u32>,
+    ) -> Result<()> {
+        register_voter::register_voter(ctx, voter, weight, credits)
+    }
+
+    /**
+     * Session administrator can configure proposals registration rules.
+     *
+     * @dev Can be called only when status is set to RegisteringVoters.
+     * The optional deposit (SOL or SPL when a mint is set) is escrowed in the session vault at each proposal registration.
+     * The number of proposals a voter can register is capped by `max_proposals_per_voter`.
+     * An event ProposalsConfigured is emitted
+     *
+     * @param config The proposals configuration
      */
-    pub fn register_voter(ctx: Context<RegisterVoterContextData>, voter: Pubkey) -> Result<()> {
-        register_voter::register_voter(ctx, voter)
+    pub fn configure_proposals(
+        ctx: Context<ConfigureProposalsContextData>,
+        config: ProposalsConfig,
+    ) -> Result<()> {
+        configure_proposals::configure_proposals(ctx, config)
+    }
+
+    /**
+     * Session administrator can configure the timelock and veto window applied to the result.
+     *
+     * @dev Can be called only when status is set to RegisteringVoters.
+     * An event ExecutionConfigured is emitted
+     *
+     * @param execution_delay The timelock in seconds between votes talling and result release, at most one year
+     * @param veto_council The address allowed to veto the result during the timelock
+     */
+    pub fn configure_execution(
+        ctx: Context<ConfigureExecutionContextData>,
+        execution_delay: i64,
+        veto_council: Option<Pubkey>,
+    ) -> Result<()> {
+        configure_execution::configure_execution(ctx, execution_delay, veto_council)
+    }
+
+    /**
+     * Session administrator can configure how ballots are cast and tallied.
+     *
+     * @dev Can be called only when status is set to RegisteringVoters.
+     * Sessions use plurality voting by default, other modes rank the session proposals as a whole and do not support added questions.
+     * With many seats, the best ranked proposals are elected, plurality voters get one vote per seat (block voting).
+     * An event VotingModeConfigured is emitted
+     *
+     * @param voting_mode The session voting mode
+     * @param seats The number of proposals to elect
+     */
+    pub fn configure_voting_mode(
+        ctx: Context<ConfigureVotingModeContextData>,
+        voting_mode: VotingMode,
+        seats: u8,
+    ) -> Result<()> {
+        configure_voting_mode::configure_voting_mode(ctx, voting_mode, seats)
+    }
+
+    /**
+     * Session administrator can make ballots secret, encrypted under the public key of a t-of-n trustees group.
+     *
+     * @dev Can be called only when status is set to RegisteringVoters, for a plurality session with a single seat and no added question.
+     * Ballots are exponential ElGamal ciphertexts on ristretto255, only the aggregated votes of each proposal are ever decrypted.
+     * Verification keys must lie on the same degree threshold - 1 polynomial as the election public key, so that any threshold trustees can decrypt.
+     * An event EncryptionConfigured is emitted
+     *
+     * @param election_public_key The compressed ristretto election public key, shared among the trustees
+     * @param threshold The number of trustees needed to decrypt
+     * @param trustees The trustees authorities and the verification keys of their secret shares, share index being the trustee position + 1
+     */
+    pub fn configure_encryption(
+        ctx: Context<ConfigureEncryptionContextData>,
+        election_public_key: [u8; 32],
+        threshold: u8,
+        trustees: Vec<Trustee>,
+    ) -> Result<()> {
+        configure_encryption::configure_encryption(ctx, election_public_key, threshold, trustees)
+    }
+
+    /**
+     * Session administrator can make voters weight the voting power of their locked tokens.
+     *
+     * @dev Can be called only when status is set to RegisteringVoters.
+     * Voters of such sessions can only vote once their voting power is applied.
+     * An event VotingPowerConfigured is emitted
+     *
+     * @param lock_mint The mint of the locked tokens, or none to keep registered weights
+     */
+    pub fn configure_voting_power(
+        ctx: Context<ConfigureVotingPowerContextData>,
+        lock_mint: Option<Pubkey>,
+    ) -> Result<()> {
+        configure_voting_power::configure_voting_power(ctx, lock_mint)
+    }
+
+    /**
+     * Anyone can lock SPL tokens in a program owned vault to get voting power.
+     *
+     * @dev The voting power is the locked amount scaled by the remaining lock time over the maximum lock duration, decaying linearly.
+     * A lock can be topped up or extended, but never shortened.
+     * An event TokensLocked is emitted
+     *
+     * @param amount The amount of tokens to add to the lock
+     * @param duration The lock duration in seconds from now, up to 4 years
+     */
+    pub fn lock_tokens(
+        ctx: Context<LockTokensContextData>,
+        amount: u64,
+        duration: i64,
+    ) -> Result<()> {
+        lock::lock_tokens(ctx, amount, duration)
+    }
+
+    /**
+     * Lock owner can withdraw the locked tokens once the lock expired.
+     *
+     * @dev An event TokensUnlocked is emitted
+     */
+    pub fn unlock_tokens(ctx: Context<UnlockTokensContextData>) -> Result<()> {
+        lock::unlock_tokens(ctx)
+    }
+
+    /**
+     * A voter can snapshot their locked tokens for a session weighted by voting power.
+     *
+     * @dev Can be called until voting opens, the latest snapshot being kept.
+     * Voting opening records the snapshot slot, only snapshots taken up to it can be applied.
+     * An event VoterSnapshotTaken is emitted
+     */
+    pub fn snapshot_voting_power(ctx: Context<SnapshotVotingPowerContextData>) -> Result<()> {
+        snapshot_voting_power::snapshot_voting_power(ctx)
+    }
+
+    /**
+     * A voter can use the voting power of their locked tokens as weight.
+     *
+     * @dev Can be called only in sessions configured with a lock mint, when status is set to VotingSessionStarted and before voting.
+     * The power is read from the voter snapshot, taken before voting opened, and evaluated at voting opening time.
+     * An event VotingPowerApplied is emitted
+     */
+    pub fn apply_voting_power(ctx: Context<ApplyVotingPowerContextData>) -> Result<()> {
+        apply_voting_power::apply_voting_power(ctx)
+    }
+
+    /**
+     * An organization member can join an organization session as voter.
+     *
+     * @dev Replaces register_voter for organization sessions. Can be called until voting session starts.
+     * Only members registered when the session was created are eligible, joined members are the session voters.
+     * The session id is appended to the voter sessions index (`voter_sessions` PDAs).
+     * An event VoterRegistered is emitted
+     */
+    pub fn join_session(ctx: Context<JoinSessionContextData>) -> Result<()> {
+        join_session::join_session(ctx)
     }
 
     /**
@@ -71,14 +378,69 @@ pub mod voting {
      * @dev Each voter can register many proposals.
      * As the vote is considered to be done in small organization context, and to prevent dos gas limit, the maximum number of proposals is limited to 256.
      * A vote can be added only by registered voter when status is set to VotingSessionStarted
+     * When a proposal deposit is configured, it is escrowed in the session vault.
+     * The proposal answers the session main question unless a question account is given.
      *
      * @param description The proposal description
+     * @param metadata_uri The off-chain proposal document URI, empty if none
+     * @param metadata_hash The SHA-256 of the off-chain proposal document
      */
     pub fn register_proposal(
         ctx: Context<RegisterProposalContextData>,
         description: String,
+        metadata_uri: String,
+        metadata_hash: [u8; 32],
+    ) -> Result<()> {
+        register_proposal::register_proposal(ctx, description, metadata_uri, metadata_hash)
+    }
+
+    /**
+     * Administrator can add a question to the session ballot.
+     *
+     * @dev Can be called only when status is set to ProposalsRegistrationStarted.
+     * The session itself is the main question (id 0), added questions get their own `Blank` proposal and result.
+     * An event QuestionAdded is emitted
+     *
+     * @param title The question title
+     */
+    pub fn add_question(ctx: Context<AddQuestionContextData>, title: String) -> Result<()> {
+        add_question::add_question(ctx, title)
+    }
+
+    /**
+     * A proposer can attach on-chain instructions to a registered proposal.
+     *
+     * @dev Can be called only when status is set to ProposalsRegistrationStarted, so voters know what they vote for.
+     * Instructions are signed by the session executor PDA when the proposal wins.
+     * An event ProposalTransactionAttached is emitted
+     *
+     * @param instructions The instructions to invoke when the proposal wins
+     */
+    pub fn attach_proposal_transaction(
+        ctx: Context<AttachProposalTransactionContextData>,
+        instructions: Vec<ProposalInstruction>,
+    ) -> Result<()> {
+        attach_proposal_transaction::attach_proposal_transaction(ctx, instructions)
+    }
+
+    /**
+     * A proposer can request a transfer from the session treasury for a registered proposal.
+     *
+     * @dev Can be called only when status is set to ProposalsRegistrationStarted.
+     * The transfer can only be withdrawn if the proposal wins.
+     * An event TreasuryTransferRequested is emitted
+     *
+     * @param recipient The transfer recipient
+     * @param mint The SPL mint to transfer, or none for SOL
+     * @param amount The amount to transfer
+     */
+    pub fn request_treasury_transfer(
+        ctx: Context<RequestTreasuryTransferContextData>,
+        recipient: Pubkey,
+        mint: Option<Pubkey>,
+        amount: u64,
     ) -> Result<()> {
-        register_proposal::register_proposal(ctx, description)
+        request_treasury_transfer::request_treasury_transfer(ctx, recipient, mint, amount)
     }
 
     /**
@@ -96,17 +458,237 @@ pub mod voting {
     /**
      * A voter can register his vote for a proposal.
      *
-     * @dev Each voter can vote only once for one proposal.
+     * @dev Each voter can vote only once per question, the question being the one of the proposal.
+     * When many seats are configured, voters can vote once per seat for distinct proposals of the main question.
+     * Each vote adds the voter weight to the proposal votes count.
+     * Each vote receipt, hash of (session id, voter, ballot, slot), is appended to the session receipts accumulator, the ballot being the proposal id.
+     * When the optional badge accounts are given, the session participation badge is minted to the voter along the vote, unless already minted.
      * Votes can be added only by registered voter when status is set to VotingSessionStarted
      */
     pub fn vote(ctx: Context<VoteContextData>) -> Result<()> {
         vote::vote(ctx)
     }
 
+    /**
+     * A relayer can submit a vote signed off-chain by a voter, paying the transaction fees.
+     *
+     * @dev Same rules as `vote`, the vote being attributed to the signing voter.
+     * The ballot ("soltrain-voting:ballot" domain tag, program id, session id, proposal id, nonce) signature must be verified by an ed25519 program instruction placed right before this one.
+     * The nonce must match the voter nonce, incremented on each signed ballot.
+     * An event Voted is emitted
+     *
+     * @param nonce The voter nonce included in the signed ballot
+     */
+    pub fn relay_vote(ctx: Context<RelayVoteContextData>, nonce: u64) -> Result<()> {
+        relay_vote::relay_vote(ctx, nonce)
+    }
+
+    /**
+     * A relayer can submit many votes signed off-chain by voters in a single transaction.
+     *
+     * @dev Same rules as `relay_vote`, all ballots signatures being verified by the ed25519 program instruction placed right before this one.
+     * Remaining accounts are the voter account and the voted proposal account of each ballot, in the nonces order.
+     * A voter can have many ballots in the batch, one per seat in block voting, with consecutive nonces.
+     * Ballots of voters who already voted are skipped instead of failing the batch.
+     * Events Voted and SignedBallotsRelayed, reporting skipped voters, are emitted
+     *
+     * @param nonces The voter nonce included in each signed ballot
+     */
+    pub fn relay_votes<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RelayVotesContextData<'info>>,
+        nonces: Vec<u64>,
+    ) -> Result<()> {
+        relay_votes::relay_votes(ctx, nonces)
+    }
+
+    /**
+     * Voter adds the encryption of its choice for a proposal to its encrypted ballot.
+     *
+     * @dev Can be called only when status is set to VotingSessionStarted, in an encrypted session.
+     * Ciphertexts must be cast for every proposal, blank proposal included, in proposal order.
+     * The proof shows the ciphertext encrypts 0 or 1 without revealing which.
+     *
+     * @param proposal_id The proposal id
+     * @param ciphertext The ElGamal encryption of 1 for the chosen proposal, 0 otherwise
+     * @param proof The disjunctive Chaum-Pedersen proof of the ciphertext
+     */
+    pub fn cast_encrypted_vote(
+        ctx: Context<CastEncryptedVoteContextData>,
+        proposal_id: u8,
+        ciphertext: Ciphertext,
+        proof: BitProof,
+    ) -> Result<()> {
+        encrypted_vote::cast_encrypted_vote(ctx, proposal_id, ciphertext, proof)
+    }
+
+    /**
+     * Voter seals its complete encrypted ballot, which is added to the proposals encrypted votes.
+     *
+     * @dev Can be called only when status is set to VotingSessionStarted.
+     * The proof shows the ciphertexts sum encrypts 1, so a single proposal is chosen.
+     * Remaining accounts are all the session proposals, in proposal order.
+     * The vote receipt ballot is the hash of the ciphertexts.
+     * Events EncryptedBallotSealed and VoteReceiptAdded are emitted
+     *
+     * @param sum_proof The Chaum-Pedersen proof that the ciphertexts sum encrypts 1
+     */
+    pub fn seal_encrypted_ballot<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SealEncryptedBallotContextData<'info>>,
+        sum_proof: EqualityProof,
+    ) -> Result<()> {
+        encrypted_vote::seal_encrypted_ballot(ctx, sum_proof)
+    }
+
+    /**
+     * Trustee posts its decryption share of a proposal encrypted votes.
+     *
+     * @dev Can be called only when status is set to VotingSessionEnded.
+     * The proof shows the share uses the same secret as the trustee verification key.
+     * An event DecryptionSharePosted is emitted
+     *
+     * @param share The trustee secret share applied to the first ciphertext component
+     * @param proof The Chaum-Pedersen proof of the share
+     */
+    pub fn post_decryption_share(
+        ctx: Context<PostDecryptionShareContextData>,
+        share: [u8; 32],
+        proof: EqualityProof,
+    ) -> Result<()> {
+        decryption::post_decryption_share(ctx, share, proof)
+    }
+
+    /**
+     * Anyone can reveal a proposal votes count once enough trustees posted their decryption shares.
+     *
+     * @dev Can be called only when status is set to VotingSessionEnded, votes of every proposal must be revealed before the tally.
+     * The first `threshold` shares are combined, the count being checked against the combined decryption.
+     * An event ProposalVotesRevealed is emitted
+     *
+     * @param vote_count The decrypted votes count
+     * @param coefficients The Lagrange coefficients at 0 of the combined shares
+     */
+    pub fn reveal_proposal_votes(
+        ctx: Context<RevealProposalVotesContextData>,
+        vote_count: u32,
+        coefficients: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        decryption::reveal_proposal_votes(ctx, vote_count, coefficients)
+    }
+
+    /**
+     * Session administrator can make votes anonymous, voters proving their membership in a session identity tree.
+     *
+     * @dev Can be called only when status is set to RegisteringVoters, for a plurality session with a single seat and no added question.
+     * Creates the session identity tree, a Poseidon Merkle tree of the voters identity commitments.
+     * The verifying key hash is snapshotted in the identity tree, votes being verified with this key only.
+     * An event AnonymousVotingConfigured is emitted
+     */
+    pub fn configure_anonymous_voting(
+        ctx: Context<ConfigureAnonymousVotingContextData>,
+    ) -> Result<()> {
+        anonymous_vote::configure_anonymous_voting(ctx)
+    }
+
+    /**
+     * Registered voter adds its Semaphore identity commitment to the session identity tree.
+     *
+     * @dev Can be called until the voting session starts, once per voter, voter weight must be 1.
+     * An event IdentityCommitmentAdded is emitted
+     *
+     * @param identity_commitment The voter identity commitment
+     */
+    pub fn register_identity_commitment(
+        ctx: Context<RegisterIdentityCommitmentContextData>,
+        identity_commitment: [u8; 32],
+    ) -> Result<()> {
+        anonymous_vote::register_identity_commitment(ctx, identity_commitment)
+    }
+
+    /**
+     * Anyone can relay an anonymous vote, proven by a zero-knowledge proof of membership in the session identity tree.
+     *
+     * @dev Can be called only when status is set to VotingSessionStarted, in an anonymous session.
+     * The session id is the external nullifier and the proposal id the signal, so each identity votes once and the choice can not be changed.
+     * The nullifier hash account creation fails if it was already used.
+     * The verifying key must match the one snapshotted when anonymous voting was configured.
+     * The vote receipt voter is the nullifier hash and its ballot the proposal id.
+     * Events AnonymousVoted and VoteReceiptAdded are emitted
+     *
+     * @param proposal_id The proposal id
+     * @param nullifier_hash The identity nullifier hash for the session
+     * @param proof The Groth16 membership proof
+     */
+    pub fn cast_anonymous_vote(
+        ctx: Context<CastAnonymousVoteContextData>,
+        proposal_id: u8,
+        nullifier_hash: [u8; 32],
+        proof: Groth16Proof,
+    ) -> Result<()> {
+        anonymous_vote::cast_anonymous_vote(ctx, proposal_id, nullifier_hash, proof)
+    }
+
+    /**
+     * A voter can score the session proposals.
+     *
+     * @dev Can be called only in score mode when status is set to VotingSessionStarted.
+     * Each voter casts a single ballot, scored proposals are given as remaining accounts in increasing id order.
+     * Proposals are ranked by mean score over all ballots when votes are tallied, unscored proposals of a ballot counting as scored 0.
+     * The vote receipt ballot is the (proposal id, value) pairs of the entries.
+     * Events BallotCast and VoteReceiptAdded are emitted
+     *
+     * @param scores The score given to each proposal, from 0 to the session maximum score
+     */
+    pub fn vote_scores<'info>(
+        ctx: Context<'_, '_, 'info, 'info, VoteScoresContextData<'info>>,
+        scores: Vec<BallotEntry>,
+    ) -> Result<()> {
+        vote_scores::vote_scores(ctx, scores)
+    }
+
+    /**
+     * A voter can rank the session proposals.
+     *
+     * @dev Can be called only in condorcet mode when status is set to VotingSessionStarted.
+     * Each voter casts a single ballot, lower ranks are preferred, equal ranks express no preference and unranked proposals come last.
+     * Ballots are accumulated in the session pairwise preferences matrix, tallied with the Schulze method.
+     * The vote receipt ballot is the (proposal id, value) pairs of the entries.
+     * Events BallotCast and VoteReceiptAdded are emitted
+     *
+     * @param rankings The rank given to each proposal, in increasing proposal id order
+     */
+    pub fn vote_ranked(
+        ctx: Context<VoteRankedContextData>,
+        rankings: Vec<BallotEntry>,
+    ) -> Result<()> {
+        vote_ranked::vote_ranked(ctx, rankings)
+    }
+
+    /**
+     * A voter can spread votes across the session proposals.
+     *
+     * @dev Can be called only in quadratic or cumulative mode when status is set to VotingSessionStarted.
+     * Voters spend down their budget across allocations, each proposal being allocated votes once.
+     * In quadratic mode, n votes on a proposal cost n² credits out of the voter credits, or the session credits.
+     * In cumulative mode, each voter holds one point per seat, or the configured points, to stack on one proposal or split.
+     * The allocations are recorded in the voter ballot account and can be audited against the proposals votes count.
+     * Voted proposals are given as remaining accounts in increasing id order, their votes count is increased by the allocated votes.
+     * The vote receipt ballot is the (proposal id, value) pairs of the entries.
+     * Events BallotCast and VoteReceiptAdded are emitted
+     *
+     * @param allocations The votes given to each proposal
+     */
+    pub fn allocate_votes<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AllocateVotesContextData<'info>>,
+        allocations: Vec<BallotEntry>,
+    ) -> Result<()> {
+        allocate_votes::allocate_votes(ctx, allocations)
+    }
+
     /**
      * Administrator can open voting session.
      *
      * @dev Can be called only when status is set to ProposalsRegistrationEnded.
+     * Creates the session vote receipts accumulator.
      * An event WorkflowStatusChange is emitted
      */
     pub fn start_voting_session(ctx: Context<StartVotingSessionContextData>) -> Result<()> {
@@ -128,7 +710,15 @@ pub mod voting {
      *
      * @dev After votes talling, it is possible that we got many winning proposals.
      * Votes talling can be triggered only by voting session administrator when voting session status is set to VotingSessionEnded
-     * Events WorkflowStatusChange and VotesTallied are emitted
+     * Remaining accounts are the session proposals followed by the added questions, writable as each added question
+     * result is stored in its question account.
+     * In condorcet mode, the pairwise preferences account is required once a ballot has been cast.
+     * The session seats are filled by the best ranked proposals, proposals tied at the last seat are recorded apart
+     * and not elected, so a single seat session tied at the top has no winner.
+     * Votes and abstention are weighted by voters weight, raw headcounts are reported along.
+     * The vote receipts accumulator root is recorded in the result, for voters to verify their receipts inclusion.
+     * The result is queued until the execution timelock elapsed, without timelock it is released right away.
+     * Events WorkflowStatusChange, VotesTallied and ResultQueued are emitted, and ResultReleased without timelock
      *
      */
     pub fn tally_votes<'info>(
@@ -136,4 +726,156 @@ pub mod voting {
     ) -> Result<()> {
         tally_votes::tally_votes(ctx)
     }
+
+    /**
+     * Veto council can cancel the result while the execution timelock is running.
+     *
+     * @dev Can be called only when status is set to VotesTallied and before result release.
+     * Events ResultVetoed and WorkflowStatusChange are emitted
+     */
+    pub fn veto_result(ctx: Context<VetoResultContextData>) -> Result<()> {
+        veto_result::veto_result(ctx)
+    }
+
+    /**
+     * Anyone can release the result once the execution timelock elapsed.
+     *
+     * @dev Can be called only when status is set to VotesTallied, and only for timelocked sessions since results without timelock are released at tally.
+     * Follow-up actions (proposal execution, treasury withdrawal, deposits settlement) require a released result.
+     * An event ResultReleased is emitted
+     */
+    pub fn release_result(ctx: Context<ReleaseResultContextData>) -> Result<()> {
+        release_result::release_result(ctx)
+    }
+
+    /**
+     * Anyone, including other programs through CPI, can read the outcome of a proposal of a tallied session.
+     *
+     * @dev Read only, the outcome is returned with set_return_data, and is read with `Return::get` by CPI callers.
+     * Can be called only when status is set to VotesTallied, vetoed sessions have no outcome.
+     * Callers relying on a timelocked result should also check the outcome released_at.
+     * Proposals of added questions are read among their question result, the question account is then required.
+     */
+    pub fn get_proposal_outcome(
+        ctx: Context<GetProposalOutcomeContextData>,
+    ) -> Result<ProposalOutcome> {
+        get_proposal_outcome::get_proposal_outcome(ctx)
+    }
+
+    /**
+     * Anyone can execute the instructions attached to the winning proposal.
+     *
+     * @dev Can be called only when status is set to VotesTallied, once the result is released,
+     * and only if the proposal is the single winning proposal.
+     * Every account used by the instructions, including invoked programs, must be passed as remaining accounts.
+     * An event ProposalExecuted is emitted
+     */
+    pub fn execute_winning_proposal<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteWinningProposalContextData<'info>>,
+    ) -> Result<()> {
+        execute_winning_proposal::execute_winning_proposal(ctx)
+    }
+
+    /**
+     * Anyone can deposit SOL or SPL tokens into a session treasury.
+     *
+     * @dev When a mint account is given, tokens are transferred to the treasury associated token account.
+     * An event TreasuryDeposited is emitted
+     *
+     * @param amount The amount to deposit
+     */
+    pub fn deposit_to_treasury(
+        ctx: Context<DepositToTreasuryContextData>,
+        amount: u64,
+    ) -> Result<()> {
+        deposit_to_treasury::deposit_to_treasury(ctx, amount)
+    }
+
+    /**
+     * Anyone can trigger the treasury transfer requested by the winning proposal.
+     *
+     * @dev Can be called only when status is set to VotesTallied, once the result is released,
+     * and only if the proposal is the single winning proposal.
+     * An event TreasuryWithdrawn is emitted
+     */
+    pub fn withdraw_from_treasury(ctx: Context<WithdrawFromTreasuryContextData>) -> Result<()> {
+        withdraw_from_treasury::withdraw_from_treasury(ctx)
+    }
+
+    /**
+     * Session administrator can fund a participation reward pool, in SOL or SPL tokens.
+     *
+     * @dev Can be called once, before votes tallying. SOL rewards are used when no mint is given.
+     * The claim window opens when votes are tallied and lasts at most a year.
+     * An event RewardPoolFunded is emitted
+     *
+     * @param amount The amount deposited in the pool
+     * @param reward_per_voter The amount each voter who voted can claim
+     * @param claim_duration The number of seconds rewards can be claimed for, from the votes tallying
+     */
+    pub fn fund_reward_pool(
+        ctx: Context<FundRewardPoolContextData>,
+        amount: u64,
+        reward_per_voter: u64,
+        claim_duration: i64,
+    ) -> Result<()> {
+        reward::fund_reward_pool(ctx, amount, reward_per_voter, claim_duration)
+    }
+
+    /**
+     * A voter who voted can claim its participation reward.
+     *
+     * @dev Can be called only when status is set to VotesTallied, once per voter, until the claim duration elapsed since the tally.
+     * An event RewardClaimed is emitted
+     */
+    pub fn claim_reward(ctx: Context<ClaimRewardContextData>) -> Result<()> {
+        reward::claim_reward(ctx)
+    }
+
+    /**
+     * Reward pool funder can take back unclaimed rewards once the claim deadline passed.
+     *
+     * @dev Can be called only when status is set to VotesTallied once the claim duration elapsed since the tally, or Vetoed.
+     * The reward pool account and its token account are closed.
+     * An event RewardPoolReclaimed is emitted
+     */
+    pub fn reclaim_reward_pool(ctx: Context<ReclaimRewardPoolContextData>) -> Result<()> {
+        reward::reclaim_reward_pool(ctx)
+    }
+
+    /**
+     * Session administrator can enable participation badges, non-transferable Token-2022 tokens minted to voters who voted.
+     *
+     * @dev Creates the session badge mint, with the non-transferable and metadata pointer extensions.
+     * Badge metadata, stored in the mint, holds the session name and metadata uri, and a session_id field.
+     * An event BadgesEnabled is emitted
+     */
+    pub fn enable_badges(ctx: Context<EnableBadgesContextData>) -> Result<()> {
+        badge::enable_badges(ctx)
+    }
+
+    /**
+     * A voter who voted can mint its session participation badge, once.
+     *
+     * @dev The badge can also be minted along the vote, by giving the optional badge accounts to `vote`, which skips voters already holding it.
+     * An event BadgeMinted is emitted
+     */
+    pub fn mint_badge(ctx: Context<MintBadgeContextData>) -> Result<()> {
+        badge::mint_badge(ctx)
+    }
+
+    /**
+     * Anyone can settle a proposal deposit once the result is released or vetoed.
+     *
+     * @dev The deposit is refunded to the proposer when the proposal support share within its question
+     * reached `refund_threshold_bps`, otherwise it is forfeited to the session treasury.
+     * Added questions proposals are settled on their question account result.
+     * Main question support depends on the voting mode: votes, score sums over the best score, pairwise
+     * contests won (with the pairwise account) or ballots allocating votes to the proposal.
+     * When nobody voted, deposits are only refunded if the threshold is 0.
+     * An event ProposalDepositSettled is emitted
+     */
+    pub fn settle_proposal_deposit(ctx: Context<SettleProposalDepositContextData>) -> Result<()> {
+        settle_proposal_deposit::settle_proposal_deposit(ctx)
+    }
 }
@@ -0,0 +1,193 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        alt_bn128::prelude::{alt_bn128_addition, alt_bn128_multiplication, alt_bn128_pairing},
+        keccak,
+        poseidon::{self, Endianness, Parameters},
+    },
+};
+
+use crate::{
+    errors::VotingError,
+    state::identity::{Groth16Proof, VerifyingKeyAccount},
+};
+
+// BN254 base field modulus p, big endian
+const BASE_FIELD_MODULUS: [u8; 32] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x97, 0x81, 0x6a, 0x91, 0x68, 0x71, 0xca, 0x8d, 0x3c, 0x20, 0x8c, 0x16, 0xd8, 0x7c, 0xfd, 0x47,
+];
+
+// BN254 scalar field modulus r, big endian
+const SCALAR_FIELD_MODULUS: [u8; 32] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x28, 0x33, 0xe8, 0x48, 0x79, 0xb9, 0x70, 0x91, 0x43, 0xe1, 0xf5, 0x93, 0xf0, 0x00, 0x00, 0x01,
+];
+
+/// Whether a big endian value is a canonical element of the BN254 scalar field.
+pub fn is_field_element(value: &[u8; 32]) -> bool {
+    *value < SCALAR_FIELD_MODULUS
+}
+
+/// Poseidon hash of two field elements, the identity tree node hash.
+pub fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> Result<[u8; 32]> {
+    poseidon::hashv(Parameters::Bn254X5, Endianness::BigEndian, &[left, right])
+        .map(|hash| hash.to_bytes())
+        .map_err(|_| error!(VotingError::InvalidIdentityCommitment))
+}
+
+/// Semaphore external value hashing: keccak256 of the 32 bytes big endian value, shifted
+/// right by 8 bits to fit in the scalar field.
+pub fn hash_to_field(value: u64) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    bytes[24..].copy_from_slice(&value.to_be_bytes());
+    let hash = keccak::hash(&bytes).to_bytes();
+
+    let mut field_element = [0u8; 32];
+    field_element[1..].copy_from_slice(&hash[..31]);
+    field_element
+}
+
+/// Negates a G1 point, big endian (x, y) becoming (x, p - y).
+fn negate_g1(point: &[u8; 64]) -> [u8; 64] {
+    let mut negated = *point;
+    if point[32..] == [0u8; 32] {
+        return negated;
+    }
+
+    let mut borrow = 0i16;
+    for index in (0..32).rev() {
+        let difference = BASE_FIELD_MODULUS[index] as i16 - point[32 + index] as i16 - borrow;
+        borrow = (difference < 0) as i16;
+        negated[32 + index] = difference.rem_euclid(256) as u8;
+    }
+    negated
+}
+
+/// Verifies a Groth16 proof with the alt_bn128 syscalls, checking that
+/// e(-A, B)·e(alpha, beta)·e(vk_x, gamma)·e(C, delta) == 1.
+pub fn verify_groth16(
+    verifying_key: &VerifyingKeyAccount,
+    proof: &Groth16Proof,
+    public_inputs: &[[u8; 32]],
+) -> Result<()> {
+    require!(
+        public_inputs.len() + 1 == verifying_key.ic.len()
+            && public_inputs.iter().all(is_field_element),
+        VotingError::InvalidMembershipProof
+    );
+
+    // vk_x = IC[0] + sum(input_i·IC[i + 1])
+    let mut vk_x = verifying_key.ic[0].to_vec();
+    for (input, ic) in public_inputs.iter().zip(&verifying_key.ic[1..]) {
+        let product = alt_bn128_multiplication(&[ic.as_slice(), input].concat())
+            .map_err(|_| error!(VotingError::InvalidMembershipProof))?;
+        vk_x = alt_bn128_addition(&[vk_x, product].concat())
+            .map_err(|_| error!(VotingError::InvalidMembershipProof))?;
+    }
+
+    let pairing_input = [
+        negate_g1(&proof.a).as_slice(),
+        &proof.b,
+        &verifying_key.alpha_g1,
+        &verifying_key.beta_g2,
+        &vk_x,
+        &verifying_key.gamma_g2,
+        &proof.c,
+        &verifying_key.delta_g2,
+    ]
+    .concat();
+    let result = alt_bn128_pairing(&pairing_input)
+        .map_err(|_| error!(VotingError::InvalidMembershipProof))?;
+
+    require!(
+        result[..31] == [0u8; 31] && result[31] == 1,
+        VotingError::InvalidMembershipProof
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// BN254 G1 generator (1, 2).
+    fn generator() -> [u8; 64] {
+        let mut point = [0u8; 64];
+        point[31] = 1;
+        point[63] = 2;
+        point
+    }
+
+    fn multiply(point: &[u8; 64], scalar: u64) -> [u8; 64] {
+        let mut input = point.to_vec();
+        input.extend_from_slice(&[0u8; 24]);
+        input.extend_from_slice(&scalar.to_be_bytes());
+        alt_bn128_multiplication(&input)
+            .unwrap()
+            .try_into()
+            .unwrap()
+    }
+
+    #[test]
+    fn negate_g1_gives_the_opposite_point() {
+        for point in [generator(), multiply(&generator(), 7)] {
+            let negated = negate_g1(&point);
+            assert_eq!(negated[..32], point[..32]);
+            assert_eq!(
+                alt_bn128_addition(&[point, negated].concat()).unwrap(),
+                vec![0u8; 64]
+            );
+            assert_eq!(negate_g1(&negated), point);
+        }
+
+        let mut expected = generator();
+        expected[32..].copy_from_slice(&BASE_FIELD_MODULUS);
+        expected[63] -= 2;
+        assert_eq!(negate_g1(&generator()), expected);
+    }
+
+    #[test]
+    fn negate_g1_keeps_the_point_at_infinity() {
+        assert_eq!(negate_g1(&[0u8; 64]), [0u8; 64]);
+    }
+
+    #[test]
+    fn hash_to_field_matches_semaphore() {
+        // keccak256(bytes32(0)) >> 8
+        let mut expected = [0u8; 32];
+        expected[1..].copy_from_slice(&keccak::hash(&[0u8; 32]).to_bytes()[..31]);
+        assert_eq!(hash_to_field(0), expected);
+        assert_eq!(expected[..4], [0x00, 0x29, 0x0d, 0xec]);
+    }
+
+    #[test]
+    fn hash_to_field_gives_distinct_field_elements() {
+        let hashes: Vec<[u8; 32]> = [0, 1, 2, u64::MAX].map(hash_to_field).to_vec();
+
+        assert!(hashes.iter().all(is_field_element));
+        for (index, hash) in hashes.iter().enumerate() {
+            assert!(!hashes[index + 1..].contains(hash));
+        }
+    }
+
+    #[test]
+    fn verifying_key_hash_covers_every_point() {
+        let key = VerifyingKeyAccount {
+            alpha_g1: generator(),
+            beta_g2: [1; 128],
+            gamma_g2: [2; 128],
+            delta_g2: [3; 128],
+            ic: [generator(); 5],
+        };
+
+        let mut other = key.clone();
+        assert_eq!(other.hash(), key.hash());
+
+        other.delta_g2[0] = 4;
+        assert_ne!(other.hash(), key.hash());
+        let mut other = key.clone();
+        other.ic[4] = multiply(&generator(), 2);
+        assert_ne!(other.hash(), key.hash());
+    }
+}
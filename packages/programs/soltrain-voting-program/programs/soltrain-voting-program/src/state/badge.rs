@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+pub const BADGE_MINT_SEED_PREFIX: &[u8; 10] = b"badge_mint";
+pub const BADGE_SYMBOL: &str = "VOTED";
+pub const BADGE_SESSION_ID_KEY: &str = "session_id";
+
+#[event]
+pub struct BadgesEnabled {
+    pub session_id: u64,
+    pub mint: Pubkey,
+}
+
+#[event]
+pub struct BadgeMinted {
+    pub session_id: u64,
+    pub voter: Pubkey,
+    pub mint: Pubkey,
+}
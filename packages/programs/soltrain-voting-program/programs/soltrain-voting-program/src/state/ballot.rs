@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+pub const MAX_SCORE: u8 = 10;
+
+#[account]
+pub struct BallotAccount {
+    // 8 discriminator
+    pub session_id: u64,           // 8
+    pub voter: Pubkey,             // 32
+    pub entries: Vec<BallotEntry>, // 4 (prefix) + 2 per entry
+}
+
+impl BallotAccount {
+    pub const SEED_PREFIX: &'static [u8; 6] = b"ballot";
+
+    pub fn space(entries_count: usize) -> usize {
+        8 + 8 + 32 + 4 + entries_count * BallotEntry::INIT_SPACE
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct BallotEntry {
+    pub proposal_id: u8, // 1
+    pub value: u8,       // 1
+}
+
+#[event]
+pub struct BallotCast {
+    pub session_id: u64,
+    pub voter: Pubkey,
+    pub entries: Vec<BallotEntry>,
+}
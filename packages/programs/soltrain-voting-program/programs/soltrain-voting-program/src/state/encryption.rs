@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+pub const MAX_TRUSTEES: usize = 10;
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct EncryptionConfig {
+    pub election_public_key: [u8; 32], // 32, compressed ristretto point H = s·G
+    pub threshold: u8,                 // 1, trustees needed to decrypt
+    #[max_len(MAX_TRUSTEES)]
+    pub trustees: Vec<Trustee>, // 4 (prefix) + 10 trustees, share index is trustee index + 1
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct Trustee {
+    pub authority: Pubkey,          // 32
+    pub verification_key: [u8; 32], // 32, compressed ristretto point Y_i = s_i·G
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct DecryptionShare {
+    pub trustee_index: u8, // 1
+    pub share: [u8; 32],   // 32, compressed ristretto point D_i = s_i·C1
+}
+
+/// Exponential ElGamal ciphertext (C1, C2) = (r·G, m·G + r·H), compressed ristretto points.
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct Ciphertext {
+    pub c1: [u8; 32], // 32
+    pub c2: [u8; 32], // 32
+}
+
+/// Chaum-Pedersen proof that log_g1(y1) == log_g2(y2).
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct EqualityProof {
+    pub commitments: [[u8; 32]; 2],
+    pub response: [u8; 32],
+}
+
+/// Disjunctive Chaum-Pedersen proof that a ciphertext encrypts 0 or 1.
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct BitProof {
+    pub commitments: [[u8; 32]; 4], // (a0, b0, a1, b1)
+    pub challenges: [[u8; 32]; 2],
+    pub responses: [[u8; 32]; 2],
+}
+
+#[account]
+pub struct EncryptedBallotAccount {
+    // 8 discriminator
+    pub session_id: u64,              // 8
+    pub voter: Pubkey,                // 32
+    pub sealed: bool,                 // 1
+    pub ciphertexts: Vec<Ciphertext>, // 4 (prefix) + 64 per proposal, in proposal order
+}
+
+impl EncryptedBallotAccount {
+    pub const SEED_PREFIX: &'static [u8; 16] = b"encrypted_ballot";
+
+    pub fn space(proposals_count: usize) -> usize {
+        8 + 8 + 32 + 1 + 4 + proposals_count * Ciphertext::INIT_SPACE
+    }
+}
+
+#[event]
+pub struct EncryptionConfigured {
+    pub session_id: u64,
+    pub election_public_key: [u8; 32],
+    pub threshold: u8,
+    pub trustees: Vec<Pubkey>,
+}
+
+#[event]
+pub struct EncryptedBallotSealed {
+    pub session_id: u64,
+    pub voter: Pubkey,
+}
+
+#[event]
+pub struct DecryptionSharePosted {
+    pub session_id: u64,
+    pub proposal_id: u8,
+    pub trustee_index: u8,
+}
+
+#[event]
+pub struct ProposalVotesRevealed {
+    pub session_id: u64,
+    pub proposal_id: u8,
+    pub vote_count: u32,
+}
@@ -3,9 +3,37 @@ use anchor_lang::prelude::*;
 #[account]
 #[derive(InitSpace)]
 pub struct GlobalAccount {
-    pub session_count: u64,
+    // 8 discriminator
+    pub session_count: u64, // 8
+    pub authority: Pubkey,  // 32
+    pub config: GlobalConfig,
+    pub paused: bool,            // 1
+    pub organization_count: u64, // 8
 }
 
 impl GlobalAccount {
     pub const SEED: &'static [u8; 6] = b"global";
 }
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct GlobalConfig {
+    pub session_fee: u64,        // 8, lamports paid to authority on session creation
+    pub max_name_len: u8,        // 1
+    pub max_description_len: u8, // 1
+    pub max_voters: u32,         // 4
+}
+
+#[event]
+pub struct GlobalConfigUpdated {
+    pub authority: Pubkey,
+    pub session_fee: u64,
+    pub max_name_len: u8,
+    pub max_description_len: u8,
+    pub max_voters: u32,
+}
+
+#[event]
+pub struct PausedChanged {
+    pub authority: Pubkey,
+    pub paused: bool,
+}
@@ -0,0 +1,148 @@
+use anchor_lang::{prelude::*, solana_program::hash::hashv};
+
+use crate::{errors::VotingError, semaphore::hash_pair};
+
+pub const IDENTITY_TREE_DEPTH: usize = 16;
+// merkle tree root, nullifier hash, signal hash and external nullifier
+pub const MEMBERSHIP_PUBLIC_INPUTS: usize = 4;
+
+#[account]
+#[derive(InitSpace)]
+pub struct IdentityTreeAccount {
+    // 8 discriminator
+    pub session_id: u64,              // 8
+    pub leaves_count: u32,            // 4
+    pub root: [u8; 32],               // 32
+    pub verifying_key_hash: [u8; 32], // 32, key the session votes are verified with
+    // 512, last left node of each level, to insert leaves incrementally
+    pub filled_subtrees: [[u8; 32]; IDENTITY_TREE_DEPTH],
+    // 512, root of an empty subtree of each level
+    pub zeros: [[u8; 32]; IDENTITY_TREE_DEPTH],
+}
+
+impl IdentityTreeAccount {
+    pub const SEED_PREFIX: &'static [u8; 13] = b"identity_tree";
+
+    /// Sets up an empty tree, leaves being 0.
+    pub fn initialize(&mut self, session_id: u64) -> Result<()> {
+        self.session_id = session_id;
+        self.leaves_count = 0;
+
+        let mut zero = [0u8; 32];
+        for level in 0..IDENTITY_TREE_DEPTH {
+            self.zeros[level] = zero;
+            self.filled_subtrees[level] = zero;
+            zero = hash_pair(&zero, &zero)?;
+        }
+        self.root = zero;
+        Ok(())
+    }
+
+    /// Appends a leaf and updates the root, returning the leaf index.
+    pub fn insert(&mut self, leaf: [u8; 32]) -> Result<u32> {
+        let leaf_index = self.leaves_count;
+        require!(
+            (leaf_index as usize) < 1 << IDENTITY_TREE_DEPTH,
+            VotingError::IdentityTreeFull
+        );
+
+        let mut index = leaf_index;
+        let mut node = leaf;
+        for level in 0..IDENTITY_TREE_DEPTH {
+            node = if index & 1 == 0 {
+                self.filled_subtrees[level] = node;
+                hash_pair(&node, &self.zeros[level])?
+            } else {
+                hash_pair(&self.filled_subtrees[level], &node)?
+            };
+            index >>= 1;
+        }
+
+        self.root = node;
+        self.leaves_count += 1;
+        Ok(leaf_index)
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct NullifierAccount {
+    // 8 discriminator
+    pub session_id: u64,          // 8
+    pub nullifier_hash: [u8; 32], // 32
+}
+
+impl NullifierAccount {
+    pub const SEED_PREFIX: &'static [u8; 9] = b"nullifier";
+}
+
+/// Groth16 verifying key of the Semaphore membership circuit, for the identity tree depth.
+/// Points are big endian, G2 coordinates being (imaginary, real) as in EIP-197.
+#[account]
+#[derive(InitSpace)]
+pub struct VerifyingKeyAccount {
+    // 8 discriminator
+    pub alpha_g1: [u8; 64],                           // 64
+    pub beta_g2: [u8; 128],                           // 128
+    pub gamma_g2: [u8; 128],                          // 128
+    pub delta_g2: [u8; 128],                          // 128
+    pub ic: [[u8; 64]; MEMBERSHIP_PUBLIC_INPUTS + 1], // 320
+}
+
+impl VerifyingKeyAccount {
+    pub const SEED: &'static [u8; 13] = b"verifying_key";
+
+    /// Hash of the key points, snapshotted by the sessions voting with the key.
+    pub fn hash(&self) -> [u8; 32] {
+        let mut data: Vec<&[u8]> = vec![
+            &self.alpha_g1,
+            &self.beta_g2,
+            &self.gamma_g2,
+            &self.delta_g2,
+        ];
+        data.extend(self.ic.iter().map(|point| point.as_slice()));
+        hashv(&data).to_bytes()
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct Groth16Proof {
+    pub a: [u8; 64],
+    pub b: [u8; 128],
+    pub c: [u8; 64],
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct VerifyingKey {
+    pub alpha_g1: [u8; 64],
+    pub beta_g2: [u8; 128],
+    pub gamma_g2: [u8; 128],
+    pub delta_g2: [u8; 128],
+    pub ic: [[u8; 64]; MEMBERSHIP_PUBLIC_INPUTS + 1],
+}
+
+#[event]
+pub struct VerifyingKeyUpdated {
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct AnonymousVotingConfigured {
+    pub session_id: u64,
+    pub root: [u8; 32],
+}
+
+#[event]
+pub struct IdentityCommitmentAdded {
+    pub session_id: u64,
+    pub identity_commitment: [u8; 32],
+    pub leaf_index: u32,
+    pub root: [u8; 32],
+}
+
+#[event]
+pub struct AnonymousVoted {
+    pub session_id: u64,
+    pub proposal_id: u8,
+    pub nullifier_hash: [u8; 32],
+}
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+
+/// Longest lock duration, giving a voting power equal to the locked amount.
+pub const MAX_LOCK_DURATION: i64 = 4 * 365 * 24 * 60 * 60;
+
+#[account]
+#[derive(InitSpace)]
+pub struct LockAccount {
+    // 8 discriminator
+    pub owner: Pubkey,   // 32
+    pub mint: Pubkey,    // 32
+    pub amount: u64,     // 8
+    pub unlock_at: i64,  // 8
+    pub updated_at: i64, // 8
+    pub bump: u8,        // 1
+}
+
+impl LockAccount {
+    pub const SEED_PREFIX: &'static [u8; 4] = b"lock";
+
+    /// Voting power at `timestamp`, decaying linearly with the remaining lock time.
+    pub fn voting_power(&self, timestamp: i64) -> u64 {
+        lock_voting_power(self.amount, self.unlock_at, timestamp)
+    }
+}
+
+/// Voting power at `timestamp` of `amount` tokens locked until `unlock_at`.
+pub fn lock_voting_power(amount: u64, unlock_at: i64, timestamp: i64) -> u64 {
+    let remaining = (unlock_at - timestamp).clamp(0, MAX_LOCK_DURATION);
+    (amount as u128 * remaining as u128 / MAX_LOCK_DURATION as u128) as u64
+}
+
+#[event]
+pub struct TokensLocked {
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub unlock_at: i64,
+}
+
+#[event]
+pub struct TokensUnlocked {
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct VotingPowerApplied {
+    pub session_id: u64,
+    pub voter: Pubkey,
+    pub weight: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: i64 = 1_700_000_000;
+
+    #[test]
+    fn voting_power_scales_with_remaining_lock_time() {
+        assert_eq!(
+            lock_voting_power(1_000, NOW + MAX_LOCK_DURATION, NOW),
+            1_000
+        );
+        assert_eq!(
+            lock_voting_power(1_000, NOW + MAX_LOCK_DURATION / 2, NOW),
+            500
+        );
+        assert_eq!(
+            lock_voting_power(1_000, NOW + MAX_LOCK_DURATION / 4, NOW),
+            250
+        );
+    }
+
+    #[test]
+    fn voting_power_decays_linearly() {
+        let unlock_at = NOW + MAX_LOCK_DURATION;
+
+        let powers: Vec<u64> = (0..=4)
+            .map(|quarter| lock_voting_power(400, unlock_at, NOW + quarter * MAX_LOCK_DURATION / 4))
+            .collect();
+        assert_eq!(powers, vec![400, 300, 200, 100, 0]);
+    }
+
+    #[test]
+    fn voting_power_is_bounded() {
+        // no power once unlocked, no bonus beyond the longest lock
+        assert_eq!(lock_voting_power(1_000, NOW, NOW), 0);
+        assert_eq!(lock_voting_power(1_000, NOW - 1, NOW), 0);
+        assert_eq!(
+            lock_voting_power(1_000, NOW + 2 * MAX_LOCK_DURATION, NOW),
+            1_000
+        );
+        assert_eq!(
+            lock_voting_power(u64::MAX, NOW + MAX_LOCK_DURATION, NOW),
+            u64::MAX
+        );
+    }
+}
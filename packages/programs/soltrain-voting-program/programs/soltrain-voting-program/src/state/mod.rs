@@ -1,4 +1,19 @@
+pub mod badge;
+pub mod ballot;
+pub mod encryption;
 pub mod global;
+pub mod identity;
+pub mod lock;
+pub mod organization;
+pub mod pairwise;
 pub mod proposal;
+pub mod proposal_transaction;
+pub mod question;
+pub mod receipt;
+pub mod reward;
+pub mod session_index;
+pub mod snapshot;
+pub mod treasury;
+pub mod vault;
 pub mod voter;
 pub mod voting_session;
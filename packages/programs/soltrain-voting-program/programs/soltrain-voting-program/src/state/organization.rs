@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+pub const MAX_ORGANIZATION_NAME_LEN: usize = 32;
+pub const MAX_ORGANIZATION_ADMINS: usize = 10;
+
+#[account]
+#[derive(InitSpace)]
+pub struct OrganizationAccount {
+    // 8 discriminator
+    pub organization_id: u64, // 8
+    #[max_len(MAX_ORGANIZATION_NAME_LEN)]
+    pub name: String, // 32
+    #[max_len(MAX_ORGANIZATION_ADMINS)]
+    pub admins: Vec<Pubkey>, // 4 (prefix) + 10 * 32
+    pub members_count: u32,   // 4, active members
+    pub members_seq: u32,     // 4, last assigned member id
+}
+
+impl OrganizationAccount {
+    pub const SEED_PREFIX: &'static [u8; 12] = b"organization";
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct OrganizationMemberAccount {
+    // 8 discriminator
+    pub organization_id: u64, // 8
+    pub member: Pubkey,       // 32
+    pub member_id: u32,       // 4
+    pub active: bool,         // 1
+}
+
+impl OrganizationMemberAccount {
+    pub const SEED_PREFIX: &'static [u8; 6] = b"member";
+}
+
+#[event]
+pub struct OrganizationCreated {
+    pub organization_id: u64,
+    pub name: String,
+    pub admin: Pubkey,
+}
+
+#[event]
+pub struct OrganizationAdminAdded {
+    pub organization_id: u64,
+    pub admin: Pubkey,
+}
+
+#[event]
+pub struct OrganizationAdminRemoved {
+    pub organization_id: u64,
+    pub admin: Pubkey,
+}
+
+#[event]
+pub struct OrganizationMemberAdded {
+    pub organization_id: u64,
+    pub member: Pubkey,
+    pub member_id: u32,
+}
+
+#[event]
+pub struct OrganizationMemberRemoved {
+    pub organization_id: u64,
+    pub member: Pubkey,
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+pub const MAX_RANKED_PROPOSALS: usize = 16;
+
+#[account]
+#[derive(InitSpace)]
+pub struct PairwiseMatrixAccount {
+    // 8 discriminator
+    pub session_id: u64,      // 8
+    pub candidates_count: u8, // 1, proposals 2.. are the candidates
+    // 1024, preferences[a][b] counts ballots ranking a over b
+    pub preferences: [[u32; MAX_RANKED_PROPOSALS]; MAX_RANKED_PROPOSALS],
+}
+
+impl PairwiseMatrixAccount {
+    pub const SEED_PREFIX: &'static [u8; 8] = b"pairwise";
+
+    /// Candidate index of a proposal, the blank proposal not being ranked.
+    pub fn candidate_index(proposal_id: u8) -> usize {
+        proposal_id as usize - 2
+    }
+}
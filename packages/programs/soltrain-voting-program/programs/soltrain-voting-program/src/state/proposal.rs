@@ -1,14 +1,37 @@
 use anchor_lang::prelude::*;
 
+use crate::state::{
+    ballot::MAX_SCORE,
+    encryption::{Ciphertext, DecryptionShare, MAX_TRUSTEES},
+    treasury::TreasuryTransfer,
+    voting_session::MAX_METADATA_URI_LEN,
+};
+
+pub const MAX_PROPOSAL_DESCRIPTION_LEN: usize = 255;
+
 #[account]
 #[derive(InitSpace)]
 pub struct ProposalAccount {
     pub session_id: u64, // 8
     pub proposal_id: u8, // 1
-    #[max_len(255)]
+    #[max_len(MAX_PROPOSAL_DESCRIPTION_LEN)]
     pub description: String, // 255
     pub proposer: Pubkey, // 32
     pub vote_count: u32, // 4
+    pub deposit_amount: u64, // 8
+    pub deposit_settled: bool, // 1
+    pub treasury_transfer: Option<TreasuryTransfer>, // 1 + 74
+    #[max_len(MAX_METADATA_URI_LEN)]
+    pub metadata_uri: String, // 200
+    pub metadata_hash: [u8; 32], // 32, SHA-256 of the off-chain document
+    pub question_id: u8, // 1
+    pub score_sum: u64,  // 8
+    pub score_count: u32, // 4, weight of the ballots scoring or allocating votes to this proposal
+    pub score_distribution: [u32; MAX_SCORE as usize + 1], // 44, ballots count per score
+    pub encrypted_votes: Ciphertext, // 64, homomorphic sum of the encrypted ballots
+    #[max_len(MAX_TRUSTEES)]
+    pub decryption_shares: Vec<DecryptionShare>, // 4 (prefix) + 33 per trustee share
+    pub votes_revealed: bool, // 1, vote_count is decrypted in encrypted sessions
 }
 
 impl ProposalAccount {
@@ -20,6 +43,8 @@ pub struct ProposalRegistered {
     pub session_id: u64,
     pub proposal_id: u8,
     pub description: String,
+    pub metadata_uri: String,
+    pub metadata_hash: [u8; 32],
 }
 
 pub struct WinningProposal {
@@ -0,0 +1,143 @@
+use anchor_lang::{prelude::*, solana_program::instruction::Instruction};
+
+#[account]
+pub struct ProposalTransactionAccount {
+    // 8 discriminator
+    pub session_id: u64,                        // 8
+    pub proposal_id: u8,                        // 1
+    pub executed: bool,                         // 1
+    pub instructions: Vec<ProposalInstruction>, // 4 (prefix) + instructions
+}
+
+impl ProposalTransactionAccount {
+    pub const SEED_PREFIX: &'static [u8; 20] = b"proposal_transaction";
+    pub const EXECUTOR_SEED_PREFIX: &'static [u8; 8] = b"executor";
+
+    pub fn space(instructions: &[ProposalInstruction]) -> usize {
+        8 + 1
+            + 1
+            + 4
+            + instructions
+                .iter()
+                .map(ProposalInstruction::space)
+                .sum::<usize>()
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct ProposalInstruction {
+    pub program_id: Pubkey,                 // 32
+    pub accounts: Vec<ProposalAccountMeta>, // 4 (prefix) + 34 per account
+    pub data: Vec<u8>,                      // 4 (prefix) + data
+}
+
+impl ProposalInstruction {
+    pub fn space(&self) -> usize {
+        32 + 4 + self.accounts.len() * ProposalAccountMeta::INIT_SPACE + 4 + self.data.len()
+    }
+}
+
+impl From<&ProposalInstruction> for Instruction {
+    fn from(instruction: &ProposalInstruction) -> Self {
+        Instruction {
+            program_id: instruction.program_id,
+            accounts: instruction
+                .accounts
+                .iter()
+                .map(|meta| AccountMeta {
+                    pubkey: meta.pubkey,
+                    is_signer: meta.is_signer,
+                    is_writable: meta.is_writable,
+                })
+                .collect(),
+            data: instruction.data.clone(),
+        }
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct ProposalAccountMeta {
+    pub pubkey: Pubkey,    // 32
+    pub is_signer: bool,   // 1
+    pub is_writable: bool, // 1
+}
+
+#[event]
+pub struct ProposalTransactionAttached {
+    pub session_id: u64,
+    pub proposal_id: u8,
+    pub instructions_count: u8,
+}
+
+#[event]
+pub struct ProposalExecuted {
+    pub session_id: u64,
+    pub proposal_id: u8,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transfer_instruction(data_len: usize) -> ProposalInstruction {
+        ProposalInstruction {
+            program_id: Pubkey::new_unique(),
+            accounts: vec![
+                ProposalAccountMeta {
+                    pubkey: Pubkey::new_unique(),
+                    is_signer: true,
+                    is_writable: true,
+                },
+                ProposalAccountMeta {
+                    pubkey: Pubkey::new_unique(),
+                    is_signer: false,
+                    is_writable: true,
+                },
+            ],
+            data: vec![7; data_len],
+        }
+    }
+
+    #[test]
+    fn instruction_space_is_its_serialized_size() {
+        for data_len in [0, 12, 255] {
+            let instruction = transfer_instruction(data_len);
+            assert_eq!(instruction.space(), instruction.try_to_vec().unwrap().len());
+        }
+    }
+
+    #[test]
+    fn transaction_space_fits_serialized_account() {
+        let instructions = vec![transfer_instruction(12), transfer_instruction(40)];
+        let account = ProposalTransactionAccount {
+            session_id: 3,
+            proposal_id: 2,
+            executed: false,
+            instructions: instructions.clone(),
+        };
+
+        assert_eq!(
+            ProposalTransactionAccount::space(&instructions),
+            account.try_to_vec().unwrap().len()
+        );
+    }
+
+    #[test]
+    fn proposal_instruction_converts_to_instruction() {
+        let proposal_instruction = transfer_instruction(12);
+        let instruction = Instruction::from(&proposal_instruction);
+
+        assert_eq!(instruction.program_id, proposal_instruction.program_id);
+        assert_eq!(instruction.data, proposal_instruction.data);
+        assert_eq!(instruction.accounts.len(), 2);
+        for (meta, proposal_meta) in instruction
+            .accounts
+            .iter()
+            .zip(proposal_instruction.accounts.iter())
+        {
+            assert_eq!(meta.pubkey, proposal_meta.pubkey);
+            assert_eq!(meta.is_signer, proposal_meta.is_signer);
+            assert_eq!(meta.is_writable, proposal_meta.is_writable);
+        }
+    }
+}
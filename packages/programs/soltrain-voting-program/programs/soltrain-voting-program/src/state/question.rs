@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+pub const MAX_QUESTIONS: usize = 16;
+pub const MAX_QUESTION_TITLE_LEN: usize = 80;
+
+#[account]
+#[derive(InitSpace)]
+pub struct QuestionAccount {
+    // 8 discriminator
+    pub session_id: u64, // 8
+    pub question_id: u8, // 1
+    #[max_len(MAX_QUESTION_TITLE_LEN)]
+    pub title: String, // 80
+    pub blank_proposal_id: u8, // 1
+    pub result: QuestionResult, // 531, set when votes are tallied
+}
+
+impl QuestionAccount {
+    pub const SEED_PREFIX: &'static [u8; 8] = b"question";
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct QuestionResult {
+    pub question_id: u8,  // 1
+    pub total_votes: u32, // 4
+    pub blank_votes: u32, // 4
+    pub abstention: u32,  // 4
+    #[max_len(255)]
+    pub winning_proposals: Vec<u8>, // 4 (prefix) + 255 bytes, the elected proposal
+    #[max_len(255)]
+    pub tied_proposals: Vec<u8>, // 4 (prefix) + 255 bytes, proposals tied at the top, not elected
+}
+
+#[event]
+pub struct QuestionAdded {
+    pub session_id: u64,
+    pub question_id: u8,
+    pub title: String,
+    pub blank_proposal_id: u8,
+}
@@ -0,0 +1,199 @@
+use anchor_lang::{prelude::*, solana_program::hash::hashv};
+
+use crate::{errors::VotingError, state::ballot::BallotEntry};
+
+pub const RECEIPT_TREE_DEPTH: usize = 20;
+
+#[account]
+#[derive(InitSpace)]
+pub struct ReceiptAccumulatorAccount {
+    // 8 discriminator
+    pub session_id: u64,     // 8
+    pub receipts_count: u32, // 4
+    pub root: [u8; 32],      // 32
+    // 640, last left node of each level, to append receipts incrementally
+    pub filled_subtrees: [[u8; 32]; RECEIPT_TREE_DEPTH],
+    // 640, root of an empty subtree of each level
+    pub zeros: [[u8; 32]; RECEIPT_TREE_DEPTH],
+}
+
+impl ReceiptAccumulatorAccount {
+    pub const SEED_PREFIX: &'static [u8; 8] = b"receipts";
+
+    /// Sets up an empty accumulator, leaves being 0.
+    pub fn initialize(&mut self, session_id: u64) {
+        self.session_id = session_id;
+        self.receipts_count = 0;
+
+        let mut zero = [0u8; 32];
+        for level in 0..RECEIPT_TREE_DEPTH {
+            self.zeros[level] = zero;
+            self.filled_subtrees[level] = zero;
+            zero = hashv(&[&zero, &zero]).to_bytes();
+        }
+        self.root = zero;
+    }
+
+    /// Appends a receipt and updates the root, returning the receipt index.
+    pub fn append(&mut self, receipt: [u8; 32]) -> Result<u32> {
+        let receipt_index = self.receipts_count;
+        require!(
+            (receipt_index as usize) < 1 << RECEIPT_TREE_DEPTH,
+            VotingError::ReceiptAccumulatorFull
+        );
+
+        let mut index = receipt_index;
+        let mut node = receipt;
+        for level in 0..RECEIPT_TREE_DEPTH {
+            node = if index & 1 == 0 {
+                self.filled_subtrees[level] = node;
+                hashv(&[&node, &self.zeros[level]]).to_bytes()
+            } else {
+                hashv(&[&self.filled_subtrees[level], &node]).to_bytes()
+            };
+            index >>= 1;
+        }
+
+        self.root = node;
+        self.receipts_count += 1;
+        Ok(receipt_index)
+    }
+
+    /// Appends the receipt of a ballot cast at the current slot.
+    pub fn add_receipt(&mut self, voter: Pubkey, ballot: Vec<u8>) -> Result<()> {
+        let slot = Clock::get()?.slot;
+        let receipt = vote_receipt(self.session_id, &voter, &ballot, slot);
+        let receipt_index = self.append(receipt)?;
+
+        emit!(VoteReceiptAdded {
+            session_id: self.session_id,
+            voter,
+            ballot,
+            slot,
+            receipt,
+            receipt_index,
+            root: self.root,
+        });
+        Ok(())
+    }
+}
+
+/// SHA-256 of the session id, voter, ballot and slot, integers being little endian.
+/// A plurality ballot is the voted proposal id, anonymous voters being their nullifier hash.
+pub fn vote_receipt(session_id: u64, voter: &Pubkey, ballot: &[u8], slot: u64) -> [u8; 32] {
+    hashv(&[
+        &session_id.to_le_bytes(),
+        voter.as_ref(),
+        ballot,
+        &slot.to_le_bytes(),
+    ])
+    .to_bytes()
+}
+
+/// Ballot of score, ranked, quadratic and cumulative votes, the (proposal id, value) pairs.
+pub fn entries_ballot(entries: &[BallotEntry]) -> Vec<u8> {
+    entries
+        .iter()
+        .flat_map(|entry| [entry.proposal_id, entry.value])
+        .collect()
+}
+
+#[event]
+pub struct VoteReceiptAdded {
+    pub session_id: u64,
+    pub voter: Pubkey,
+    pub ballot: Vec<u8>,
+    pub slot: u64,
+    pub receipt: [u8; 32],
+    pub receipt_index: u32,
+    pub root: [u8; 32],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn accumulator() -> ReceiptAccumulatorAccount {
+        let mut accumulator = ReceiptAccumulatorAccount {
+            session_id: 0,
+            receipts_count: 0,
+            root: [0; 32],
+            filled_subtrees: [[0; 32]; RECEIPT_TREE_DEPTH],
+            zeros: [[0; 32]; RECEIPT_TREE_DEPTH],
+        };
+        accumulator.initialize(1);
+        accumulator
+    }
+
+    /// Root of the full tree, leaves after `receipts` being 0.
+    fn merkle_root(receipts: &[[u8; 32]]) -> [u8; 32] {
+        let mut level = receipts.to_vec();
+        let mut zero = [0u8; 32];
+        for _ in 0..RECEIPT_TREE_DEPTH {
+            if level.len() % 2 == 1 {
+                level.push(zero);
+            }
+            level = level
+                .chunks(2)
+                .map(|pair| hashv(&[&pair[0], &pair[1]]).to_bytes())
+                .collect();
+            zero = hashv(&[&zero, &zero]).to_bytes();
+        }
+        level.first().copied().unwrap_or(zero)
+    }
+
+    #[test]
+    fn append_updates_the_root_incrementally() {
+        let mut accumulator = accumulator();
+        assert_eq!(accumulator.root, merkle_root(&[]));
+
+        let receipts: Vec<[u8; 32]> = (1..=5).map(|value| [value; 32]).collect();
+        for (index, receipt) in receipts.iter().enumerate() {
+            assert_eq!(accumulator.append(*receipt).unwrap(), index as u32);
+            assert_eq!(accumulator.root, merkle_root(&receipts[..=index]));
+        }
+        assert_eq!(accumulator.receipts_count, 5);
+    }
+
+    #[test]
+    fn append_rejects_receipts_once_full() {
+        let mut accumulator = accumulator();
+        accumulator.receipts_count = 1 << RECEIPT_TREE_DEPTH;
+
+        assert_eq!(
+            accumulator.append([1; 32]).unwrap_err(),
+            VotingError::ReceiptAccumulatorFull.into()
+        );
+    }
+
+    #[test]
+    fn ballots_are_hashed_in_receipts() {
+        let voter = Pubkey::new_unique();
+        let entries = [
+            BallotEntry {
+                proposal_id: 2,
+                value: 5,
+            },
+            BallotEntry {
+                proposal_id: 3,
+                value: 0,
+            },
+        ];
+
+        assert_eq!(entries_ballot(&entries), vec![2, 5, 3, 0]);
+        assert_eq!(
+            vote_receipt(1, &voter, &[2], 10),
+            hashv(&[
+                &1u64.to_le_bytes(),
+                voter.as_ref(),
+                &[2],
+                &10u64.to_le_bytes()
+            ])
+            .to_bytes()
+        );
+        assert_ne!(
+            vote_receipt(1, &voter, &[2], 10),
+            vote_receipt(1, &voter, &[2, 5], 10)
+        );
+    }
+}
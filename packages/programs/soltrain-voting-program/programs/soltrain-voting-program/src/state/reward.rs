@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+/// Longest claim window, opening when votes are tallied.
+pub const MAX_CLAIM_DURATION: i64 = 365 * 24 * 60 * 60;
+
+#[account]
+#[derive(InitSpace)]
+pub struct RewardPoolAccount {
+    // 8 discriminator
+    pub session_id: u64,       // 8
+    pub funder: Pubkey,        // 32
+    pub mint: Option<Pubkey>,  // 1 + 32, None means SOL rewards
+    pub reward_per_voter: u64, // 8
+    pub funded_amount: u64,    // 8
+    pub claimed_amount: u64,   // 8
+    pub claims_count: u32,     // 4
+    pub claim_duration: i64, // 8, claim window from the tally, unclaimed funds being returned afterwards
+    pub bump: u8,            // 1
+}
+
+impl RewardPoolAccount {
+    pub const SEED_PREFIX: &'static [u8; 11] = b"reward_pool";
+
+    /// Timestamp until which rewards can be claimed, for a session tallied at `tallied_at`.
+    pub fn claim_deadline(&self, tallied_at: i64) -> i64 {
+        tallied_at + self.claim_duration
+    }
+}
+
+#[event]
+pub struct RewardPoolFunded {
+    pub session_id: u64,
+    pub funder: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+    pub reward_per_voter: u64,
+    pub claim_duration: i64,
+}
+
+#[event]
+pub struct RewardClaimed {
+    pub session_id: u64,
+    pub voter: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+}
+
+#[event]
+pub struct RewardPoolReclaimed {
+    pub session_id: u64,
+    pub funder: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn claim_deadline_follows_the_tally() {
+        let reward_pool = RewardPoolAccount {
+            session_id: 1,
+            funder: Pubkey::new_unique(),
+            mint: None,
+            reward_per_voter: 10,
+            funded_amount: 100,
+            claimed_amount: 0,
+            claims_count: 0,
+            claim_duration: 3_600,
+            bump: 255,
+        };
+
+        assert_eq!(reward_pool.claim_deadline(1_700_000_000), 1_700_003_600);
+        assert_eq!(
+            reward_pool.claim_deadline(1_800_000_000),
+            1_800_000_000 + 3_600
+        );
+    }
+}
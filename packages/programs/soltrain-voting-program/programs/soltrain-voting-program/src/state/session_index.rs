@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+pub const SESSION_INDEX_PAGE_SIZE: usize = 32;
+
+#[account]
+#[derive(InitSpace)]
+pub struct SessionIndexAccount {
+    // 8 discriminator
+    pub owner: Pubkey,       // 32
+    pub sessions_count: u32, // 4
+}
+
+impl SessionIndexAccount {
+    pub const ADMIN_SEED_PREFIX: &'static [u8; 14] = b"admin_sessions";
+    pub const VOTER_SEED_PREFIX: &'static [u8; 14] = b"voter_sessions";
+
+    pub fn current_page(&self) -> u32 {
+        self.sessions_count / SESSION_INDEX_PAGE_SIZE as u32
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct SessionIndexPageAccount {
+    // 8 discriminator
+    pub owner: Pubkey, // 32
+    pub page: u32,     // 4
+    #[max_len(SESSION_INDEX_PAGE_SIZE)]
+    pub session_ids: Vec<u64>, // 4 (prefix) + 32 * 8
+}
@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::VotingError, state::lock::lock_voting_power};
+
+#[account]
+#[derive(InitSpace)]
+pub struct VoterSnapshotAccount {
+    // 8 discriminator
+    pub session_id: u64, // 8
+    pub voter: Pubkey,   // 32
+    pub slot: u64,       // 8, slot the snapshot was taken at
+    pub amount: u64,     // 8, locked amount
+    pub unlock_at: i64,  // 8
+}
+
+impl VoterSnapshotAccount {
+    pub const SEED_PREFIX: &'static [u8; 8] = b"snapshot";
+
+    /// Voting power at `timestamp` of the snapshotted lock.
+    pub fn voting_power(&self, timestamp: i64) -> u64 {
+        lock_voting_power(self.amount, self.unlock_at, timestamp)
+    }
+
+    /// Voter weight in whole tokens at `timestamp`, for a session opened at `snapshot_slot`.
+    pub fn weight(&self, snapshot_slot: u64, timestamp: i64, decimals: u8) -> Result<u32> {
+        // power is read from the snapshot taken before voting opened, snapshots being closed once
+        // voting opens, a snapshot of the opening slot was taken before it
+        require!(self.slot <= snapshot_slot, VotingError::SnapshotTooLate);
+
+        let power = self.voting_power(timestamp) / 10u64.pow(decimals as u32);
+        let weight = power.min(u32::MAX as u64) as u32;
+        require!(weight > 0, VotingError::NoVotingPower);
+
+        Ok(weight)
+    }
+}
+
+#[event]
+pub struct VoterSnapshotTaken {
+    pub session_id: u64,
+    pub voter: Pubkey,
+    pub slot: u64,
+    pub amount: u64,
+    pub unlock_at: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::lock::MAX_LOCK_DURATION;
+
+    const NOW: i64 = 1_700_000_000;
+    const SNAPSHOT_SLOT: u64 = 100;
+
+    fn snapshot(slot: u64, amount: u64) -> VoterSnapshotAccount {
+        VoterSnapshotAccount {
+            session_id: 1,
+            voter: Pubkey::new_unique(),
+            slot,
+            amount,
+            unlock_at: NOW + MAX_LOCK_DURATION,
+        }
+    }
+
+    #[test]
+    fn weight_is_counted_in_whole_tokens() {
+        let snapshot = snapshot(SNAPSHOT_SLOT - 1, 2_500_000);
+
+        assert_eq!(snapshot.weight(SNAPSHOT_SLOT, NOW, 6).unwrap(), 2);
+        assert_eq!(snapshot.weight(SNAPSHOT_SLOT, NOW, 0).unwrap(), 2_500_000);
+        // power decays until the session opening timestamp
+        assert_eq!(
+            snapshot
+                .weight(SNAPSHOT_SLOT, NOW + MAX_LOCK_DURATION / 2, 6)
+                .unwrap(),
+            1
+        );
+    }
+
+    #[test]
+    fn weight_is_bounded() {
+        let snapshot = snapshot(SNAPSHOT_SLOT - 1, u64::MAX);
+
+        assert_eq!(snapshot.weight(SNAPSHOT_SLOT, NOW, 0).unwrap(), u32::MAX);
+    }
+
+    #[test]
+    fn weight_requires_snapshot_before_opening() {
+        for slot in [SNAPSHOT_SLOT + 1, SNAPSHOT_SLOT + 2] {
+            assert_eq!(
+                snapshot(slot, 1_000)
+                    .weight(SNAPSHOT_SLOT, NOW, 0)
+                    .unwrap_err(),
+                VotingError::SnapshotTooLate.into()
+            );
+        }
+    }
+
+    #[test]
+    fn weight_accepts_snapshot_of_the_opening_slot() {
+        assert_eq!(
+            snapshot(SNAPSHOT_SLOT, 1_000)
+                .weight(SNAPSHOT_SLOT, NOW, 0)
+                .unwrap(),
+            1_000
+        );
+    }
+
+    #[test]
+    fn weight_requires_voting_power() {
+        // less than one whole token, or an expired lock
+        assert_eq!(
+            snapshot(SNAPSHOT_SLOT - 1, 999_999)
+                .weight(SNAPSHOT_SLOT, NOW, 6)
+                .unwrap_err(),
+            VotingError::NoVotingPower.into()
+        );
+        assert_eq!(
+            snapshot(SNAPSHOT_SLOT - 1, 1_000)
+                .weight(SNAPSHOT_SLOT, NOW + MAX_LOCK_DURATION, 0)
+                .unwrap_err(),
+            VotingError::NoVotingPower.into()
+        );
+    }
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct TreasuryAccount {
+    // 8 discriminator
+    pub session_id: u64, // 8
+    pub bump: u8,        // 1
+}
+
+impl TreasuryAccount {
+    pub const SEED_PREFIX: &'static [u8; 8] = b"treasury";
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct TreasuryTransfer {
+    pub recipient: Pubkey,    // 32
+    pub mint: Option<Pubkey>, // 1 + 32, None means SOL transfer
+    pub amount: u64,          // 8
+    pub executed: bool,       // 1
+}
+
+#[event]
+pub struct TreasuryDeposited {
+    pub session_id: u64,
+    pub depositor: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+}
+
+#[event]
+pub struct TreasuryTransferRequested {
+    pub session_id: u64,
+    pub proposal_id: u8,
+    pub recipient: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+}
+
+#[event]
+pub struct TreasuryWithdrawn {
+    pub session_id: u64,
+    pub proposal_id: u8,
+    pub recipient: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+}
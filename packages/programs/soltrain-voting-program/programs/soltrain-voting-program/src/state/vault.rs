@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct VaultAccount {
+    // 8 discriminator
+    pub session_id: u64, // 8
+    pub bump: u8,        // 1
+}
+
+impl VaultAccount {
+    pub const SEED_PREFIX: &'static [u8; 5] = b"vault";
+}
+
+#[event]
+pub struct ProposalDepositEscrowed {
+    pub session_id: u64,
+    pub proposal_id: u8,
+    pub proposer: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ProposalDepositSettled {
+    pub session_id: u64,
+    pub proposal_id: u8,
+    pub refunded: bool,
+    pub amount: u64,
+}
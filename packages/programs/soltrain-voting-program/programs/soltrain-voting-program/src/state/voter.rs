@@ -1,14 +1,27 @@
 use anchor_lang::{prelude::*, solana_program::program_pack::IsInitialized};
 
+use crate::state::{question::MAX_QUESTIONS, voting_session::MAX_SEATS};
+
 #[account]
 #[derive(InitSpace)]
-pub struct VoterAccount { // 8 discriminator
-    pub session_id: u64, // 8
-    pub voter: Pubkey, // 32
-    pub voter_id: u32, // 4
-    pub has_voted: bool, // 1
-    pub voted_proposal_id: u8, // 1
-    pub nb_proposals: u8, // 1
+pub struct VoterAccount {
+    // 8 discriminator
+    pub session_id: u64,                     // 8
+    pub voter: Pubkey,                       // 32
+    pub voter_id: u32,                       // 4
+    pub has_voted: bool,                     // 1
+    pub voted_proposal_id: u8,               // 1
+    pub nb_proposals: u8,                    // 1
+    pub question_votes: [u8; MAX_QUESTIONS], // 16, voted proposal id per question, 0 when not voted
+    pub seat_votes: [u8; MAX_SEATS],         // 10, main question voted proposal ids in block voting
+    pub credits: Option<u32>, // 1 + 4, quadratic credits budget, the session credits when none
+    pub credits_spent: u32,   // 4, quadratic credits or cumulative points allocated by the voter
+    pub weight: u32,          // 4, votes count of each voter vote
+    pub nonce: u64,           // 8, next nonce expected in a signed ballot
+    pub identity_commitment: [u8; 32], // 32, identity tree leaf, 0 when not registered
+    pub reward_claimed: bool, // 1, participation reward claimed
+    pub badge_minted: bool,   // 1, participation badge minted
+    pub power_applied: bool,  // 1, locked tokens voting power applied as weight
 }
 
 impl VoterAccount {
@@ -25,11 +38,20 @@ impl IsInitialized for VoterAccount {
 pub struct VoterRegistered {
     pub session_id: u64,
     pub voter: Pubkey,
+    pub weight: u32,
+}
+
+#[event]
+pub struct SignedBallotsRelayed {
+    pub session_id: u64,
+    pub relayed_count: u32,
+    pub skipped_voters: Vec<Pubkey>,
 }
 
 #[event]
 pub struct Voted {
     pub session_id: u64,
+    pub question_id: u8,
     pub proposal_id: u8,
     pub voter: Pubkey,
 }
@@ -1,13 +1,38 @@
 use anchor_lang::prelude::*;
 
+use crate::errors::VotingError;
+use crate::state::{
+    ballot::MAX_SCORE,
+    encryption::EncryptionConfig,
+    pairwise::PairwiseMatrixAccount,
+    proposal::ProposalAccount,
+    question::{QuestionAccount, QuestionResult},
+    voter::VoterAccount,
+};
+
+pub const MAX_SESSION_NAME_LEN: usize = 20;
+pub const MAX_SESSION_DESCRIPTION_LEN: usize = 80;
+pub const MAX_METADATA_URI_LEN: usize = 200;
+pub const MAX_SEATS: usize = 10;
+pub const MAX_EXECUTION_DELAY: i64 = 365 * 24 * 60 * 60; // one year
+
 #[account]
 #[derive(InitSpace)]
 pub struct SessionResult {
     pub total_votes: u32, // 4
     pub blank_votes: u32, // 4
     pub abstention: u32,  // 4
-    #[max_len(10)]
-    pub winning_proposals: Vec<u8>, // 4 (prefix) + 10 bytes
+    #[max_len(255)]
+    pub winning_proposals: Vec<u8>, // 4 (prefix) + 255 bytes, elected proposals, best first
+    #[max_len(MAX_SCORE as usize + 1)]
+    pub score_distribution: Vec<u32>, // 4 (prefix) + 11 scores, only filled in score mode
+    pub has_condorcet_winner: bool, // 1, only set in condorcet mode
+    #[max_len(255)]
+    pub tied_proposals: Vec<u8>, // 4 (prefix) + 255 bytes, proposals tied at the last seat
+    pub voted_count: u32, // 4, headcount of voters who voted on the main question
+    pub abstention_count: u32, // 4, headcount of voters who did not
+    pub receipts_root: [u8; 32], // 32, vote receipts merkle root
+    pub receipts_count: u32, // 4
 }
 
 #[account]
@@ -15,19 +40,229 @@ pub struct SessionResult {
 pub struct SessionAccount {
     // 8 discriminator
     pub session_id: u64, // 8
-    #[max_len(20)]
+    #[max_len(MAX_SESSION_NAME_LEN)]
     pub name: String, // 20
-    #[max_len(80)]
+    #[max_len(MAX_SESSION_DESCRIPTION_LEN)]
     pub description: String, // 80
     pub status: SessionWorkflowStatus, // 1
     pub admin: Pubkey,   // 32
     pub voters_count: u32, // 4
     pub proposals_count: u8, // 1
     pub result: SessionResult,
+    pub proposals_config: ProposalsConfig,
+    pub execution_delay: i64, // 8, seconds between tally and result release
+    pub veto_council: Option<Pubkey>, // 1 + 32
+    pub tallied_at: i64,      // 8
+    pub execution_eta: i64,   // 8, release is allowed from this timestamp
+    pub vetoed_at: i64,       // 8
+    pub released_at: i64,     // 8
+    pub organization_id: Option<u64>, // 1 + 8
+    pub organization_members_seq: u32, // 4, members with a greater id are not eligible
+    #[max_len(MAX_METADATA_URI_LEN)]
+    pub metadata_uri: String, // 200
+    pub metadata_hash: [u8; 32], // 32, SHA-256 of the off-chain document
+    pub questions_count: u8,  // 1, question 0 is the session itself
+    pub voting_mode: VotingMode, // 1 + 4, largest variant
+    pub ballots_count: u32,   // 4, voters who voted on the main question
+    pub seats: u8,            // 1, number of proposals to elect
+    pub voters_weight: u32,   // 4, sum of the registered voters weights
+    pub ballots_weight: u32,  // 4, sum of the weights of voters who voted on the main question
+    pub lock_mint: Option<Pubkey>, // 1 + 32, voters weight is their locked tokens voting power
+    pub voting_started_at: i64, // 8, voting power snapshot timestamp
+    pub snapshot_slot: u64,   // 8, voter snapshots must be taken until this slot
+    pub encryption: Option<EncryptionConfig>, // 1 + 37 + 640, ballots are ElGamal encrypted
+    pub anonymous: bool,      // 1, votes are cast with identity tree membership proofs
+    pub badge_mint: Option<Pubkey>, // 1 + 32, non-transferable participation badge mint
+    pub badge_mint_bump: u8,  // 1
 }
 
 impl SessionAccount {
     pub const SEED_PREFIX: &'static [u8; 7] = b"session";
+
+    /// Outcome of a proposal, among the result of its question, once the session votes are tallied.
+    /// Proposals of added questions are looked up in their question account result.
+    pub fn proposal_outcome(
+        &self,
+        proposal: &ProposalAccount,
+        question: Option<&QuestionAccount>,
+    ) -> Result<ProposalOutcome> {
+        require!(
+            self.status == SessionWorkflowStatus::VotesTallied,
+            VotingError::UnexpectedSessionStatus
+        );
+        let proposal_id = proposal.proposal_id;
+        // 0 is abstention vote, it can not win
+        require!(
+            proposal.session_id == self.session_id
+                && proposal_id > 0
+                && proposal_id < self.proposals_count,
+            VotingError::InvalidProposalId
+        );
+
+        let (winning_proposals, tied_proposals, total_votes) = if proposal.question_id > 0 {
+            let question = question.ok_or(VotingError::InvalidQuestionId)?;
+            require!(
+                question.session_id == self.session_id
+                    && question.question_id == proposal.question_id,
+                VotingError::InvalidQuestionId
+            );
+            (
+                &question.result.winning_proposals,
+                &question.result.tied_proposals,
+                question.result.total_votes,
+            )
+        } else {
+            (
+                &self.result.winning_proposals,
+                &self.result.tied_proposals,
+                self.result.total_votes,
+            )
+        };
+
+        let rank = winning_proposals
+            .iter()
+            .position(|id| *id == proposal_id)
+            .map_or(0, |position| position as u8 + 1);
+
+        Ok(ProposalOutcome {
+            session_id: self.session_id,
+            proposal_id,
+            won: rank > 0,
+            rank,
+            tied: tied_proposals.contains(&proposal_id),
+            total_votes,
+            tallied_at: self.tallied_at,
+            released_at: self.released_at,
+        })
+    }
+
+    /// Proposals are executed and paid out only when elected to a seat, never when tied at the last one.
+    pub fn require_elected(&self, proposal_id: u8) -> Result<()> {
+        require!(
+            self.result.winning_proposals.contains(&proposal_id)
+                && self.result.winning_proposals.len() <= self.seats as usize
+                && !self.result.tied_proposals.contains(&proposal_id),
+            VotingError::ProposalNotWinning
+        );
+        Ok(())
+    }
+
+    /// Voters of a session weighted by locked tokens vote once their voting power is applied.
+    pub fn require_voting_power(&self, voter_account: &VoterAccount) -> Result<()> {
+        require!(
+            self.lock_mint.is_none() || voter_account.power_applied,
+            VotingError::NoVotingPower
+        );
+        Ok(())
+    }
+
+    /// Support of a proposal among the ballots weight of its question, as a `(support, total)` pair.
+    /// Added questions take one vote per ballot, the main question support depends on the voting mode.
+    pub fn proposal_support(
+        &self,
+        proposal: &ProposalAccount,
+        question: Option<&QuestionAccount>,
+        pairwise: Option<&PairwiseMatrixAccount>,
+    ) -> Result<(u64, u64)> {
+        if proposal.question_id > 0 {
+            let question = question.ok_or(VotingError::InvalidQuestionId)?;
+            require!(
+                question.session_id == self.session_id
+                    && question.question_id == proposal.question_id,
+                VotingError::InvalidQuestionId
+            );
+            return Ok((
+                proposal.vote_count as u64,
+                question.result.total_votes as u64,
+            ));
+        }
+
+        let ballots_weight = self.ballots_weight as u64;
+        Ok(match self.voting_mode {
+            VotingMode::Plurality => (proposal.vote_count as u64, ballots_weight),
+            // share of the best score every ballot could have given
+            VotingMode::Score { max_score } => {
+                (proposal.score_sum, max_score as u64 * ballots_weight)
+            }
+            // share of the pairwise contests against the other candidates
+            VotingMode::Condorcet => match pairwise {
+                Some(pairwise) if proposal.proposal_id > 1 => {
+                    let candidates_count = pairwise.candidates_count as usize;
+                    let candidate = PairwiseMatrixAccount::candidate_index(proposal.proposal_id);
+                    require!(
+                        pairwise.session_id == self.session_id && candidate < candidates_count,
+                        VotingError::InvalidPairwiseAccount
+                    );
+                    let support = pairwise.preferences[candidate][..candidates_count]
+                        .iter()
+                        .map(|count| *count as u64)
+                        .sum();
+                    (support, (candidates_count as u64 - 1) * ballots_weight)
+                }
+                Some(_) => (0, ballots_weight),
+                None => {
+                    require!(self.ballots_count == 0, VotingError::InvalidPairwiseAccount);
+                    (0, 0)
+                }
+            },
+            // share of the ballots allocating votes to the proposal, whatever their cost
+            VotingMode::Quadratic { .. } | VotingMode::Cumulative { .. } => {
+                (proposal.score_count as u64, ballots_weight)
+            }
+        })
+    }
+}
+
+/// Returned by `get_proposal_outcome` to calling programs, see `SessionAccount::proposal_outcome`.
+#[derive(Clone, Debug, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub struct ProposalOutcome {
+    pub session_id: u64,
+    pub proposal_id: u8,
+    pub won: bool,
+    pub rank: u8,   // 1 for the best elected proposal, 0 when not elected
+    pub tied: bool, // tied at the last seat, so not elected
+    pub total_votes: u32,
+    pub tallied_at: i64,
+    pub released_at: i64, // tallied_at without execution delay, else 0 until release_result
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct ProposalsConfig {
+    pub deposit_amount: u64,          // 8, 0 means no deposit
+    pub deposit_mint: Option<Pubkey>, // 1 + 32, None means SOL deposit
+    pub refund_threshold_bps: u16,    // 2, minimum share of total votes to get deposit back
+    pub max_proposals_per_voter: u8,  // 1
+}
+
+impl Default for ProposalsConfig {
+    fn default() -> Self {
+        Self {
+            deposit_amount: 0,
+            deposit_mint: None,
+            refund_threshold_bps: 0,
+            max_proposals_per_voter: u8::MAX,
+        }
+    }
+}
+
+impl ProposalsConfig {
+    /// Whether a proposal deposit goes back to its proposer, given the proposal support and total.
+    /// Without any vote, no proposal reaches a positive threshold and deposits are forfeited.
+    pub fn deposit_refunded(&self, support: u64, total: u64) -> bool {
+        if total == 0 {
+            return self.refund_threshold_bps == 0;
+        }
+        support as u128 * 10_000 >= self.refund_threshold_bps as u128 * total as u128
+    }
+}
+
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, InitSpace, PartialEq)]
+pub enum VotingMode {
+    Plurality,
+    Score { max_score: u8 },
+    Condorcet,
+    Quadratic { credits: u32 },
+    Cumulative { points: Option<u8> },
 }
 
 #[derive(Clone, AnchorSerialize, AnchorDeserialize, InitSpace, PartialEq)]
@@ -39,6 +274,7 @@ pub enum SessionWorkflowStatus {
     VotingSessionStarted,
     VotingSessionEnded,
     VotesTallied,
+    Vetoed,
 }
 
 #[event]
@@ -53,6 +289,57 @@ pub struct SessionCreated {
     pub session_id: u64,
     pub name: String,
     pub description: String,
+    pub metadata_uri: String,
+    pub metadata_hash: [u8; 32],
+}
+
+#[event]
+pub struct ProposalsConfigured {
+    pub session_id: u64,
+    pub deposit_amount: u64,
+    pub deposit_mint: Option<Pubkey>,
+    pub refund_threshold_bps: u16,
+    pub max_proposals_per_voter: u8,
+}
+
+#[event]
+pub struct ExecutionConfigured {
+    pub session_id: u64,
+    pub execution_delay: i64,
+    pub veto_council: Option<Pubkey>,
+}
+
+#[event]
+pub struct VotingModeConfigured {
+    pub session_id: u64,
+    pub voting_mode: VotingMode,
+    pub seats: u8,
+}
+
+#[event]
+pub struct VotingPowerConfigured {
+    pub session_id: u64,
+    pub lock_mint: Option<Pubkey>,
+}
+
+#[event]
+pub struct ResultQueued {
+    pub session_id: u64,
+    pub tallied_at: i64,
+    pub execution_eta: i64,
+}
+
+#[event]
+pub struct ResultVetoed {
+    pub session_id: u64,
+    pub veto_council: Pubkey,
+    pub vetoed_at: i64,
+}
+
+#[event]
+pub struct ResultReleased {
+    pub session_id: u64,
+    pub released_at: i64,
 }
 
 #[event]
@@ -63,4 +350,459 @@ pub struct VotesTallied {
     pub blank_votes: u32,
     pub abstention: u32,
     pub winning_proposals: Vec<u8>,
+    pub questions: Vec<QuestionResult>,
+    pub score_distribution: Vec<u32>,
+    pub has_condorcet_winner: bool,
+    pub tied_proposals: Vec<u8>,
+    pub voted_count: u32,
+    pub abstention_count: u32,
+    pub receipts_root: [u8; 32],
+    pub receipts_count: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{
+        encryption::Ciphertext, pairwise::MAX_RANKED_PROPOSALS, question::MAX_QUESTIONS,
+    };
+
+    fn config(refund_threshold_bps: u16) -> ProposalsConfig {
+        ProposalsConfig {
+            deposit_amount: 1_000_000,
+            refund_threshold_bps,
+            ..ProposalsConfig::default()
+        }
+    }
+
+    #[test]
+    fn deposit_is_refunded_from_threshold_share() {
+        // 10% threshold over 20 votes: 2 votes are needed
+        let config = config(1_000);
+
+        assert!(config.deposit_refunded(2, 20));
+        assert!(config.deposit_refunded(20, 20));
+        assert!(!config.deposit_refunded(1, 20));
+        assert!(!config.deposit_refunded(0, 20));
+    }
+
+    #[test]
+    fn deposit_is_always_refunded_without_threshold() {
+        let config = config(0);
+
+        assert!(config.deposit_refunded(0, 20));
+        assert!(config.deposit_refunded(0, 0));
+    }
+
+    #[test]
+    fn deposit_is_forfeited_when_nobody_voted() {
+        assert!(!config(1).deposit_refunded(0, 0));
+        assert!(!config(10_000).deposit_refunded(0, 0));
+    }
+
+    fn voter(power_applied: bool) -> VoterAccount {
+        VoterAccount {
+            session_id: 0,
+            voter: Pubkey::new_unique(),
+            voter_id: 1,
+            has_voted: false,
+            voted_proposal_id: 0,
+            nb_proposals: 0,
+            question_votes: [0; MAX_QUESTIONS],
+            seat_votes: [0; MAX_SEATS],
+            credits: None,
+            credits_spent: 0,
+            weight: 1,
+            nonce: 0,
+            identity_commitment: [0; 32],
+            reward_claimed: false,
+            badge_minted: false,
+            power_applied,
+        }
+    }
+
+    fn session(lock_mint: Option<Pubkey>) -> SessionAccount {
+        SessionAccount {
+            session_id: 0,
+            name: String::new(),
+            description: String::new(),
+            status: SessionWorkflowStatus::VotingSessionStarted,
+            admin: Pubkey::new_unique(),
+            voters_count: 1,
+            proposals_count: 2,
+            result: SessionResult {
+                total_votes: 0,
+                blank_votes: 0,
+                abstention: 0,
+                winning_proposals: Vec::new(),
+                score_distribution: Vec::new(),
+                has_condorcet_winner: false,
+                tied_proposals: Vec::new(),
+                voted_count: 0,
+                abstention_count: 0,
+                receipts_root: [0; 32],
+                receipts_count: 0,
+            },
+            proposals_config: ProposalsConfig::default(),
+            execution_delay: 0,
+            veto_council: None,
+            tallied_at: 0,
+            execution_eta: 0,
+            vetoed_at: 0,
+            released_at: 0,
+            organization_id: None,
+            organization_members_seq: 0,
+            metadata_uri: String::new(),
+            metadata_hash: [0; 32],
+            questions_count: 1,
+            voting_mode: VotingMode::Plurality,
+            ballots_count: 0,
+            seats: 1,
+            voters_weight: 1,
+            ballots_weight: 0,
+            lock_mint,
+            voting_started_at: 0,
+            snapshot_slot: 0,
+            encryption: None,
+            anonymous: false,
+            badge_mint: None,
+            badge_mint_bump: 0,
+        }
+    }
+
+    #[test]
+    fn lock_weighted_voters_need_applied_power() {
+        let session = session(Some(Pubkey::new_unique()));
+
+        assert_eq!(
+            session.require_voting_power(&voter(false)).unwrap_err(),
+            VotingError::NoVotingPower.into()
+        );
+        assert!(session.require_voting_power(&voter(true)).is_ok());
+    }
+
+    #[test]
+    fn unweighted_voters_vote_with_their_registered_weight() {
+        assert!(session(None).require_voting_power(&voter(false)).is_ok());
+    }
+
+    fn proposal(proposal_id: u8, question_id: u8) -> ProposalAccount {
+        ProposalAccount {
+            session_id: 0,
+            proposal_id,
+            description: String::new(),
+            proposer: Pubkey::new_unique(),
+            vote_count: 0,
+            deposit_amount: 1_000_000,
+            deposit_settled: false,
+            treasury_transfer: None,
+            metadata_uri: String::new(),
+            metadata_hash: [0; 32],
+            question_id,
+            score_sum: 0,
+            score_count: 0,
+            score_distribution: [0; MAX_SCORE as usize + 1],
+            encrypted_votes: Ciphertext {
+                c1: [0; 32],
+                c2: [0; 32],
+            },
+            decryption_shares: Vec::new(),
+            votes_revealed: false,
+        }
+    }
+
+    fn tallied_session(voting_mode: VotingMode, ballots_weight: u32) -> SessionAccount {
+        SessionAccount {
+            status: SessionWorkflowStatus::VotesTallied,
+            voting_mode,
+            ballots_count: ballots_weight,
+            ballots_weight,
+            ..session(None)
+        }
+    }
+
+    #[test]
+    fn plurality_support_is_the_share_of_ballots() {
+        let session = tallied_session(VotingMode::Plurality, 20);
+        let mut proposal = proposal(2, 0);
+        proposal.vote_count = 5;
+
+        assert_eq!(
+            session.proposal_support(&proposal, None, None).unwrap(),
+            (5, 20)
+        );
+    }
+
+    #[test]
+    fn score_support_is_the_share_of_the_best_score() {
+        let session = tallied_session(VotingMode::Score { max_score: 5 }, 4);
+        let mut proposal = proposal(2, 0);
+        proposal.score_sum = 10;
+        proposal.score_count = 4;
+
+        // 4 ballots scoring 2.5 out of 5 on average
+        assert_eq!(
+            session.proposal_support(&proposal, None, None).unwrap(),
+            (10, 20)
+        );
+        assert!(config(5_000).deposit_refunded(10, 20));
+    }
+
+    #[test]
+    fn condorcet_support_is_the_share_of_pairwise_contests() {
+        let session = tallied_session(VotingMode::Condorcet, 10);
+        let mut pairwise = PairwiseMatrixAccount {
+            session_id: 0,
+            candidates_count: 3,
+            preferences: [[0; MAX_RANKED_PROPOSALS]; MAX_RANKED_PROPOSALS],
+        };
+        // proposal 2 is ranked over proposal 3 by 7 ballots and over proposal 4 by 4 ballots
+        pairwise.preferences[0][1] = 7;
+        pairwise.preferences[0][2] = 4;
+
+        assert_eq!(
+            session
+                .proposal_support(&proposal(2, 0), None, Some(&pairwise))
+                .unwrap(),
+            (11, 20)
+        );
+        // the blank proposal is not ranked
+        assert_eq!(
+            session
+                .proposal_support(&proposal(1, 0), None, Some(&pairwise))
+                .unwrap(),
+            (0, 10)
+        );
+        assert_eq!(
+            session
+                .proposal_support(&proposal(2, 0), None, None)
+                .unwrap_err(),
+            VotingError::InvalidPairwiseAccount.into()
+        );
+        assert_eq!(
+            tallied_session(VotingMode::Condorcet, 0)
+                .proposal_support(&proposal(2, 0), None, None)
+                .unwrap(),
+            (0, 0)
+        );
+    }
+
+    #[test]
+    fn allocated_support_is_the_share_of_ballots_whatever_the_cost() {
+        for voting_mode in [
+            VotingMode::Quadratic { credits: 100 },
+            VotingMode::Cumulative { points: Some(10) },
+        ] {
+            let session = tallied_session(voting_mode, 4);
+            let mut proposal = proposal(2, 0);
+            // 2 ballots allocating 9 votes each
+            proposal.vote_count = 18;
+            proposal.score_count = 2;
+
+            assert_eq!(
+                session.proposal_support(&proposal, None, None).unwrap(),
+                (2, 4)
+            );
+        }
+    }
+
+    #[test]
+    fn question_support_is_the_share_of_the_question_votes() {
+        let session = tallied_session(VotingMode::Score { max_score: 5 }, 20);
+        let question = QuestionAccount {
+            session_id: 0,
+            question_id: 1,
+            title: String::new(),
+            blank_proposal_id: 4,
+            result: QuestionResult {
+                question_id: 1,
+                total_votes: 8,
+                blank_votes: 2,
+                abstention: 12,
+                winning_proposals: vec![5],
+                tied_proposals: Vec::new(),
+            },
+        };
+        let mut proposal = proposal(5, 1);
+        proposal.vote_count = 3;
+
+        assert_eq!(
+            session
+                .proposal_support(&proposal, Some(&question), None)
+                .unwrap(),
+            (3, 8)
+        );
+        assert_eq!(
+            session.proposal_support(&proposal, None, None).unwrap_err(),
+            VotingError::InvalidQuestionId.into()
+        );
+    }
+
+    #[test]
+    fn only_proposals_elected_to_a_seat_are_executed() {
+        let mut session = tallied_session(VotingMode::Plurality, 2);
+        session.result.winning_proposals = vec![3];
+        assert!(session.require_elected(3).is_ok());
+        assert_eq!(
+            session.require_elected(2).unwrap_err(),
+            VotingError::ProposalNotWinning.into()
+        );
+
+        // a single seat tied at the top elects nobody
+        session.result.winning_proposals = Vec::new();
+        session.result.tied_proposals = vec![2, 4];
+        assert!(session.require_elected(2).is_err());
+
+        // winners can not outnumber the seats
+        session.result.winning_proposals = vec![2, 4];
+        session.result.tied_proposals = Vec::new();
+        assert!(session.require_elected(2).is_err());
+        session.seats = 2;
+        assert!(session.require_elected(2).is_ok());
+    }
+
+    const TALLIED_AT: i64 = 1_700_000_000;
+
+    /// Tallied session with proposals 1 to 5, 0 being the abstention vote and 1 the blank proposal.
+    fn outcome_session(winning_proposals: Vec<u8>, tied_proposals: Vec<u8>) -> SessionAccount {
+        let mut session = tallied_session(VotingMode::Plurality, 9);
+        session.proposals_count = 6;
+        session.seats = 2;
+        session.tallied_at = TALLIED_AT;
+        session.result.total_votes = 9;
+        session.result.winning_proposals = winning_proposals;
+        session.result.tied_proposals = tied_proposals;
+        session
+    }
+
+    #[test]
+    fn elected_proposals_are_ranked() {
+        let session = outcome_session(vec![3, 2], Vec::new());
+
+        let first = session.proposal_outcome(&proposal(3, 0), None).unwrap();
+        assert_eq!(
+            first,
+            ProposalOutcome {
+                session_id: 0,
+                proposal_id: 3,
+                won: true,
+                rank: 1,
+                tied: false,
+                total_votes: 9,
+                tallied_at: TALLIED_AT,
+                released_at: 0,
+            }
+        );
+
+        let second = session.proposal_outcome(&proposal(2, 0), None).unwrap();
+        assert!(second.won);
+        assert_eq!(second.rank, 2);
+    }
+
+    #[test]
+    fn tied_proposals_are_not_elected() {
+        let session = outcome_session(vec![3], vec![2, 4]);
+
+        let outcome = session.proposal_outcome(&proposal(4, 0), None).unwrap();
+        assert!(!outcome.won);
+        assert!(outcome.tied);
+        assert_eq!(outcome.rank, 0);
+
+        let outcome = session.proposal_outcome(&proposal(5, 0), None).unwrap();
+        assert!(!outcome.won);
+        assert!(!outcome.tied);
+    }
+
+    #[test]
+    fn question_proposals_outcome_is_their_question_result() {
+        // proposal 3 wins the main question, proposal 5 wins question 1 with 4 votes
+        let session = outcome_session(vec![3], Vec::new());
+        let question = QuestionAccount {
+            session_id: 0,
+            question_id: 1,
+            title: String::new(),
+            blank_proposal_id: 4,
+            result: QuestionResult {
+                question_id: 1,
+                total_votes: 4,
+                blank_votes: 1,
+                abstention: 5,
+                winning_proposals: vec![5],
+                tied_proposals: Vec::new(),
+            },
+        };
+
+        let outcome = session
+            .proposal_outcome(&proposal(5, 1), Some(&question))
+            .unwrap();
+        assert!(outcome.won);
+        assert_eq!(outcome.rank, 1);
+        assert_eq!(outcome.total_votes, 4);
+
+        let outcome = session
+            .proposal_outcome(&proposal(3, 0), Some(&question))
+            .unwrap();
+        assert!(outcome.won);
+        assert_eq!(outcome.total_votes, 9);
+
+        // the question account is required, and must be the proposal one
+        assert_eq!(
+            session.proposal_outcome(&proposal(5, 1), None).unwrap_err(),
+            VotingError::InvalidQuestionId.into()
+        );
+        assert_eq!(
+            session
+                .proposal_outcome(&proposal(5, 2), Some(&question))
+                .unwrap_err(),
+            VotingError::InvalidQuestionId.into()
+        );
+    }
+
+    #[test]
+    fn outcome_requires_tallied_session() {
+        let mut session = outcome_session(vec![3], Vec::new());
+
+        for status in [
+            SessionWorkflowStatus::VotingSessionStarted,
+            SessionWorkflowStatus::VotingSessionEnded,
+            SessionWorkflowStatus::Vetoed,
+        ] {
+            session.status = status;
+            assert_eq!(
+                session.proposal_outcome(&proposal(3, 0), None).unwrap_err(),
+                VotingError::UnexpectedSessionStatus.into()
+            );
+        }
+    }
+
+    #[test]
+    fn outcome_requires_registered_proposal() {
+        let session = outcome_session(vec![3], Vec::new());
+
+        // 0 is abstention vote, 6 is not registered
+        for proposal_id in [0, 6, u8::MAX] {
+            assert_eq!(
+                session
+                    .proposal_outcome(&proposal(proposal_id, 0), None)
+                    .unwrap_err(),
+                VotingError::InvalidProposalId.into()
+            );
+        }
+
+        // proposal of another session
+        let mut other = proposal(3, 0);
+        other.session_id = 1;
+        assert_eq!(
+            session.proposal_outcome(&other, None).unwrap_err(),
+            VotingError::InvalidProposalId.into()
+        );
+    }
+
+    #[test]
+    fn deposit_threshold_does_not_overflow() {
+        let config = config(10_000);
+
+        assert!(config.deposit_refunded(u64::MAX, u64::MAX));
+        assert!(!config.deposit_refunded(u64::MAX - 1, u64::MAX));
+    }
 }
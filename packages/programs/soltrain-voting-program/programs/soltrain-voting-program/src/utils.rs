@@ -0,0 +1,308 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{ed25519_program, instruction::Instruction},
+};
+use anchor_spl::token::{self, Mint, Token, TokenAccount, TransferChecked};
+
+use crate::{
+    errors::VotingError,
+    state::{session_index::*, voting_session::MAX_METADATA_URI_LEN},
+};
+
+/// Transfers tokens out of a token account whose authority is a program PDA.
+/// `signer_seeds` are the PDA authority seeds, including bump.
+pub fn transfer_tokens_signed<'info>(
+    token_program: &Program<'info, Token>,
+    mint: &Account<'info, Mint>,
+    from: &Account<'info, TokenAccount>,
+    to: &Account<'info, TokenAccount>,
+    authority: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    amount: u64,
+) -> Result<()> {
+    token::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: from.to_account_info(),
+                mint: mint.to_account_info(),
+                to: to.to_account_info(),
+                authority,
+            },
+            signer_seeds,
+        ),
+        amount,
+        mint.decimals,
+    )
+}
+
+/// Transfers tokens from a token account owned by a transaction signer.
+pub fn transfer_tokens<'info>(
+    token_program: &Program<'info, Token>,
+    mint: &Account<'info, Mint>,
+    from: &Account<'info, TokenAccount>,
+    to: &Account<'info, TokenAccount>,
+    authority: AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    transfer_tokens_signed(token_program, mint, from, to, authority, &[], amount)
+}
+
+/// Appends a session id to the current page of a user sessions index.
+pub fn index_session(
+    index: &mut SessionIndexAccount,
+    page: &mut SessionIndexPageAccount,
+    owner: Pubkey,
+    session_id: u64,
+) {
+    page.owner = owner;
+    page.page = index.current_page();
+    page.session_ids.push(session_id);
+
+    index.owner = owner;
+    index.sessions_count += 1;
+}
+
+/// Checks an off-chain metadata reference: the URI fits in accounts and a hash comes with a URI.
+pub fn validate_metadata(metadata_uri: &str, metadata_hash: &[u8; 32]) -> Result<()> {
+    require!(
+        metadata_uri.len() <= MAX_METADATA_URI_LEN,
+        VotingError::MetadataUriTooLong
+    );
+    require!(
+        !metadata_uri.is_empty() || *metadata_hash == [0; 32],
+        VotingError::MissingMetadataUri
+    );
+    Ok(())
+}
+
+/// Domain tag prefixing signed ballots, so they can not be mistaken for other signed messages.
+pub const SIGNED_BALLOT_DOMAIN: &[u8; 22] = b"soltrain-voting:ballot";
+
+/// Message signed off-chain by a voter to vote for a proposal, relayed by a third party.
+/// Layout is the domain tag, program id, session id (u64 LE), proposal id (u8) and voter nonce
+/// (u64 LE).
+pub fn signed_ballot_message(session_id: u64, proposal_id: u8, nonce: u64) -> Vec<u8> {
+    [
+        SIGNED_BALLOT_DOMAIN.as_ref(),
+        crate::ID.as_ref(),
+        &session_id.to_le_bytes(),
+        &[proposal_id],
+        &nonce.to_le_bytes(),
+    ]
+    .concat()
+}
+
+/// Returns the public keys and messages of the signatures verified by an ed25519 program
+/// instruction. Only signatures whose data is held by the instruction itself are accepted.
+pub fn get_ed25519_signatures(instruction: &Instruction) -> Result<Vec<(Pubkey, Vec<u8>)>> {
+    require!(
+        instruction.program_id == ed25519_program::ID,
+        VotingError::InvalidSignedBallot
+    );
+
+    let data = &instruction.data;
+    let read_u16 = |at: usize| -> Result<usize> {
+        data.get(at..at + 2)
+            .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]) as usize)
+            .ok_or(error!(VotingError::InvalidSignedBallot))
+    };
+    let read_bytes = |at: usize, len: usize| -> Result<&[u8]> {
+        data.get(at..at + len)
+            .ok_or(error!(VotingError::InvalidSignedBallot))
+    };
+
+    // 1 byte signatures count and 1 byte padding, then 14 bytes offsets per signature
+    let count = *data.first().ok_or(VotingError::InvalidSignedBallot)? as usize;
+    let mut signatures = Vec::with_capacity(count);
+    for index in 0..count {
+        let offsets = 2 + index * 14;
+        let signature_instruction = read_u16(offsets + 2)?;
+        let public_key_offset = read_u16(offsets + 4)?;
+        let public_key_instruction = read_u16(offsets + 6)?;
+        let message_offset = read_u16(offsets + 8)?;
+        let message_size = read_u16(offsets + 10)?;
+        let message_instruction = read_u16(offsets + 12)?;
+        require!(
+            signature_instruction == u16::MAX as usize
+                && public_key_instruction == u16::MAX as usize
+                && message_instruction == u16::MAX as usize,
+            VotingError::InvalidSignedBallot
+        );
+
+        let public_key = Pubkey::try_from(read_bytes(public_key_offset, 32)?)
+            .map_err(|_| error!(VotingError::InvalidSignedBallot))?;
+        let message = read_bytes(message_offset, message_size)?.to_vec();
+        signatures.push((public_key, message));
+    }
+    Ok(signatures)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty_page() -> SessionIndexPageAccount {
+        SessionIndexPageAccount {
+            owner: Pubkey::default(),
+            page: 0,
+            session_ids: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn validate_metadata_accepts_optional_hashed_uri() {
+        assert!(validate_metadata("", &[0; 32]).is_ok());
+        assert!(validate_metadata("ipfs://document", &[0; 32]).is_ok());
+        assert!(validate_metadata("ipfs://document", &[7; 32]).is_ok());
+        assert!(validate_metadata(&"u".repeat(MAX_METADATA_URI_LEN), &[7; 32]).is_ok());
+    }
+
+    #[test]
+    fn validate_metadata_rejects_long_uri_or_hash_without_uri() {
+        assert_eq!(
+            validate_metadata(&"u".repeat(MAX_METADATA_URI_LEN + 1), &[0; 32]).unwrap_err(),
+            VotingError::MetadataUriTooLong.into()
+        );
+        assert_eq!(
+            validate_metadata("", &[7; 32]).unwrap_err(),
+            VotingError::MissingMetadataUri.into()
+        );
+    }
+
+    #[test]
+    fn index_session_fills_pages_in_order() {
+        let owner = Pubkey::new_unique();
+        let mut index = SessionIndexAccount {
+            owner: Pubkey::default(),
+            sessions_count: 0,
+        };
+        let mut pages = vec![empty_page()];
+
+        for session_id in 0..SESSION_INDEX_PAGE_SIZE as u64 + 1 {
+            // clients pass the page PDA derived from the current page
+            let current_page = index.current_page() as usize;
+            if current_page == pages.len() {
+                pages.push(empty_page());
+            }
+            index_session(&mut index, &mut pages[current_page], owner, session_id);
+        }
+
+        assert_eq!(index.owner, owner);
+        assert_eq!(index.sessions_count, SESSION_INDEX_PAGE_SIZE as u32 + 1);
+        assert_eq!(index.current_page(), 1);
+        assert_eq!(pages.len(), 2);
+        assert_eq!(
+            pages[0].session_ids,
+            (0..SESSION_INDEX_PAGE_SIZE as u64).collect::<Vec<_>>()
+        );
+        assert_eq!((pages[1].page, pages[1].owner), (1, owner));
+        assert_eq!(pages[1].session_ids, vec![SESSION_INDEX_PAGE_SIZE as u64]);
+    }
+
+    /// ed25519 program instruction verifying `signatures`, laid out as the ed25519 program expects:
+    /// offsets first, then each public key, signature and message.
+    fn ed25519_instruction(
+        signatures: &[(Pubkey, Vec<u8>)],
+        instruction_index: u16,
+    ) -> Instruction {
+        let mut data = vec![signatures.len() as u8, 0];
+        let mut payload = Vec::new();
+        let payload_offset = 2 + signatures.len() * 14;
+        for (public_key, message) in signatures {
+            let public_key_offset = payload_offset + payload.len();
+            payload.extend_from_slice(public_key.as_ref());
+            let signature_offset = payload_offset + payload.len();
+            payload.extend_from_slice(&[0; 64]);
+            let message_offset = payload_offset + payload.len();
+            payload.extend_from_slice(message);
+
+            for value in [
+                signature_offset as u16,
+                instruction_index,
+                public_key_offset as u16,
+                instruction_index,
+                message_offset as u16,
+                message.len() as u16,
+                instruction_index,
+            ] {
+                data.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+        data.extend(payload);
+
+        Instruction {
+            program_id: ed25519_program::ID,
+            accounts: Vec::new(),
+            data,
+        }
+    }
+
+    #[test]
+    fn signed_ballot_message_is_bound_to_the_program() {
+        let message = signed_ballot_message(1, 2, 3);
+
+        assert_eq!(message.len(), 22 + 32 + 8 + 1 + 8);
+        assert!(message.starts_with(SIGNED_BALLOT_DOMAIN));
+        assert_eq!(&message[22..54], crate::ID.as_ref());
+        assert_eq!(
+            &message[54..],
+            [1, 0, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 0]
+        );
+        assert_ne!(message, signed_ballot_message(1, 2, 4));
+    }
+
+    #[test]
+    fn get_ed25519_signatures_returns_signed_messages() {
+        let signatures = vec![
+            (Pubkey::new_unique(), signed_ballot_message(1, 2, 0)),
+            (Pubkey::new_unique(), signed_ballot_message(1, 3, 5)),
+        ];
+
+        assert_eq!(
+            get_ed25519_signatures(&ed25519_instruction(&signatures, u16::MAX)).unwrap(),
+            signatures
+        );
+        assert!(get_ed25519_signatures(&ed25519_instruction(&[], u16::MAX))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn get_ed25519_signatures_rejects_other_programs() {
+        let mut instruction = ed25519_instruction(&[(Pubkey::new_unique(), vec![1])], u16::MAX);
+        instruction.program_id = crate::ID;
+
+        assert_eq!(
+            get_ed25519_signatures(&instruction).unwrap_err(),
+            VotingError::InvalidSignedBallot.into()
+        );
+    }
+
+    #[test]
+    fn get_ed25519_signatures_rejects_data_held_by_other_instructions() {
+        // the verified data could differ from the data read in this instruction
+        let instruction = ed25519_instruction(&[(Pubkey::new_unique(), vec![1])], 0);
+
+        assert_eq!(
+            get_ed25519_signatures(&instruction).unwrap_err(),
+            VotingError::InvalidSignedBallot.into()
+        );
+    }
+
+    #[test]
+    fn get_ed25519_signatures_rejects_truncated_data() {
+        let instruction = ed25519_instruction(&[(Pubkey::new_unique(), vec![1, 2])], u16::MAX);
+
+        for len in [0, 1, 10, instruction.data.len() - 1] {
+            let truncated = Instruction {
+                data: instruction.data[..len].to_vec(),
+                ..instruction.clone()
+            };
+            assert_eq!(
+                get_ed25519_signatures(&truncated).unwrap_err(),
+                VotingError::InvalidSignedBallot.into()
+            );
+        }
+    }
+}
@@ -0,0 +1,223 @@
+use anchor_lang::prelude::*;
+use voting::{
+    cpi::{accounts::GetProposalOutcomeContextData, get_proposal_outcome},
+    program::Voting,
+    state::{
+        proposal::ProposalAccount,
+        voting_session::{ProposalOutcome, SessionAccount},
+    },
+};
+
+declare_id!("8LjrTU3jucqitc2THJqW94FXbd4gtK5m16uypfwnCU8v");
+
+/// Example program gating an action on a voting session result.
+#[program]
+pub mod voting_consumer {
+    use super::*;
+
+    /**
+     * Anyone can record the execution of a proposal which won its session, once per proposal.
+     *
+     * @dev The outcome is read from the voting program through CPI, the session result must be released.
+     * An event ProposalExecutionRecorded is emitted
+     *
+     * @param session_id The voting session id
+     * @param proposal_id The proposal id, the question account is required for proposals of added questions
+     */
+    pub fn execute_if_won(
+        ctx: Context<ExecuteIfWonContextData>,
+        session_id: u64,
+        proposal_id: u8,
+    ) -> Result<()> {
+        let outcome = get_proposal_outcome(CpiContext::new(
+            ctx.accounts.voting_program.to_account_info(),
+            GetProposalOutcomeContextData {
+                session_account: ctx.accounts.session_account.to_account_info(),
+                proposal_account: ctx.accounts.proposal_account.to_account_info(),
+                question_account: ctx
+                    .accounts
+                    .question_account
+                    .as_ref()
+                    .map(|question_account| question_account.to_account_info()),
+            },
+        ))?
+        .get();
+
+        require_won(&outcome, session_id, proposal_id)?;
+
+        let execution_record = &mut ctx.accounts.execution_record;
+        execution_record.session_id = session_id;
+        execution_record.proposal_id = proposal_id;
+        execution_record.rank = outcome.rank;
+        execution_record.executed_at = Clock::get()?.unix_timestamp;
+
+        emit!(ProposalExecutionRecorded {
+            session_id,
+            proposal_id,
+            rank: outcome.rank,
+            executed_at: execution_record.executed_at,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+#[instruction(session_id: u64, proposal_id: u8)]
+pub struct ExecuteIfWonContextData<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: session of the voting program, deserialized and checked by the voting program
+    #[account(
+        seeds = [
+            SessionAccount::SEED_PREFIX.as_ref(),
+            &session_id.to_le_bytes(),
+        ],
+        bump,
+        seeds::program = voting_program.key()
+    )]
+    pub session_account: UncheckedAccount<'info>,
+
+    /// CHECK: proposal of the voting session, deserialized and checked by the voting program
+    #[account(
+        seeds = [
+            ProposalAccount::SEED_PREFIX.as_ref(),
+            &session_id.to_le_bytes(),
+            &[proposal_id],
+        ],
+        bump,
+        seeds::program = voting_program.key()
+    )]
+    pub proposal_account: UncheckedAccount<'info>,
+
+    /// CHECK: question of the proposal, deserialized and checked by the voting program
+    pub question_account: Option<UncheckedAccount<'info>>,
+
+    // one record per proposal, the action can not be executed twice
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ExecutionRecordAccount::INIT_SPACE,
+        seeds = [
+            ExecutionRecordAccount::SEED_PREFIX.as_ref(),
+            &session_id.to_le_bytes(),
+            &[proposal_id],
+        ],
+        bump
+    )]
+    pub execution_record: Account<'info, ExecutionRecordAccount>,
+
+    pub voting_program: Program<'info, Voting>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ExecutionRecordAccount {
+    // 8 discriminator
+    pub session_id: u64,  // 8
+    pub proposal_id: u8,  // 1
+    pub rank: u8,         // 1
+    pub executed_at: i64, // 8
+}
+
+impl ExecutionRecordAccount {
+    pub const SEED_PREFIX: &'static [u8; 9] = b"execution";
+}
+
+#[event]
+pub struct ProposalExecutionRecorded {
+    pub session_id: u64,
+    pub proposal_id: u8,
+    pub rank: u8,
+    pub executed_at: i64,
+}
+
+#[error_code]
+pub enum ConsumerError {
+    #[msg("Outcome does not match the requested proposal")]
+    UnexpectedOutcome,
+    #[msg("Proposal was not elected")]
+    ProposalNotElected,
+    #[msg("Session result is not released yet")]
+    ResultNotReleased,
+}
+
+/// Checks the outcome is the requested proposal one, and that the proposal won a released result.
+pub fn require_won(outcome: &ProposalOutcome, session_id: u64, proposal_id: u8) -> Result<()> {
+    require!(
+        outcome.session_id == session_id && outcome.proposal_id == proposal_id,
+        ConsumerError::UnexpectedOutcome
+    );
+    require!(outcome.won, ConsumerError::ProposalNotElected);
+    require!(outcome.released_at > 0, ConsumerError::ResultNotReleased);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SESSION_ID: u64 = 7;
+    const TALLIED_AT: i64 = 1_700_000_000;
+
+    fn outcome(won: bool, released_at: i64) -> ProposalOutcome {
+        ProposalOutcome {
+            session_id: SESSION_ID,
+            proposal_id: 3,
+            won,
+            rank: won as u8,
+            tied: false,
+            total_votes: 9,
+            tallied_at: TALLIED_AT,
+            released_at,
+        }
+    }
+
+    #[test]
+    fn outcome_survives_return_data_encoding() {
+        let outcome = outcome(true, TALLIED_AT + 60);
+
+        // CPI callers decode the return data with `Return::get`, which is borsh deserialization
+        let data = outcome.try_to_vec().unwrap();
+        assert_eq!(ProposalOutcome::try_from_slice(&data).unwrap(), outcome);
+    }
+
+    #[test]
+    fn consumer_accepts_released_winning_proposal() {
+        assert!(require_won(&outcome(true, TALLIED_AT + 60), SESSION_ID, 3).is_ok());
+    }
+
+    #[test]
+    fn consumer_rejects_losing_proposal() {
+        assert_eq!(
+            require_won(&outcome(false, TALLIED_AT + 60), SESSION_ID, 3).unwrap_err(),
+            ConsumerError::ProposalNotElected.into()
+        );
+    }
+
+    #[test]
+    fn consumer_rejects_unreleased_result() {
+        assert_eq!(
+            require_won(&outcome(true, 0), SESSION_ID, 3).unwrap_err(),
+            ConsumerError::ResultNotReleased.into()
+        );
+    }
+
+    #[test]
+    fn consumer_rejects_outcome_of_another_proposal() {
+        let outcome = outcome(true, TALLIED_AT + 60);
+
+        assert_eq!(
+            require_won(&outcome, SESSION_ID + 1, 3).unwrap_err(),
+            ConsumerError::UnexpectedOutcome.into()
+        );
+        assert_eq!(
+            require_won(&outcome, SESSION_ID, 2).unwrap_err(),
+            ConsumerError::UnexpectedOutcome.into()
+        );
+    }
+}
@@ -1,6 +1,8 @@
 import * as anchor from '@coral-xyz/anchor';
 import { AnchorError, BN, Program, Wallet } from '@coral-xyz/anchor';
+import { Ed25519Program, PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
 import { assert } from 'chai';
+import { createHash } from 'crypto';
 
 import { Voting } from '../client/types/voting';
 import { VotingClient, VotingSessionStatus } from '../client';
@@ -41,6 +43,54 @@ describe('voting', () => {
 	const client = new VotingClient(program, { skipPreflight: false, preflightCommitment: 'confirmed' });
 	let sessionId: BN;
 
+	const send = async (payer: Wallet, method: { transaction: () => Promise<anchor.web3.Transaction> }) => client.signAndSendTransaction(payer, await method.transaction());
+
+	// session administered by the program administrator, with registered voters
+	const openSession = async (name: string, voters: Wallet[]): Promise<BN> => {
+		const {
+			accounts: { sessionAccountPubkey },
+		} = await client.createVotingSession(administrator, name, `${name} session`);
+		const session = await client.getSession(sessionAccountPubkey);
+		for (const voter of voters) {
+			await client.registerVoter(administrator, session.sessionId, voter.publicKey);
+		}
+		return session.sessionId;
+	};
+
+	const configureVotingMode = (sessionId: BN, votingMode: anchor.IdlTypes<Voting>['votingMode'], seats: number) =>
+		send(
+			administrator,
+			program.methods.configureVotingMode(votingMode, seats).accountsPartial({ admin: administrator.publicKey, sessionAccount: client.findSessionAccountAddress(sessionId) }),
+		);
+
+	const findBallotAddress = (sessionId: BN, voter: PublicKey) =>
+		PublicKey.findProgramAddressSync([Buffer.from('ballot'), sessionId.toBuffer('le', 8), voter.toBuffer()], program.programId)[0];
+
+	// ballot entries are given in increasing proposal id order, with the proposal accounts
+	const proposalAccounts = (sessionId: BN, proposalIds: number[]) =>
+		proposalIds.map((proposalId) => ({ pubkey: client.findProposalAccountAddress(sessionId, proposalId), isWritable: true, isSigner: false }));
+
+	const allocateVotes = (voter: Wallet, sessionId: BN, allocations: { proposalId: number; value: number }[]) =>
+		send(
+			voter,
+			program.methods
+				.allocateVotes(allocations)
+				.accountsPartial({
+					voter: voter.publicKey,
+					sessionAccount: client.findSessionAccountAddress(sessionId),
+					voterAccount: client.findVoterAccountAddress(sessionId, voter.publicKey),
+					ballotAccount: findBallotAddress(sessionId, voter.publicKey),
+				})
+				.remainingAccounts(
+					proposalAccounts(
+						sessionId,
+						allocations.map(({ proposalId }) => proposalId),
+					),
+				),
+		);
+
+	const getResult = async (sessionId: BN) => (await program.account.sessionAccount.fetch(client.findSessionAccountAddress(sessionId))).result;
+
 	before(async () => {
 		// initialize program global account
 		await client.initGlobal(administrator);
@@ -1024,7 +1074,1712 @@ describe('voting', () => {
 			assert.equal(votesTallied.totalVotes, 6);
 			assert.equal(votesTallied.blankVotes, 1);
 			assert.equal(votesTallied.abstention, 1);
-			assert.sameMembers([...votesTallied.winningProposals], [3, 4]);
+			assert.isEmpty(votesTallied.winningProposals);
+			assert.sameMembers([...votesTallied.tiedProposals], [3, 4]);
+		});
+	});
+
+	describe('> Global configuration', () => {
+		const defaultConfig = { sessionFee: new BN(0), maxNameLen: 20, maxDescriptionLen: 80, maxVoters: 1000 };
+
+		afterEach(async () => {
+			const global = await client.getGlobal();
+			if (global.paused) {
+				await client.setPaused(administrator, false);
+			}
+			await client.updateGlobalConfig(administrator, defaultConfig);
+		});
+
+		describe('> initGlobal', () => {
+			it('> should have set the program upgrade authority as protocol authority', async () => {
+				const global = await client.getGlobal();
+				assert.equal(global.authority.toString(), administrator.publicKey.toString());
+				assert.isFalse(global.paused);
+				assert.equal(global.config.maxVoters, defaultConfig.maxVoters);
+			});
+		});
+
+		describe('> updateGlobalConfig', () => {
+			it('> should succeed when called with protocol authority', async () => {
+				const config = { sessionFee: new BN(1000), maxNameLen: 10, maxDescriptionLen: 40, maxVoters: 2 };
+				const { events } = await client.updateGlobalConfig(administrator, config);
+
+				const global = await client.getGlobal();
+				assert.equal(global.config.sessionFee.toNumber(), 1000);
+				assert.equal(global.config.maxNameLen, 10);
+				assert.equal(global.config.maxDescriptionLen, 40);
+				assert.equal(global.config.maxVoters, 2);
+
+				const { globalConfigUpdated } = events;
+				assert.equal(globalConfigUpdated.authority.toString(), administrator.publicKey.toString());
+				assert.equal(globalConfigUpdated.sessionFee.toNumber(), 1000);
+				assert.equal(globalConfigUpdated.maxVoters, 2);
+			});
+
+			it('> should fail when payer is not protocol authority', async () => {
+				await assertError(() => client.updateGlobalConfig(batman, defaultConfig), {
+					number: 6012,
+					code: 'ForbiddenAsNonAuthority',
+					errorMessage: 'Forbidden as non protocol authority',
+					programId: program.programId.toString(),
+				});
+			});
+
+			it('> should fail when config is invalid', async () => {
+				await assertError(() => client.updateGlobalConfig(administrator, { ...defaultConfig, maxVoters: 0 }), {
+					number: 6014,
+					code: 'InvalidGlobalConfig',
+					errorMessage: 'Invalid global configuration',
+					programId: program.programId.toString(),
+				});
+				await assertError(() => client.updateGlobalConfig(administrator, { ...defaultConfig, maxNameLen: 21 }), {
+					number: 6014,
+					code: 'InvalidGlobalConfig',
+					errorMessage: 'Invalid global configuration',
+					programId: program.programId.toString(),
+				});
+			});
+
+			it('> should limit session name and description lengths', async () => {
+				await client.updateGlobalConfig(administrator, { ...defaultConfig, maxNameLen: 4, maxDescriptionLen: 8 });
+
+				await assertError(() => client.createVotingSession(batman, 'Gotham', 'Vote'), {
+					number: 6016,
+					code: 'SessionNameTooLong',
+					errorMessage: 'Session name is too long',
+					programId: program.programId.toString(),
+				});
+				await assertError(() => client.createVotingSession(batman, 'Bat', 'Gotham vote'), {
+					number: 6017,
+					code: 'SessionDescriptionTooLong',
+					errorMessage: 'Session description is too long',
+					programId: program.programId.toString(),
+				});
+				await client.createVotingSession(batman, 'Bat', 'Vote');
+			});
+
+			it('> should limit session voters count', async () => {
+				await client.updateGlobalConfig(administrator, { ...defaultConfig, maxVoters: 1 });
+
+				const {
+					accounts: { sessionAccountPubkey },
+				} = await client.createVotingSession(administrator, 'Max voters', 'One voter only');
+				const { sessionId } = await client.getSession(sessionAccountPubkey);
+
+				await client.registerVoter(administrator, sessionId, batman.publicKey);
+				await assertError(() => client.registerVoter(administrator, sessionId, superman.publicKey), {
+					number: 6043,
+					code: 'MaxVotersReached',
+					errorMessage: 'Maximum number of voters reached',
+					programId: program.programId.toString(),
+				});
+			});
+		});
+
+		describe('> createVotingSession', () => {
+			it('> should pay session fee to protocol authority', async () => {
+				const sessionFee = 5000;
+				await client.updateGlobalConfig(administrator, { ...defaultConfig, sessionFee: new BN(sessionFee) });
+
+				const balanceBefore = await connection.getBalance(administrator.publicKey);
+				await client.createVotingSession(batman, 'Paid session', 'Batman pays the fee');
+				const balanceAfter = await connection.getBalance(administrator.publicKey);
+
+				assert.equal(balanceAfter - balanceBefore, sessionFee);
+			});
+
+			it('> should fail when authority account is not protocol authority', async () => {
+				const sessionId = await client.getNextSessionId();
+				const adminSessionIndexPage = await client.findSessionIndexPageAddress('admin_sessions', batman.publicKey);
+
+				await assertError(
+					async () => {
+						const tx = await program.methods
+							.createVotingSession('Fee thief', 'Batman keeps the fee', '', new Array(32).fill(0))
+							.accountsPartial({
+								owner: batman.publicKey,
+								authority: batman.publicKey,
+								sessionAccount: client.findSessionAccountAddress(sessionId),
+								organizationAccount: null,
+								adminSessionIndexPage,
+							})
+							.transaction();
+						return client.signAndSendTransaction(batman, tx);
+					},
+					{
+						number: 6013,
+						code: 'UnexpectedAuthority',
+						errorMessage: 'Unexpected protocol authority account',
+						programId: program.programId.toString(),
+					},
+				);
+			});
+		});
+
+		describe('> setPaused', () => {
+			it('> should fail when payer is not protocol authority', async () => {
+				await assertError(() => client.setPaused(batman, true), {
+					number: 6012,
+					code: 'ForbiddenAsNonAuthority',
+					errorMessage: 'Forbidden as non protocol authority',
+					programId: program.programId.toString(),
+				});
+			});
+
+			it('> should block session actions until the program is resumed', async () => {
+				const {
+					accounts: { sessionAccountPubkey },
+				} = await client.createVotingSession(administrator, 'Paused', 'Session created before pause');
+				const { sessionId } = await client.getSession(sessionAccountPubkey);
+
+				const { events } = await client.setPaused(administrator, true);
+				assert.isTrue(events.pausedChanged.paused);
+				assert.isTrue((await client.getGlobal()).paused);
+
+				await assertError(() => client.createVotingSession(batman, 'Paused', 'Session created during pause'), {
+					number: 6015,
+					code: 'ProgramPaused',
+					errorMessage: 'Program is paused',
+					programId: program.programId.toString(),
+				});
+				await assertError(() => client.registerVoter(administrator, sessionId, batman.publicKey), {
+					number: 6015,
+					code: 'ProgramPaused',
+					errorMessage: 'Program is paused',
+					programId: program.programId.toString(),
+				});
+
+				await client.setPaused(administrator, false);
+				const {
+					accounts: { voterAccountPubkey },
+				} = await client.registerVoter(administrator, sessionId, batman.publicKey);
+				const voter = await client.getVoter(voterAccountPubkey);
+				assert.equal(voter.voter.toString(), batman.publicKey.toString());
+			});
+		});
+	});
+
+	describe('> Proposal deposits', () => {
+		const deposit = new BN(100000000);
+
+		const configureDeposit = (sessionId: BN, refundThresholdBps: number) =>
+			send(
+				administrator,
+				program.methods.configureProposals({ depositAmount: deposit, depositMint: null, refundThresholdBps, maxProposalsPerVoter: 1 }).accountsPartial({
+					admin: administrator.publicKey,
+					sessionAccount: client.findSessionAccountAddress(sessionId),
+					depositMint: null,
+					vaultTokenAccount: null,
+					tokenProgram: null,
+					associatedTokenProgram: null,
+				}),
+			);
+
+		const settleDeposit = (sessionId: BN, proposalId: number, proposer: PublicKey) =>
+			send(
+				administrator,
+				program.methods.settleProposalDeposit().accountsPartial({
+					payer: administrator.publicKey,
+					sessionAccount: client.findSessionAccountAddress(sessionId),
+					proposalAccount: client.findProposalAccountAddress(sessionId, proposalId),
+					pairwiseAccount: null,
+					questionAccount: null,
+					proposer,
+					depositMint: null,
+					vaultTokenAccount: null,
+					proposerTokenAccount: null,
+					treasuryTokenAccount: null,
+					tokenProgram: null,
+					associatedTokenProgram: null,
+				}),
+			);
+
+		it('> should escrow deposits and refund proposals reaching the threshold', async () => {
+			const sessionId = await openSession('Deposits', [batman, superman]);
+			await configureDeposit(sessionId, 5000);
+			await client.startProposalsRegistration(administrator, sessionId);
+
+			const vaultAccountPubkey = client.findVaultAccountAddress(sessionId);
+			const vaultBalance = await connection.getBalance(vaultAccountPubkey);
+
+			const { events } = await client.registerProposal(batman, sessionId, 'Batcave for everyone'); // 2
+			assert.equal(events.proposalDepositEscrowed.amount.toString(), deposit.toString());
+			await assertError(() => client.registerProposal(batman, sessionId, 'Batmobile for everyone'), {
+				number: 6045,
+				code: 'MaxProposalsPerVoterReached',
+				errorMessage: 'Maximum number of proposals per voter reached',
+				programId: program.programId.toString(),
+			});
+			await client.registerProposal(superman, sessionId, 'Krypton for everyone'); // 3
+			assert.equal(await connection.getBalance(vaultAccountPubkey), vaultBalance + 2 * deposit.toNumber());
+
+			await client.stopProposalsRegistration(administrator, sessionId);
+			await client.startVotingSession(administrator, sessionId);
+			await client.vote(batman, sessionId, 2);
+			await client.vote(superman, sessionId, 2);
+			await client.stopVotingSession(administrator, sessionId);
+
+			await assertError(() => settleDeposit(sessionId, 2, batman.publicKey), {
+				number: 6000,
+				code: 'UnexpectedSessionStatus',
+				errorMessage: 'Unexpected session status',
+				programId: program.programId.toString(),
+			});
+			await client.tallyVotes(administrator, sessionId);
+
+			const batmanBalance = await connection.getBalance(batman.publicKey);
+			const refund = await settleDeposit(sessionId, 2, batman.publicKey);
+			assert.isTrue(refund.events.proposalDepositSettled.refunded);
+			assert.equal(await connection.getBalance(batman.publicKey), batmanBalance + deposit.toNumber());
+
+			const supermanBalance = await connection.getBalance(superman.publicKey);
+			const forfeit = await settleDeposit(sessionId, 3, superman.publicKey);
+			assert.isFalse(forfeit.events.proposalDepositSettled.refunded);
+			assert.equal(await connection.getBalance(superman.publicKey), supermanBalance);
+
+			await assertError(() => settleDeposit(sessionId, 2, batman.publicKey), {
+				number: 6049,
+				code: 'DepositAlreadySettled',
+				errorMessage: 'Deposit already settled',
+				programId: program.programId.toString(),
+			});
+		});
+
+		it('> should forfeit deposits when nobody voted', async () => {
+			const sessionId = await openSession('No votes deposits', [batman]);
+			await configureDeposit(sessionId, 1);
+			await client.startProposalsRegistration(administrator, sessionId);
+			await client.registerProposal(batman, sessionId, 'Batcave for everyone'); // 2
+			await client.stopProposalsRegistration(administrator, sessionId);
+			await client.startVotingSession(administrator, sessionId);
+			await client.stopVotingSession(administrator, sessionId);
+			await client.tallyVotes(administrator, sessionId);
+
+			const batmanBalance = await connection.getBalance(batman.publicKey);
+			const { events } = await settleDeposit(sessionId, 2, batman.publicKey);
+			assert.isFalse(events.proposalDepositSettled.refunded);
+			assert.equal(await connection.getBalance(batman.publicKey), batmanBalance);
+		});
+
+		it('> should reject voter accounts of another session', async () => {
+			const sessionId = await openSession('Capped deposits', [batman]);
+			const otherSessionId = await openSession('Other deposits', [batman]);
+			await configureDeposit(sessionId, 5000);
+			await client.startProposalsRegistration(administrator, sessionId);
+			await client.registerProposal(batman, sessionId, 'Batcave for everyone'); // 2
+
+			// the voter account of another session does not count the proposals of this one
+			await assertError(
+				() =>
+					send(
+						batman,
+						program.methods.registerProposal('Batmobile for everyone', '', Array(32).fill(0)).accountsPartial({
+							proposer: batman.publicKey,
+							sessionAccount: client.findSessionAccountAddress(sessionId),
+							voterAccount: client.findVoterAccountAddress(otherSessionId, batman.publicKey),
+							proposalAccount: client.findProposalAccountAddress(sessionId, 3),
+							vaultAccount: client.findVaultAccountAddress(sessionId),
+							depositMint: null,
+							proposerTokenAccount: null,
+							vaultTokenAccount: null,
+							tokenProgram: null,
+							questionAccount: null,
+						}),
+					),
+				{
+					number: 6005,
+					code: 'ProposerNotRegistered',
+					errorMessage: 'Proposer must be registered as voter',
+					programId: program.programId.toString(),
+				},
+			);
+		});
+	});
+
+	describe('> Executable proposals', () => {
+		const recipient = anchor.web3.Keypair.generate().publicKey;
+		const amount = 500000000;
+
+		const findExecutorAddress = (sessionId: BN) => PublicKey.findProgramAddressSync([Buffer.from('executor'), sessionId.toBuffer('le', 8)], program.programId)[0];
+		const findProposalTransactionAddress = (sessionId: BN, proposalId: number) =>
+			PublicKey.findProgramAddressSync([Buffer.from('proposal_transaction'), sessionId.toBuffer('le', 8), Buffer.from([proposalId])], program.programId)[0];
+
+		const attach = (proposer: Wallet, sessionId: BN, proposalId: number, instructions: TransactionInstruction[]) =>
+			send(
+				proposer,
+				program.methods.attachProposalTransaction(instructions.map((ix) => ({ programId: ix.programId, accounts: ix.keys, data: ix.data }))).accountsPartial({
+					proposer: proposer.publicKey,
+					sessionAccount: client.findSessionAccountAddress(sessionId),
+					proposalAccount: client.findProposalAccountAddress(sessionId, proposalId),
+					proposalTransactionAccount: findProposalTransactionAddress(sessionId, proposalId),
+				}),
+			);
+
+		const execute = (sessionId: BN, proposalId: number) =>
+			send(
+				administrator,
+				program.methods
+					.executeWinningProposal()
+					.accountsPartial({
+						payer: administrator.publicKey,
+						sessionAccount: client.findSessionAccountAddress(sessionId),
+						proposalTransactionAccount: findProposalTransactionAddress(sessionId, proposalId),
+					})
+					.remainingAccounts([
+						{ pubkey: recipient, isWritable: true, isSigner: false },
+						{ pubkey: SystemProgram.programId, isWritable: false, isSigner: false },
+					]),
+			);
+
+		it('> should run the winning proposal instructions once, signed by the session executor', async () => {
+			const sessionId = await openSession('Executable', [batman, superman]);
+			const executor = findExecutorAddress(sessionId);
+			await client.airdrop(executor, 1);
+
+			await client.startProposalsRegistration(administrator, sessionId);
+			await client.registerProposal(batman, sessionId, 'Pay the recipient'); // 2
+			await client.registerProposal(superman, sessionId, 'Pay the recipient too'); // 3
+			await client.registerProposal(batman, sessionId, 'Call the voting program'); // 4
+
+			const transfer = SystemProgram.transfer({ fromPubkey: executor, toPubkey: recipient, lamports: amount });
+			const { events } = await attach(batman, sessionId, 2, [transfer]);
+			assert.equal(events.proposalTransactionAttached.instructionsCount, 1);
+			await attach(superman, sessionId, 3, [transfer]);
+
+			await assertError(() => attach(batman, sessionId, 4, []), {
+				number: 6051,
+				code: 'EmptyProposalTransaction',
+				errorMessage: 'Proposal transaction is empty',
+				programId: program.programId.toString(),
+			});
+			await assertError(() => attach(batman, sessionId, 4, [new TransactionInstruction({ programId: program.programId, keys: [], data: Buffer.alloc(0) })]), {
+				number: 6052,
+				code: 'ForbiddenProposalInstruction',
+				errorMessage: 'Proposal transaction can not invoke the voting program',
+				programId: program.programId.toString(),
+			});
+
+			await client.stopProposalsRegistration(administrator, sessionId);
+			await client.startVotingSession(administrator, sessionId);
+			await client.vote(batman, sessionId, 2);
+			await client.vote(superman, sessionId, 2);
+			await client.stopVotingSession(administrator, sessionId);
+			await client.tallyVotes(administrator, sessionId);
+
+			await assertError(() => execute(sessionId, 3), {
+				number: 6053,
+				code: 'ProposalNotWinning',
+				errorMessage: 'Proposal is not the single winning proposal',
+				programId: program.programId.toString(),
+			});
+
+			const executed = await execute(sessionId, 2);
+			assert.equal(executed.events.proposalExecuted.proposalId, 2);
+			assert.equal(await connection.getBalance(recipient), amount);
+
+			await assertError(() => execute(sessionId, 2), {
+				number: 6069,
+				code: 'ProposalAlreadyExecuted',
+				errorMessage: 'Proposal already executed',
+				programId: program.programId.toString(),
+			});
+		});
+	});
+
+	describe('> Session treasury', () => {
+		const recipient = anchor.web3.Keypair.generate().publicKey;
+		const amount = 300000000;
+
+		const requestTransfer = (proposer: Wallet, sessionId: BN, proposalId: number, amount: number) =>
+			send(
+				proposer,
+				program.methods.requestTreasuryTransfer(recipient, null, new BN(amount)).accountsPartial({
+					proposer: proposer.publicKey,
+					sessionAccount: client.findSessionAccountAddress(sessionId),
+					proposalAccount: client.findProposalAccountAddress(sessionId, proposalId),
+				}),
+			);
+
+		const deposit = (depositor: Wallet, sessionId: BN, amount: number) =>
+			send(
+				depositor,
+				program.methods.depositToTreasury(new BN(amount)).accountsPartial({
+					depositor: depositor.publicKey,
+					sessionAccount: client.findSessionAccountAddress(sessionId),
+					mint: null,
+					depositorTokenAccount: null,
+					treasuryTokenAccount: null,
+					tokenProgram: null,
+					associatedTokenProgram: null,
+				}),
+			);
+
+		const withdraw = (sessionId: BN, proposalId: number) =>
+			send(
+				administrator,
+				program.methods.withdrawFromTreasury().accountsPartial({
+					payer: administrator.publicKey,
+					sessionAccount: client.findSessionAccountAddress(sessionId),
+					proposalAccount: client.findProposalAccountAddress(sessionId, proposalId),
+					recipient,
+					mint: null,
+					treasuryTokenAccount: null,
+					recipientTokenAccount: null,
+					tokenProgram: null,
+				}),
+			);
+
+		it('> should only pay the transfer requested by the winning proposal', async () => {
+			const sessionId = await openSession('Treasury', [batman, superman]);
+			await client.startProposalsRegistration(administrator, sessionId);
+			await client.registerProposal(batman, sessionId, 'Pay the recipient'); // 2
+			await client.registerProposal(superman, sessionId, 'Pay the recipient more'); // 3
+
+			const { events } = await requestTransfer(batman, sessionId, 2, amount);
+			assert.equal(events.treasuryTransferRequested.recipient.toString(), recipient.toString());
+			assert.equal(events.treasuryTransferRequested.amount.toNumber(), amount);
+			await assertError(() => requestTransfer(batman, sessionId, 2, amount), {
+				number: 6075,
+				code: 'TreasuryTransferAlreadyRequested',
+				errorMessage: 'Treasury transfer already requested',
+				programId: program.programId.toString(),
+			});
+			await assertError(() => requestTransfer(superman, sessionId, 3, 0), {
+				number: 6071,
+				code: 'InvalidAmount',
+				errorMessage: 'Invalid amount',
+				programId: program.programId.toString(),
+			});
+			await requestTransfer(superman, sessionId, 3, 2 * amount);
+
+			await deposit(superman, sessionId, amount / 2);
+
+			await client.stopProposalsRegistration(administrator, sessionId);
+			await client.startVotingSession(administrator, sessionId);
+			await client.vote(batman, sessionId, 2);
+			await client.vote(superman, sessionId, 2);
+			await client.stopVotingSession(administrator, sessionId);
+			await client.tallyVotes(administrator, sessionId);
+
+			await assertError(() => withdraw(sessionId, 3), {
+				number: 6053,
+				code: 'ProposalNotWinning',
+				errorMessage: 'Proposal is not the single winning proposal',
+				programId: program.programId.toString(),
+			});
+			await assertError(() => withdraw(sessionId, 2), {
+				number: 6076,
+				code: 'InsufficientTreasuryFunds',
+				errorMessage: 'Insufficient treasury funds',
+				programId: program.programId.toString(),
+			});
+
+			await deposit(batman, sessionId, amount / 2);
+			await withdraw(sessionId, 2);
+			assert.equal(await connection.getBalance(recipient), amount);
+
+			await assertError(() => withdraw(sessionId, 2), {
+				number: 6069,
+				code: 'ProposalAlreadyExecuted',
+				errorMessage: 'Proposal already executed',
+				programId: program.programId.toString(),
+			});
+		});
+	});
+
+	describe('> Execution timelock', () => {
+		const getSessionAccount = (sessionId: BN) => program.account.sessionAccount.fetch(client.findSessionAccountAddress(sessionId));
+
+		const configureExecution = (sessionId: BN, executionDelay: number, vetoCouncil: PublicKey | null) =>
+			send(
+				administrator,
+				program.methods.configureExecution(new BN(executionDelay), vetoCouncil).accountsPartial({
+					admin: administrator.publicKey,
+					sessionAccount: client.findSessionAccountAddress(sessionId),
+				}),
+			);
+
+		const releaseResult = (sessionId: BN) =>
+			send(administrator, program.methods.releaseResult().accountsPartial({ payer: administrator.publicKey, sessionAccount: client.findSessionAccountAddress(sessionId) }));
+
+		const vetoResult = (vetoCouncil: Wallet, sessionId: BN) =>
+			send(vetoCouncil, program.methods.vetoResult().accountsPartial({ vetoCouncil: vetoCouncil.publicKey, sessionAccount: client.findSessionAccountAddress(sessionId) }));
+
+		const withdraw = (sessionId: BN, proposalId: number) =>
+			send(
+				administrator,
+				program.methods.withdrawFromTreasury().accountsPartial({
+					payer: administrator.publicKey,
+					sessionAccount: client.findSessionAccountAddress(sessionId),
+					proposalAccount: client.findProposalAccountAddress(sessionId, proposalId),
+					recipient: administrator.publicKey,
+					mint: null,
+					treasuryTokenAccount: null,
+					recipientTokenAccount: null,
+					tokenProgram: null,
+				}),
+			);
+
+		const tallySession = async (name: string, executionDelay: number, vetoCouncil: PublicKey | null) => {
+			const sessionId = await openSession(name, [batman]);
+			await configureExecution(sessionId, executionDelay, vetoCouncil);
+			await client.startProposalsRegistration(administrator, sessionId);
+			await client.registerProposal(batman, sessionId, 'Batcave for everyone'); // 2
+			await client.stopProposalsRegistration(administrator, sessionId);
+			await client.startVotingSession(administrator, sessionId);
+			await client.vote(batman, sessionId, 2);
+			await client.stopVotingSession(administrator, sessionId);
+			const { events } = await client.tallyVotes(administrator, sessionId);
+			return { sessionId, events };
+		};
+
+		it('> should reject negative or longer than a year execution delays', async () => {
+			const sessionId = await openSession('Timelock bounds', []);
+			for (const executionDelay of [-1, 365 * 24 * 60 * 60 + 1]) {
+				await assertError(() => configureExecution(sessionId, executionDelay, null), {
+					number: 6050,
+					code: 'InvalidExecutionConfig',
+					errorMessage: 'Invalid execution configuration',
+					programId: program.programId.toString(),
+				});
+			}
+		});
+
+		it('> should release the result at tally without timelock', async () => {
+			const { sessionId, events } = await tallySession('No timelock', 0, superman.publicKey);
+
+			const session = await getSessionAccount(sessionId);
+			assert.isTrue(session.releasedAt.gtn(0));
+			assert.equal(session.releasedAt.toString(), session.talliedAt.toString());
+			assert.equal(session.executionEta.toString(), session.talliedAt.toString());
+			assert.equal(events.resultReleased.releasedAt.toString(), session.releasedAt.toString());
+
+			await assertError(() => releaseResult(sessionId), {
+				number: 6058,
+				code: 'ResultAlreadyReleased',
+				errorMessage: 'Result already released',
+				programId: program.programId.toString(),
+			});
+			await assertError(() => vetoResult(superman, sessionId), {
+				number: 6056,
+				code: 'VetoWindowClosed',
+				errorMessage: 'Veto window is closed',
+				programId: program.programId.toString(),
+			});
+		});
+
+		it('> should hold the result until the timelock elapsed, unless vetoed', async () => {
+			const executionDelay = 3600;
+			const { sessionId, events } = await tallySession('Timelock', executionDelay, superman.publicKey);
+
+			const session = await getSessionAccount(sessionId);
+			assert.equal(session.releasedAt.toNumber(), 0);
+			assert.equal(session.executionEta.toNumber(), session.talliedAt.toNumber() + executionDelay);
+			assert.equal(events.resultQueued.executionEta.toString(), session.executionEta.toString());
+			assert.isUndefined(events.resultReleased);
+
+			await assertError(() => releaseResult(sessionId), {
+				number: 6054,
+				code: 'TimelockNotElapsed',
+				errorMessage: 'Execution timelock not elapsed',
+				programId: program.programId.toString(),
+			});
+			await assertError(() => withdraw(sessionId, 2), {
+				number: 6057,
+				code: 'ResultNotReleased',
+				errorMessage: 'Result is not released',
+				programId: program.programId.toString(),
+			});
+			await assertError(() => vetoResult(batman, sessionId), {
+				number: 6055,
+				code: 'ForbiddenAsNonVetoCouncil',
+				errorMessage: 'Forbidden as non veto council',
+				programId: program.programId.toString(),
+			});
+
+			const vetoed = await vetoResult(superman, sessionId);
+			assert.equal(vetoed.events.resultVetoed.vetoCouncil.toString(), superman.publicKey.toString());
+			assert.deepEqual((await client.getSession(client.findSessionAccountAddress(sessionId))).status, VotingSessionStatus.Vetoed);
+		});
+	});
+
+	describe('> Organization sessions', () => {
+		const robin = new Wallet(anchor.web3.Keypair.generate());
+		const flash = new Wallet(anchor.web3.Keypair.generate());
+
+		const findOrganizationAddress = (organizationId: BN) => PublicKey.findProgramAddressSync([Buffer.from('organization'), organizationId.toBuffer('le', 8)], program.programId)[0];
+		const findMemberAddress = (organizationId: BN, member: PublicKey) =>
+			PublicKey.findProgramAddressSync([Buffer.from('member'), organizationId.toBuffer('le', 8), member.toBuffer()], program.programId)[0];
+
+		const getVoters = async (sessionId: BN) => {
+			const { votersCount, votersWeight } = await program.account.sessionAccount.fetch(client.findSessionAccountAddress(sessionId));
+			return { votersCount, votersWeight };
+		};
+
+		const joinSession = async (member: Wallet, organizationId: BN, sessionId: BN) =>
+			send(
+				member,
+				program.methods.joinSession().accountsPartial({
+					member: member.publicKey,
+					sessionAccount: client.findSessionAccountAddress(sessionId),
+					memberAccount: findMemberAddress(organizationId, member.publicKey),
+					voterAccount: client.findVoterAccountAddress(sessionId, member.publicKey),
+					voterSessionIndexPage: await client.findSessionIndexPageAddress('voter_sessions', member.publicKey),
+				}),
+			);
+
+		it('> should count the organization members joining the session as voters', async () => {
+			await client.airdrop(robin.publicKey, 1);
+			await client.airdrop(flash.publicKey, 1);
+
+			const { organizationCount: organizationId } = await program.account.globalAccount.fetch(client.findGlobalAccountAddress());
+			const organizationAccount = findOrganizationAddress(organizationId);
+			await send(administrator, program.methods.createOrganization('Justice League').accountsPartial({ owner: administrator.publicKey, organizationAccount }));
+			for (const member of [administrator, batman, superman, robin, flash]) {
+				await send(
+					administrator,
+					program.methods.addOrganizationMember(member.publicKey).accountsPartial({
+						admin: administrator.publicKey,
+						organizationAccount,
+						memberAccount: findMemberAddress(organizationId, member.publicKey),
+					}),
+				);
+			}
+			await send(
+				administrator,
+				program.methods.removeOrganizationMember().accountsPartial({
+					admin: administrator.publicKey,
+					organizationAccount,
+					memberAccount: findMemberAddress(organizationId, robin.publicKey),
+				}),
+			);
+
+			const global = await client.getGlobal();
+			const sessionId = global.sessionCount;
+			await send(
+				administrator,
+				program.methods.createVotingSession('League', 'League session', '', new Array(32).fill(0)).accountsPartial({
+					owner: administrator.publicKey,
+					sessionAccount: client.findSessionAccountAddress(sessionId),
+					authority: global.authority,
+					organizationAccount,
+					adminSessionIndexPage: await client.findSessionIndexPageAddress('admin_sessions', administrator.publicKey),
+				}),
+			);
+			assert.deepEqual(await getVoters(sessionId), { votersCount: 0, votersWeight: 0 });
+
+			await joinSession(batman, organizationId, sessionId);
+			await joinSession(superman, organizationId, sessionId);
+			assert.deepEqual(await getVoters(sessionId), { votersCount: 2, votersWeight: 2 });
+
+			await assertError(() => joinSession(administrator, organizationId, sessionId), {
+				number: 6001,
+				code: 'AdminForbiddenAsVoter',
+				errorMessage: 'Voting session administrator can not be registered as voter',
+				programId: program.programId.toString(),
+			});
+			await assertError(() => joinSession(robin, organizationId, sessionId), {
+				number: 6066,
+				code: 'MemberNotRegistered',
+				errorMessage: 'Member not registered',
+				programId: program.programId.toString(),
+			});
+			assert.deepEqual(await getVoters(sessionId), { votersCount: 2, votersWeight: 2 });
+
+			await client.startProposalsRegistration(administrator, sessionId);
+			await client.registerProposal(batman, sessionId, 'Watchtower for everyone'); // 2
+			await client.stopProposalsRegistration(administrator, sessionId);
+			await client.startVotingSession(administrator, sessionId);
+
+			// voters are known once voting starts
+			await assertError(() => joinSession(flash, organizationId, sessionId), {
+				number: 6000,
+				code: 'UnexpectedSessionStatus',
+				errorMessage: 'Unexpected session status',
+				programId: program.programId.toString(),
+			});
+
+			await client.vote(batman, sessionId, 2);
+			await client.stopVotingSession(administrator, sessionId);
+			await client.tallyVotes(administrator, sessionId);
+
+			const { result } = await program.account.sessionAccount.fetch(client.findSessionAccountAddress(sessionId));
+			assert.equal(result.abstention, 1);
+			assert.equal(result.abstentionCount, 1);
+		});
+	});
+
+	describe('> Session indexes', () => {
+		it('> should list the sessions of their administrator and of their voters', async () => {
+			const joker = new Wallet(anchor.web3.Keypair.generate());
+			const adminSessionIds = await client.getSessionIds('admin_sessions', administrator.publicKey);
+			assert.deepEqual(await client.getSessionIds('voter_sessions', joker.publicKey), []);
+
+			const firstSessionId = await openSession('Indexed A', [joker]);
+			const secondSessionId = await openSession('Indexed B', [joker, batman]);
+
+			const ids = (sessionIds: BN[]) => sessionIds.map((sessionId) => sessionId.toString());
+			assert.deepEqual(ids(await client.getSessionIds('admin_sessions', administrator.publicKey)), ids([...adminSessionIds, firstSessionId, secondSessionId]));
+			assert.deepEqual(ids(await client.getSessionIds('voter_sessions', joker.publicKey)), ids([firstSessionId, secondSessionId]));
+			assert.include(ids(await client.getSessionIds('voter_sessions', batman.publicKey)), secondSessionId.toString());
+			assert.notInclude(ids(await client.getSessionIds('voter_sessions', batman.publicKey)), firstSessionId.toString());
+		});
+	});
+
+	describe('> Session metadata', () => {
+		const metadataUri = 'ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi';
+		const metadataHash = [...createHash('sha256').update('The full session document').digest()];
+
+		it('> should record and emit the session and proposal metadata', async () => {
+			const { events } = await client.createVotingSession(administrator, 'Documented', 'Documented session', metadataUri, metadataHash);
+			assert.equal(events.sessionCreated.metadataUri, metadataUri);
+			assert.deepEqual(events.sessionCreated.metadataHash, metadataHash);
+
+			const sessionId: BN = events.sessionCreated.sessionId;
+			const session = await program.account.sessionAccount.fetch(client.findSessionAccountAddress(sessionId));
+			assert.equal(session.metadataUri, metadataUri);
+			assert.deepEqual(session.metadataHash, metadataHash);
+
+			await client.registerVoter(administrator, sessionId, batman.publicKey);
+			await client.startProposalsRegistration(administrator, sessionId);
+			const registered = await client.registerProposal(batman, sessionId, 'Documented proposal', metadataUri, metadataHash); // 2
+			assert.equal(registered.events.proposalRegistered.metadataUri, metadataUri);
+			assert.deepEqual(registered.events.proposalRegistered.metadataHash, metadataHash);
+
+			const proposal = await program.account.proposalAccount.fetch(client.findProposalAccountAddress(sessionId, 2));
+			assert.equal(proposal.metadataUri, metadataUri);
+			assert.deepEqual(proposal.metadataHash, metadataHash);
+
+			await assertError(() => client.registerProposal(batman, sessionId, 'x'.repeat(256)), {
+				number: 6019,
+				code: 'ProposalDescriptionTooLong',
+				errorMessage: 'Proposal description is too long',
+				programId: program.programId.toString(),
+			});
+		});
+
+		it('> should reject long metadata URIs and hashes without URI', async () => {
+			await assertError(() => client.createVotingSession(administrator, 'Documented', 'Documented session', 'u'.repeat(201), metadataHash), {
+				number: 6021,
+				code: 'MetadataUriTooLong',
+				errorMessage: 'Metadata URI is too long',
+				programId: program.programId.toString(),
+			});
+			await assertError(() => client.createVotingSession(administrator, 'Documented', 'Documented session', '', metadataHash), {
+				number: 6022,
+				code: 'MissingMetadataUri',
+				errorMessage: 'Metadata hash requires a metadata URI',
+				programId: program.programId.toString(),
+			});
+		});
+	});
+
+	describe('> Multi-question ballots', () => {
+		it('> should tally each question on its own with one voter account', async () => {
+			const sessionId = await openSession('Assembly', [batman, superman]);
+			await client.startProposalsRegistration(administrator, sessionId);
+
+			const { events } = await client.addQuestion(administrator, sessionId, 'Raise the budget?');
+			assert.equal(events.questionAdded.questionId, 1);
+			assert.equal(events.questionAdded.blankProposalId, 2);
+
+			await client.registerProposal(batman, sessionId, 'Batcave for everyone'); // 3, main question
+			await client.registerProposal(superman, sessionId, 'Krypton for everyone'); // 4, main question
+			await client.registerProposal(superman, sessionId, 'Raise it by 10%', '', undefined, 1); // 5, question 1
+			const proposal = await program.account.proposalAccount.fetch(client.findProposalAccountAddress(sessionId, 5));
+			assert.equal(proposal.questionId, 1);
+
+			await client.stopProposalsRegistration(administrator, sessionId);
+			await client.startVotingSession(administrator, sessionId);
+
+			// one ballot per question
+			await client.vote(batman, sessionId, 3);
+			await client.vote(batman, sessionId, 5);
+			await client.vote(superman, sessionId, 3);
+			await assertError(() => client.vote(superman, sessionId, 4), {
+				number: 6006,
+				code: 'VoterAlreadyVoted',
+				errorMessage: 'Voter already voted',
+				programId: program.programId.toString(),
+			});
+			await client.vote(superman, sessionId, 2);
+			await assertError(() => client.vote(batman, sessionId, 2), {
+				number: 6006,
+				code: 'VoterAlreadyVoted',
+				errorMessage: 'Voter already voted',
+				programId: program.programId.toString(),
+			});
+
+			const voter = await program.account.voterAccount.fetch(client.findVoterAccountAddress(sessionId, superman.publicKey));
+			assert.deepEqual(voter.questionVotes.slice(0, 2), [3, 2]);
+
+			await client.stopVotingSession(administrator, sessionId);
+			await client.tallyVotes(administrator, sessionId);
+
+			const { result } = await program.account.sessionAccount.fetch(client.findSessionAccountAddress(sessionId));
+			assert.deepEqual(
+				{ totalVotes: result.totalVotes, blankVotes: result.blankVotes, abstention: result.abstention, winningProposals: result.winningProposals },
+				{ totalVotes: 2, blankVotes: 0, abstention: 0, winningProposals: [3] },
+			);
+			const question = await program.account.questionAccount.fetch(client.findQuestionAccountAddress(sessionId, 1));
+			const { questionId, totalVotes, blankVotes, abstention, winningProposals } = question.result;
+			assert.deepEqual({ questionId, totalVotes, blankVotes, abstention, winningProposals }, { questionId: 1, totalVotes: 2, blankVotes: 1, abstention: 0, winningProposals: [5] });
+		});
+	});
+
+	describe('> Score voting', () => {
+		const voteScores = (voter: Wallet, sessionId: BN, scores: { proposalId: number; value: number }[]) =>
+			send(
+				voter,
+				program.methods
+					.voteScores(scores)
+					.accountsPartial({
+						voter: voter.publicKey,
+						sessionAccount: client.findSessionAccountAddress(sessionId),
+						voterAccount: client.findVoterAccountAddress(sessionId, voter.publicKey),
+						ballotAccount: findBallotAddress(sessionId, voter.publicKey),
+					})
+					.remainingAccounts(
+						proposalAccounts(
+							sessionId,
+							scores.map(({ proposalId }) => proposalId),
+						),
+					),
+			);
+
+		it('> should rank proposals by mean score over all ballots, unscored proposals counting as 0', async () => {
+			const sessionId = await openSession('Scores', [batman, superman]);
+			await configureVotingMode(sessionId, { score: { maxScore: 10 } }, 1);
+			await client.startProposalsRegistration(administrator, sessionId);
+			await client.registerProposal(batman, sessionId, 'Batcave for everyone'); // 2
+			await client.registerProposal(superman, sessionId, 'Krypton for everyone'); // 3
+			await client.stopProposalsRegistration(administrator, sessionId);
+			await client.startVotingSession(administrator, sessionId);
+
+			// proposal 2 has the best mean among the ballots scoring it (10 against 6),
+			// but its mean over all ballots is 5 against 6 for proposal 3
+			await voteScores(batman, sessionId, [
+				{ proposalId: 2, value: 10 },
+				{ proposalId: 3, value: 6 },
+			]);
+			await voteScores(superman, sessionId, [{ proposalId: 3, value: 6 }]);
+			await assertError(() => voteScores(superman, sessionId, [{ proposalId: 2, value: 1 }]), {
+				number: 6006,
+				code: 'VoterAlreadyVoted',
+				errorMessage: 'Voter already voted',
+				programId: program.programId.toString(),
+			});
+
+			await client.stopVotingSession(administrator, sessionId);
+			await client.tallyVotes(administrator, sessionId);
+
+			const result = await getResult(sessionId);
+			assert.deepEqual(result.winningProposals, [3]);
+			assert.deepEqual(result.tiedProposals, []);
+			assert.equal(result.totalVotes, 2);
+			assert.equal(result.scoreDistribution[6], 2);
+			assert.equal(result.scoreDistribution[10], 1);
+		});
+	});
+
+	describe('> Condorcet voting', () => {
+		const voteRanked = (voter: Wallet, sessionId: BN, rankings: { proposalId: number; value: number }[]) =>
+			send(
+				voter,
+				program.methods.voteRanked(rankings).accountsPartial({
+					voter: voter.publicKey,
+					sessionAccount: client.findSessionAccountAddress(sessionId),
+					voterAccount: client.findVoterAccountAddress(sessionId, voter.publicKey),
+					ballotAccount: findBallotAddress(sessionId, voter.publicKey),
+					pairwiseAccount: client.findPairwiseAccountAddress(sessionId),
+				}),
+			);
+
+		it('> should elect the Schulze winner of the weighted ranked ballots', async () => {
+			const sessionId = await openSession('Ranked', [superman]);
+			await client.registerVoter(administrator, sessionId, batman.publicKey, 2);
+			await configureVotingMode(sessionId, { condorcet: {} }, 1);
+			await client.startProposalsRegistration(administrator, sessionId);
+			await client.registerProposal(batman, sessionId, 'Batcave for everyone'); // 2
+			await client.registerProposal(superman, sessionId, 'Krypton for everyone'); // 3
+			await client.registerProposal(superman, sessionId, 'Fortress for everyone'); // 4
+			await client.stopProposalsRegistration(administrator, sessionId);
+			await client.startVotingSession(administrator, sessionId);
+
+			// unranked proposals come after the ranked ones
+			await voteRanked(batman, sessionId, [
+				{ proposalId: 2, value: 1 },
+				{ proposalId: 3, value: 2 },
+			]);
+			await voteRanked(superman, sessionId, [{ proposalId: 3, value: 1 }]);
+			await assertError(() => voteRanked(superman, sessionId, [{ proposalId: 1, value: 1 }]), {
+				number: 6006,
+				code: 'VoterAlreadyVoted',
+				errorMessage: 'Voter already voted',
+				programId: program.programId.toString(),
+			});
+
+			const pairwise = await program.account.pairwiseMatrixAccount.fetch(client.findPairwiseAccountAddress(sessionId));
+			assert.deepEqual(
+				pairwise.preferences.slice(0, 3).map((row) => row.slice(0, 3)),
+				[
+					[0, 2, 2],
+					[1, 0, 3],
+					[0, 0, 0],
+				],
+			);
+
+			await client.stopVotingSession(administrator, sessionId);
+			await client.tallyVotes(administrator, sessionId);
+
+			const result = await getResult(sessionId);
+			assert.deepEqual(result.winningProposals, [2]);
+			assert.isTrue(result.hasCondorcetWinner);
+			assert.equal(result.totalVotes, 3);
+		});
+	});
+
+	describe('> Seats', () => {
+		const tallySession = async (name: string, seats: number, ballots: [Wallet, number[]][]) => {
+			const sessionId = await openSession(name, [batman, superman]);
+			await configureVotingMode(sessionId, { plurality: {} }, seats);
+			await client.startProposalsRegistration(administrator, sessionId);
+			await client.registerProposal(batman, sessionId, 'Batcave for everyone'); // 2
+			await client.registerProposal(superman, sessionId, 'Krypton for everyone'); // 3
+			await client.registerProposal(superman, sessionId, 'Fortress for everyone'); // 4
+			await client.stopProposalsRegistration(administrator, sessionId);
+			await client.startVotingSession(administrator, sessionId);
+			for (const [voter, proposalIds] of ballots) {
+				for (const proposalId of proposalIds) {
+					await client.vote(voter, sessionId, proposalId);
+				}
+			}
+			await client.stopVotingSession(administrator, sessionId);
+			await client.tallyVotes(administrator, sessionId);
+			return client.getSession(client.findSessionAccountAddress(sessionId));
+		};
+
+		it('> should record the proposals tied at the top of a single seat session apart', async () => {
+			const { result } = await tallySession('Single seat', 1, [
+				[batman, [2]],
+				[superman, [4]],
+			]);
+			assert.deepEqual(result.winningProposals, []);
+			assert.deepEqual(result.tiedProposals, [2, 4]);
+		});
+
+		it('> should record the proposals tied at the last seat apart, voters having one vote per seat', async () => {
+			const { result } = await tallySession('Two seats', 2, [
+				[batman, [2, 3]],
+				[superman, [2, 4]],
+			]);
+			assert.deepEqual(result.winningProposals, [2]);
+			assert.deepEqual(result.tiedProposals, [3, 4]);
+			// each voter has two votes, abstention is based on the ballots
+			assert.equal(result.totalVotes, 4);
+			assert.equal(result.abstention, 0);
+		});
+	});
+
+	describe('> Quadratic voting', () => {
+		it('> should charge n² credits for n votes within the voter budget', async () => {
+			const sessionId = await openSession('Quadratic', [batman, superman]);
+			await configureVotingMode(sessionId, { quadratic: { credits: 10 } }, 1);
+			await client.startProposalsRegistration(administrator, sessionId);
+			await client.registerProposal(batman, sessionId, 'Batcave for everyone'); // 2
+			await client.registerProposal(superman, sessionId, 'Krypton for everyone'); // 3
+			await client.stopProposalsRegistration(administrator, sessionId);
+			await client.startVotingSession(administrator, sessionId);
+
+			await allocateVotes(batman, sessionId, [{ proposalId: 2, value: 3 }]);
+			await assertError(
+				() =>
+					allocateVotes(superman, sessionId, [
+						{ proposalId: 2, value: 3 },
+						{ proposalId: 3, value: 2 },
+					]),
+				{
+					number: 6033,
+					code: 'InsufficientCredits',
+					errorMessage: 'Votes cost exceeds the voter credits or points',
+					programId: program.programId.toString(),
+				},
+			);
+			await allocateVotes(superman, sessionId, [
+				{ proposalId: 2, value: 2 },
+				{ proposalId: 3, value: 2 },
+			]);
+
+			const batmanVoter = await program.account.voterAccount.fetch(client.findVoterAccountAddress(sessionId, batman.publicKey));
+			const supermanVoter = await program.account.voterAccount.fetch(client.findVoterAccountAddress(sessionId, superman.publicKey));
+			assert.equal(batmanVoter.creditsSpent, 9);
+			assert.equal(supermanVoter.creditsSpent, 8);
+
+			await client.stopVotingSession(administrator, sessionId);
+			await client.tallyVotes(administrator, sessionId);
+
+			// voters cast more votes than their weight, abstention is based on the ballots
+			const result = await getResult(sessionId);
+			assert.deepEqual(result.winningProposals, [2]);
+			assert.equal(result.totalVotes, 7);
+			assert.equal(result.abstention, 0);
+		});
+
+		it('> should spend down the voter own budget across allocations', async () => {
+			const sessionId = await openSession('Quadratic budgets', [superman]);
+			await client.registerVoter(administrator, sessionId, batman.publicKey, null, 20);
+			await configureVotingMode(sessionId, { quadratic: { credits: 10 } }, 1);
+			await client.startProposalsRegistration(administrator, sessionId);
+			await client.registerProposal(batman, sessionId, 'Batcave for everyone'); // 2
+			await client.registerProposal(superman, sessionId, 'Krypton for everyone'); // 3
+			await client.registerProposal(superman, sessionId, 'Fortress for everyone'); // 4
+			await client.stopProposalsRegistration(administrator, sessionId);
+			await client.startVotingSession(administrator, sessionId);
+
+			// batman budget is 20 credits, superman gets the session 10 credits
+			await allocateVotes(batman, sessionId, [{ proposalId: 2, value: 4 }]);
+			await allocateVotes(superman, sessionId, [{ proposalId: 3, value: 3 }]);
+			await assertError(() => allocateVotes(superman, sessionId, [{ proposalId: 4, value: 2 }]), {
+				number: 6033,
+				code: 'InsufficientCredits',
+				errorMessage: 'Votes cost exceeds the voter credits or points',
+				programId: program.programId.toString(),
+			});
+			await assertError(() => allocateVotes(batman, sessionId, [{ proposalId: 2, value: 1 }]), {
+				number: 6010,
+				code: 'InvalidProposalId',
+				errorMessage: 'Invalid proposal',
+				programId: program.programId.toString(),
+			});
+			await allocateVotes(batman, sessionId, [{ proposalId: 3, value: 2 }]);
+			await allocateVotes(superman, sessionId, [{ proposalId: 4, value: 1 }]);
+
+			const batmanVoter = await program.account.voterAccount.fetch(client.findVoterAccountAddress(sessionId, batman.publicKey));
+			assert.equal(batmanVoter.creditsSpent, 20);
+			const ballot = await program.account.ballotAccount.fetch(findBallotAddress(sessionId, batman.publicKey));
+			assert.deepEqual(ballot.entries, [
+				{ proposalId: 2, value: 4 },
+				{ proposalId: 3, value: 2 },
+			]);
+			const { ballotsCount } = await program.account.sessionAccount.fetch(client.findSessionAccountAddress(sessionId));
+			assert.equal(ballotsCount, 2);
+
+			await client.stopVotingSession(administrator, sessionId);
+			await client.tallyVotes(administrator, sessionId);
+
+			const result = await getResult(sessionId);
+			assert.deepEqual(result.winningProposals, [3]);
+			assert.equal(result.totalVotes, 10);
+		});
+	});
+
+	describe('> Cumulative voting', () => {
+		const openCumulativeSession = async (name: string, points: number | null, seats: number) => {
+			const sessionId = await openSession(name, [batman, superman]);
+			await configureVotingMode(sessionId, { cumulative: { points } }, seats);
+			await client.startProposalsRegistration(administrator, sessionId);
+			await client.registerProposal(batman, sessionId, 'Batcave for everyone'); // 2
+			await client.registerProposal(superman, sessionId, 'Krypton for everyone'); // 3
+			await client.registerProposal(superman, sessionId, 'Fortress for everyone'); // 4
+			await client.stopProposalsRegistration(administrator, sessionId);
+			await client.startVotingSession(administrator, sessionId);
+			return sessionId;
+		};
+
+		it('> should let voters stack or split their points, and record their allocation', async () => {
+			const sessionId = await openCumulativeSession('Cumulative', 3, 1);
+
+			await allocateVotes(batman, sessionId, [{ proposalId: 2, value: 3 }]);
+			await assertError(
+				() =>
+					allocateVotes(superman, sessionId, [
+						{ proposalId: 3, value: 2 },
+						{ proposalId: 4, value: 2 },
+					]),
+				{
+					number: 6033,
+					code: 'InsufficientCredits',
+					errorMessage: 'Votes cost exceeds the voter credits or points',
+					programId: program.programId.toString(),
+				},
+			);
+			await allocateVotes(superman, sessionId, [
+				{ proposalId: 3, value: 1 },
+				{ proposalId: 4, value: 2 },
+			]);
+
+			// ballots can be audited against the proposals votes count
+			const ballot = await program.account.ballotAccount.fetch(findBallotAddress(sessionId, superman.publicKey));
+			assert.deepEqual(ballot.entries, [
+				{ proposalId: 3, value: 1 },
+				{ proposalId: 4, value: 2 },
+			]);
+			const proposal = await client.getProposal(client.findProposalAccountAddress(sessionId, 4));
+			assert.equal(proposal.voteCount, 2);
+
+			await client.stopVotingSession(administrator, sessionId);
+			await client.tallyVotes(administrator, sessionId);
+
+			const result = await getResult(sessionId);
+			assert.deepEqual(result.winningProposals, [2]);
+			assert.equal(result.totalVotes, 6);
+			assert.equal(result.abstention, 0);
+		});
+
+		it('> should give voters one point per seat by default', async () => {
+			const sessionId = await openCumulativeSession('Cumulative seats', null, 2);
+
+			await assertError(() => allocateVotes(batman, sessionId, [{ proposalId: 2, value: 3 }]), {
+				number: 6033,
+				code: 'InsufficientCredits',
+				errorMessage: 'Votes cost exceeds the voter credits or points',
+				programId: program.programId.toString(),
+			});
+			await allocateVotes(batman, sessionId, [
+				{ proposalId: 2, value: 1 },
+				{ proposalId: 3, value: 1 },
+			]);
+			await allocateVotes(superman, sessionId, [{ proposalId: 3, value: 2 }]);
+
+			await client.stopVotingSession(administrator, sessionId);
+			await client.tallyVotes(administrator, sessionId);
+
+			const result = await getResult(sessionId);
+			assert.deepEqual(result.winningProposals, [3, 2]);
+			assert.deepEqual(result.tiedProposals, []);
+		});
+	});
+
+	describe('> Weighted voters', () => {
+		it('> should weight votes and abstention while reporting raw headcounts', async () => {
+			const joker = new Wallet(anchor.web3.Keypair.generate());
+			const sessionId = await openSession('Weighted', []);
+			await client.registerVoter(administrator, sessionId, batman.publicKey, 3);
+			await client.registerVoter(administrator, sessionId, superman.publicKey);
+			await client.registerVoter(administrator, sessionId, joker.publicKey, 2);
+
+			await client.startProposalsRegistration(administrator, sessionId);
+			await client.registerProposal(batman, sessionId, 'Batcave for everyone'); // 2
+			await client.stopProposalsRegistration(administrator, sessionId);
+			await client.startVotingSession(administrator, sessionId);
+			await client.vote(batman, sessionId, 2);
+			await client.vote(superman, sessionId, 1);
+			await client.stopVotingSession(administrator, sessionId);
+			await client.tallyVotes(administrator, sessionId);
+
+			const result = await getResult(sessionId);
+			assert.equal(result.totalVotes, 4);
+			assert.equal(result.blankVotes, 1);
+			assert.equal(result.abstention, 2);
+			assert.equal(result.votedCount, 2);
+			assert.equal(result.abstentionCount, 1);
+		});
+
+		it('> should reject null weights and voters weight overflows', async () => {
+			const sessionId = await openSession('Overweighted', []);
+			await assertError(() => client.registerVoter(administrator, sessionId, batman.publicKey, 0), {
+				number: 6034,
+				code: 'InvalidVoterWeight',
+				errorMessage: 'Voter weight must be positive',
+				programId: program.programId.toString(),
+			});
+
+			await client.registerVoter(administrator, sessionId, batman.publicKey, 2 ** 32 - 1);
+			await assertError(() => client.registerVoter(administrator, sessionId, superman.publicKey, 1), {
+				number: 6101,
+				code: 'VotersWeightOverflow',
+				errorMessage: 'Voters weight overflow',
+				programId: program.programId.toString(),
+			});
+		});
+	});
+
+	describe('> Locked tokens voting power', () => {
+		it('> should not let voters vote before applying their voting power', async () => {
+			const lockMint = anchor.web3.Keypair.generate().publicKey;
+			const sessionId = await openSession('Locked', [batman]);
+			const { events } = await send(
+				administrator,
+				program.methods.configureVotingPower(lockMint).accountsPartial({ admin: administrator.publicKey, sessionAccount: client.findSessionAccountAddress(sessionId) }),
+			);
+			assert.equal(events.votingPowerConfigured.lockMint.toString(), lockMint.toString());
+
+			await client.startProposalsRegistration(administrator, sessionId);
+			await client.registerProposal(batman, sessionId, 'Batcave for everyone'); // 2
+			await client.stopProposalsRegistration(administrator, sessionId);
+			await client.startVotingSession(administrator, sessionId);
+
+			// registered weight does not count, only the applied locked tokens voting power
+			await assertError(() => client.vote(batman, sessionId, 2), {
+				number: 6042,
+				code: 'NoVotingPower',
+				errorMessage: 'No voting power',
+				programId: program.programId.toString(),
+			});
+		});
+
+		it('> should record the snapshot slot when voting opens', async () => {
+			const sessionId = await openSession('Snapshot', [batman]);
+			await client.startProposalsRegistration(administrator, sessionId);
+			await client.stopProposalsRegistration(administrator, sessionId);
+
+			const slot = await connection.getSlot();
+			await client.startVotingSession(administrator, sessionId);
+
+			// snapshots must be taken strictly before this slot
+			const session = await program.account.sessionAccount.fetch(client.findSessionAccountAddress(sessionId));
+			assert.isAtLeast(session.snapshotSlot.toNumber(), slot);
+			assert.isAbove(session.votingStartedAt.toNumber(), 0);
+		});
+	});
+
+	describe('> Relayed ballots', () => {
+		const ballotMessage = (sessionId: BN, proposalId: number, nonce: number) =>
+			Buffer.concat([Buffer.from('soltrain-voting:ballot'), program.programId.toBuffer(), sessionId.toBuffer('le', 8), Buffer.from([proposalId]), new BN(nonce).toBuffer('le', 8)]);
+
+		// single ed25519 program instruction verifying every ballot, each signature data held by the instruction itself
+		const ed25519Instruction = (ballots: { voter: Wallet; message: Buffer }[]) => {
+			const header = Buffer.alloc(2 + ballots.length * 14);
+			header.writeUInt8(ballots.length, 0);
+			const payloads: Buffer[] = [];
+			let offset = header.length;
+			ballots.forEach(({ voter, message }, index) => {
+				// public key (32), signature (64), message, as laid out by the single signature instruction
+				const signature = Ed25519Program.createInstructionWithPrivateKey({ privateKey: voter.payer.secretKey, message }).data.subarray(48, 112);
+				const offsets = [offset + 32, 0xffff, offset, 0xffff, offset + 96, message.length, 0xffff];
+				offsets.forEach((value, position) => header.writeUInt16LE(value, 2 + index * 14 + position * 2));
+				payloads.push(voter.publicKey.toBuffer(), Buffer.from(signature), message);
+				offset += 96 + message.length;
+			});
+			return new TransactionInstruction({ keys: [], programId: Ed25519Program.programId, data: Buffer.concat([header, ...payloads]) });
+		};
+
+		const relayVotes = (sessionId: BN, ballots: { voter: Wallet; proposalId: number; nonce: number }[]) =>
+			send(
+				administrator,
+				program.methods
+					.relayVotes(ballots.map(({ nonce }) => new BN(nonce)))
+					.accountsPartial({ relayer: administrator.publicKey, sessionAccount: client.findSessionAccountAddress(sessionId) })
+					.remainingAccounts(
+						ballots.flatMap(({ voter, proposalId }) => [
+							{ pubkey: client.findVoterAccountAddress(sessionId, voter.publicKey), isWritable: true, isSigner: false },
+							...proposalAccounts(sessionId, [proposalId]),
+						]),
+					)
+					.preInstructions([ed25519Instruction(ballots.map(({ voter, proposalId, nonce }) => ({ voter, message: ballotMessage(sessionId, proposalId, nonce) })))]),
+			);
+
+		let relayedSessionId: BN;
+
+		before(async () => {
+			relayedSessionId = await openSession('Relayed', [batman, superman]);
+			await client.startProposalsRegistration(administrator, relayedSessionId);
+			await client.registerProposal(batman, relayedSessionId, 'Batcave for everyone'); // 2
+			await client.stopProposalsRegistration(administrator, relayedSessionId);
+			await client.startVotingSession(administrator, relayedSessionId);
+		});
+
+		it('> should reject ballots signed with another nonce', async () => {
+			await assertError(() => relayVotes(relayedSessionId, [{ voter: batman, proposalId: 2, nonce: 1 }]), {
+				number: 6039,
+				code: 'InvalidBallotNonce',
+				errorMessage: 'Invalid signed ballot nonce',
+				programId: program.programId.toString(),
+			});
+		});
+
+		it('> should reject a ballot relayed twice', async () => {
+			await assertError(
+				() =>
+					relayVotes(relayedSessionId, [
+						{ voter: batman, proposalId: 2, nonce: 0 },
+						{ voter: batman, proposalId: 2, nonce: 0 },
+					]),
+				{
+					number: 6041,
+					code: 'DuplicateSignedBallot',
+					errorMessage: 'Signed ballot is relayed twice in the batch',
+					programId: program.programId.toString(),
+				},
+			);
+		});
+
+		it('> should relay a batch of signed ballots', async () => {
+			const { events } = await relayVotes(relayedSessionId, [
+				{ voter: batman, proposalId: 2, nonce: 0 },
+				{ voter: superman, proposalId: 2, nonce: 0 },
+			]);
+			assert.equal(events.signedBallotsRelayed.relayedCount, 2);
+			assert.isEmpty(events.signedBallotsRelayed.skippedVoters);
+
+			const proposal = await program.account.proposalAccount.fetch(client.findProposalAccountAddress(relayedSessionId, 2));
+			assert.equal(proposal.voteCount, 2);
+			const voter = await program.account.voterAccount.fetch(client.findVoterAccountAddress(relayedSessionId, batman.publicKey));
+			assert.isTrue(voter.hasVoted);
+			assert.equal(voter.nonce.toNumber(), 1);
+		});
+
+		it('> should skip already voted ballots', async () => {
+			const { events } = await relayVotes(relayedSessionId, [{ voter: batman, proposalId: 2, nonce: 1 }]);
+			assert.equal(events.signedBallotsRelayed.relayedCount, 0);
+			assert.deepEqual(
+				events.signedBallotsRelayed.skippedVoters.map((voter: PublicKey) => voter.toString()),
+				[batman.publicKey.toString()],
+			);
+
+			// skipped ballots do not consume the voter nonce
+			const voter = await program.account.voterAccount.fetch(client.findVoterAccountAddress(relayedSessionId, batman.publicKey));
+			assert.equal(voter.nonce.toNumber(), 1);
+		});
+
+		it('> should relay a ballot per seat of block voting voters', async () => {
+			const sessionId = await openSession('Relayed seats', [batman, superman]);
+			await configureVotingMode(sessionId, { plurality: {} }, 2);
+			await client.startProposalsRegistration(administrator, sessionId);
+			await client.registerProposal(batman, sessionId, 'Batcave for everyone'); // 2
+			await client.registerProposal(superman, sessionId, 'Krypton for everyone'); // 3
+			await client.stopProposalsRegistration(administrator, sessionId);
+			await client.startVotingSession(administrator, sessionId);
+
+			const { events } = await relayVotes(sessionId, [
+				{ voter: batman, proposalId: 2, nonce: 0 },
+				{ voter: batman, proposalId: 3, nonce: 1 },
+				{ voter: superman, proposalId: 3, nonce: 0 },
+			]);
+			assert.equal(events.signedBallotsRelayed.relayedCount, 3);
+			assert.isEmpty(events.signedBallotsRelayed.skippedVoters);
+
+			const voter = await program.account.voterAccount.fetch(client.findVoterAccountAddress(sessionId, batman.publicKey));
+			assert.deepEqual(voter.seatVotes.slice(0, 2), [2, 3]);
+			assert.equal(voter.nonce.toNumber(), 2);
+			const proposal = await program.account.proposalAccount.fetch(client.findProposalAccountAddress(sessionId, 3));
+			assert.equal(proposal.voteCount, 2);
+		});
+	});
+
+	describe('> Participation rewards', () => {
+		const REWARD_PER_VOTER = new BN(400_000);
+		const CLAIM_DURATION = 5;
+
+		let rewardedSessionId: BN;
+
+		before(async () => {
+			rewardedSessionId = await openSession('Rewarded', [batman, superman]);
+		});
+
+		it('> should reject a claim window out of bounds', async () => {
+			for (const claimDuration of [0, 365 * 24 * 60 * 60 + 1]) {
+				await assertError(() => client.fundRewardPool(administrator, rewardedSessionId, new BN(1_000_000), REWARD_PER_VOTER, new BN(claimDuration)), {
+					number: 6092,
+					code: 'InvalidClaimDuration',
+					errorMessage: 'Invalid claim duration',
+					programId: program.programId.toString(),
+				});
+			}
+		});
+
+		it('> should not reclaim the pool before votes tallying', async () => {
+			await client.fundRewardPool(administrator, rewardedSessionId, new BN(1_000_000), REWARD_PER_VOTER, new BN(CLAIM_DURATION));
+
+			await assertError(() => client.reclaimRewardPool(administrator, rewardedSessionId), {
+				number: 6000,
+				code: 'UnexpectedSessionStatus',
+				errorMessage: 'Unexpected session status',
+				programId: program.programId.toString(),
+			});
+		});
+
+		it('> should open the claim window at votes tallying', async () => {
+			await client.startProposalsRegistration(administrator, rewardedSessionId);
+			await client.registerProposal(batman, rewardedSessionId, 'Batcave for everyone'); // 2
+			await client.stopProposalsRegistration(administrator, rewardedSessionId);
+			await client.startVotingSession(administrator, rewardedSessionId);
+			await client.vote(batman, rewardedSessionId, 2);
+			await client.stopVotingSession(administrator, rewardedSessionId);
+			await client.tallyVotes(administrator, rewardedSessionId);
+
+			const { events } = await client.claimReward(batman, rewardedSessionId);
+			assert.equal(events.rewardClaimed.amount.toString(), REWARD_PER_VOTER.toString());
+
+			await assertError(() => client.claimReward(superman, rewardedSessionId), {
+				number: 6093,
+				code: 'VoterDidNotVote',
+				errorMessage: 'Voter did not vote',
+				programId: program.programId.toString(),
+			});
+			await assertError(() => client.reclaimRewardPool(administrator, rewardedSessionId), {
+				number: 6096,
+				code: 'ClaimPeriodNotEnded',
+				errorMessage: 'Claim period not ended',
+				programId: program.programId.toString(),
+			});
+		});
+
+		it('> should reclaim unclaimed rewards once the claim window closed', async () => {
+			await new Promise((resolve) => setTimeout(resolve, (CLAIM_DURATION + 1) * 1000));
+
+			const { events } = await client.reclaimRewardPool(administrator, rewardedSessionId);
+			assert.equal(events.rewardPoolReclaimed.amount.toString(), '600000');
+			assert.isNull(await program.account.rewardPoolAccount.fetchNullable(client.findRewardPoolAccountAddress(rewardedSessionId)));
+		});
+	});
+
+	describe('> Participation badges', () => {
+		const TOKEN_2022_PROGRAM_ID = new PublicKey('TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb');
+		const ASSOCIATED_TOKEN_PROGRAM_ID = new PublicKey('ATokenGPvbdGVxr1b2hf5ThkR4cU8CyBuGTVq1oB4KAr');
+
+		const findBadgeMintAddress = (sessionId: BN) => PublicKey.findProgramAddressSync([Buffer.from('badge_mint'), sessionId.toBuffer('le', 8)], program.programId)[0];
+		const findBadgeAddress = (sessionId: BN, voter: Wallet) =>
+			PublicKey.findProgramAddressSync([voter.publicKey.toBuffer(), TOKEN_2022_PROGRAM_ID.toBuffer(), findBadgeMintAddress(sessionId).toBuffer()], ASSOCIATED_TOKEN_PROGRAM_ID)[0];
+
+		const badgeAccounts = (sessionId: BN, voter: Wallet) => ({
+			voter: voter.publicKey,
+			sessionAccount: client.findSessionAccountAddress(sessionId),
+			voterAccount: client.findVoterAccountAddress(sessionId, voter.publicKey),
+			badgeMint: findBadgeMintAddress(sessionId),
+			voterBadgeAccount: findBadgeAddress(sessionId, voter),
+			tokenProgram: TOKEN_2022_PROGRAM_ID,
+			associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+		});
+		const voteWithBadge = (voter: Wallet, sessionId: BN, proposalId: number) =>
+			send(voter, program.methods.vote().accountsPartial({ ...badgeAccounts(sessionId, voter), proposalAccount: client.findProposalAccountAddress(sessionId, proposalId) }));
+		const mintBadge = (voter: Wallet, sessionId: BN) => send(voter, program.methods.mintBadge().accountsPartial(badgeAccounts(sessionId, voter)));
+		const enableBadges = (sessionId: BN) =>
+			send(
+				administrator,
+				program.methods.enableBadges().accountsPartial({
+					admin: administrator.publicKey,
+					sessionAccount: client.findSessionAccountAddress(sessionId),
+					badgeMint: findBadgeMintAddress(sessionId),
+					tokenProgram: TOKEN_2022_PROGRAM_ID,
+				}),
+			);
+
+		let badgeSessionId: BN;
+
+		before(async () => {
+			// two seats, so that a voter votes twice on the main question
+			badgeSessionId = await openSession('Badges', [batman, superman]);
+			await configureVotingMode(badgeSessionId, { plurality: {} }, 2);
+			const { events } = await enableBadges(badgeSessionId);
+			assert.equal(events.badgesEnabled.mint.toString(), findBadgeMintAddress(badgeSessionId).toString());
+
+			await client.startProposalsRegistration(administrator, badgeSessionId);
+			await client.registerProposal(batman, badgeSessionId, 'Batcave for everyone'); // 2
+			await client.registerProposal(superman, badgeSessionId, 'Krypton for everyone'); // 3
+			await client.stopProposalsRegistration(administrator, badgeSessionId);
+			await client.startVotingSession(administrator, badgeSessionId);
+		});
+
+		it('> should not enable badges twice', async () => {
+			await assertError(() => enableBadges(badgeSessionId), {
+				number: 6099,
+				code: 'BadgesAlreadyEnabled',
+				errorMessage: 'Badges already enabled',
+				programId: program.programId.toString(),
+			});
+		});
+
+		it('> should enable badges on a prefunded mint address', async () => {
+			const sessionId = await openSession('Prefunded badges', [batman]);
+			const badgeMint = findBadgeMintAddress(sessionId);
+			await client.airdrop(badgeMint, 1);
+
+			const { events } = await enableBadges(sessionId);
+			assert.equal(events.badgesEnabled.mint.toString(), badgeMint.toString());
+
+			const mintInfo = await connection.getAccountInfo(badgeMint);
+			assert.equal(mintInfo.owner.toString(), TOKEN_2022_PROGRAM_ID.toString());
+		});
+
+		it('> should not mint a badge to a voter who did not vote', async () => {
+			await assertError(() => mintBadge(superman, badgeSessionId), {
+				number: 6093,
+				code: 'VoterDidNotVote',
+				errorMessage: 'Voter did not vote',
+				programId: program.programId.toString(),
+			});
+		});
+
+		it('> should mint a single badge along the votes', async () => {
+			const { events } = await voteWithBadge(batman, badgeSessionId, 2);
+			assert.equal(events.badgeMinted.voter.toString(), batman.publicKey.toString());
+
+			// the second seat vote skips the badge already minted
+			const { events: secondEvents } = await voteWithBadge(batman, badgeSessionId, 3);
+			assert.isDefined(secondEvents.voted);
+			assert.isUndefined(secondEvents.badgeMinted);
+
+			const { value } = await connection.getTokenAccountBalance(findBadgeAddress(badgeSessionId, batman));
+			assert.equal(value.amount, '1');
+			const voter = await program.account.voterAccount.fetch(client.findVoterAccountAddress(badgeSessionId, batman.publicKey));
+			assert.isTrue(voter.badgeMinted);
+		});
+
+		it('> should not mint an already minted badge', async () => {
+			await assertError(() => mintBadge(batman, badgeSessionId), {
+				number: 6100,
+				code: 'BadgeAlreadyMinted',
+				errorMessage: 'Badge already minted',
+				programId: program.programId.toString(),
+			});
+		});
+
+		it('> should mint the badge after voting', async () => {
+			await client.vote(superman, badgeSessionId, 2);
+			const { events } = await mintBadge(superman, badgeSessionId);
+			assert.equal(events.badgeMinted.voter.toString(), superman.publicKey.toString());
+
+			const { value } = await connection.getTokenAccountBalance(findBadgeAddress(badgeSessionId, superman));
+			assert.equal(value.amount, '1');
+		});
+	});
+
+	describe('> Outcome through CPI', () => {
+		// the consumer program reads the outcome with get_proposal_outcome CPI, decoded with `Return::get`
+		const consumer = anchor.workspace.VotingConsumer as Program;
+
+		const findExecutionRecordAddress = (sessionId: BN, proposalId: number) =>
+			PublicKey.findProgramAddressSync([Buffer.from('execution'), sessionId.toBuffer('le', 8), Buffer.from([proposalId])], consumer.programId)[0];
+
+		const executeIfWon = (sessionId: BN, proposalId: number, sessionAccount: PublicKey = client.findSessionAccountAddress(sessionId), questionAccount: PublicKey = null) =>
+			send(
+				administrator,
+				consumer.methods.executeIfWon(sessionId, proposalId).accountsPartial({
+					payer: administrator.publicKey,
+					sessionAccount,
+					proposalAccount: client.findProposalAccountAddress(sessionId, proposalId),
+					questionAccount,
+					executionRecord: findExecutionRecordAddress(sessionId, proposalId),
+					votingProgram: program.programId,
+				}),
+			);
+
+		const assertConsumerError = (fn: () => Promise<any>, number: number, code: string, errorMessage: string) =>
+			assertError(fn, { number, code, errorMessage, programId: consumer.programId.toString() });
+
+		const tallySession = async (name: string, executionDelay: number) => {
+			const sessionId = await openSession(name, [batman, superman]);
+			await send(
+				administrator,
+				program.methods.configureExecution(new BN(executionDelay), null).accountsPartial({
+					admin: administrator.publicKey,
+					sessionAccount: client.findSessionAccountAddress(sessionId),
+				}),
+			);
+			await client.startProposalsRegistration(administrator, sessionId);
+			await client.registerProposal(batman, sessionId, 'Batcave for everyone'); // 2
+			await client.registerProposal(superman, sessionId, 'Krypton for everyone'); // 3
+			await client.stopProposalsRegistration(administrator, sessionId);
+			await client.startVotingSession(administrator, sessionId);
+			await client.vote(batman, sessionId, 2);
+			await client.vote(superman, sessionId, 2);
+			await client.stopVotingSession(administrator, sessionId);
+			await client.tallyVotes(administrator, sessionId);
+			return sessionId;
+		};
+
+		let releasedSessionId: BN;
+
+		before(async () => {
+			releasedSessionId = await tallySession('Outcome through CPI', 0);
+		});
+
+		it('> should return the outcome of a proposal', async () => {
+			const outcome = await program.methods
+				.getProposalOutcome()
+				.accountsPartial({
+					sessionAccount: client.findSessionAccountAddress(releasedSessionId),
+					proposalAccount: client.findProposalAccountAddress(releasedSessionId, 2),
+					questionAccount: null,
+				})
+				.view();
+
+			assert.equal(outcome.sessionId.toString(), releasedSessionId.toString());
+			assert.equal(outcome.proposalId, 2);
+			assert.isTrue(outcome.won);
+			assert.equal(outcome.rank, 1);
+			assert.equal(outcome.totalVotes, 2);
+			assert.isTrue(outcome.releasedAt.gtn(0));
+		});
+
+		it('> should record the execution of a released winning proposal once', async () => {
+			await executeIfWon(releasedSessionId, 2);
+
+			const record = await consumer.account.executionRecordAccount.fetch(findExecutionRecordAddress(releasedSessionId, 2));
+			assert.equal(record.sessionId.toString(), releasedSessionId.toString());
+			assert.equal(record.proposalId, 2);
+			assert.equal(record.rank, 1);
+			assert.isTrue(record.executedAt.gtn(0));
+
+			// the execution record already exists
+			try {
+				await executeIfWon(releasedSessionId, 2);
+				assert.ok(false);
+			} catch (_err) {
+				assert.isArray(_err.logs);
+			}
+		});
+
+		it('> should execute a proposal which won its question', async () => {
+			const sessionId = await openSession('Outcome through CPI questions', [batman, superman]);
+			await client.startProposalsRegistration(administrator, sessionId);
+			await client.addQuestion(administrator, sessionId, 'Raise the budget?'); // blank proposal 2
+			await client.registerProposal(batman, sessionId, 'Batcave for everyone'); // 3, main question
+			await client.registerProposal(superman, sessionId, 'Raise it by 10%', '', undefined, 1); // 4, question 1
+			await client.stopProposalsRegistration(administrator, sessionId);
+			await client.startVotingSession(administrator, sessionId);
+			await client.vote(batman, sessionId, 3);
+			await client.vote(superman, sessionId, 3);
+			await client.vote(batman, sessionId, 4);
+			await client.stopVotingSession(administrator, sessionId);
+			await client.tallyVotes(administrator, sessionId);
+
+			const questionAccount = client.findQuestionAccountAddress(sessionId, 1);
+			const outcome = await program.methods
+				.getProposalOutcome()
+				.accountsPartial({
+					sessionAccount: client.findSessionAccountAddress(sessionId),
+					proposalAccount: client.findProposalAccountAddress(sessionId, 4),
+					questionAccount,
+				})
+				.view();
+			assert.isTrue(outcome.won);
+			assert.equal(outcome.rank, 1);
+			// the question total votes, not the main question ones
+			assert.equal(outcome.totalVotes, 1);
+
+			await assertError(() => executeIfWon(sessionId, 4), {
+				number: 6026,
+				code: 'InvalidQuestionId',
+				errorMessage: 'Invalid question',
+				programId: program.programId.toString(),
+			});
+			await executeIfWon(sessionId, 4, client.findSessionAccountAddress(sessionId), questionAccount);
+			const record = await consumer.account.executionRecordAccount.fetch(findExecutionRecordAddress(sessionId, 4));
+			assert.equal(record.rank, 1);
+		});
+
+		it('> should not execute a losing proposal', async () => {
+			await assertConsumerError(() => executeIfWon(releasedSessionId, 3), 6001, 'ProposalNotElected', 'Proposal was not elected');
+		});
+
+		it('> should not execute a proposal of an unreleased result', async () => {
+			const sessionId = await tallySession('Outcome through CPI timelocked', 3600);
+
+			await assertConsumerError(() => executeIfWon(sessionId, 2), 6002, 'ResultNotReleased', 'Session result is not released yet');
+		});
+
+		it('> should not read the outcome of another session account', async () => {
+			const otherSessionId = await openSession('Outcome through CPI other', []);
+
+			// the session account must be the voting program one for the given session id
+			await assertConsumerError(
+				() => executeIfWon(otherSessionId, 2, client.findSessionAccountAddress(releasedSessionId)),
+				2006,
+				'ConstraintSeeds',
+				'A seeds constraint was violated',
+			);
 		});
 	});
 });